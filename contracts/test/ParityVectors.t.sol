@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity 0.8.23;
+
+import {Test} from "forge-std/Test.sol";
+import {Create2} from "openzeppelin-contracts/contracts/utils/Create2.sol";
+
+import {IBaseEscrow} from "../lib/cross-chain-swap/contracts/interfaces/IBaseEscrow.sol";
+import {Timelocks, TimelocksLib} from "../lib/cross-chain-swap/contracts/libraries/TimelocksLib.sol";
+import {TimelocksSettersLib} from "../lib/cross-chain-swap/test/utils/libraries/TimelocksSettersLib.sol";
+import {Address} from "solidity-utils/contracts/libraries/AddressLib.sol";
+import {ImmutablesLib} from "../lib/cross-chain-swap/contracts/libraries/ImmutablesLib.sol";
+
+/**
+ * @title Cross-chain hashing parity with the ink! contracts.
+ * @dev Reads the fixtures generated by the `parity_vectors` crate and recomputes every
+ * Ethereum-side value from the raw fields, including the packed `timelocks` word. Regenerate them with
+ * `PARITY_VECTORS_UPDATE=1 cargo test -p parity_vectors` after an intentional encoding change.
+ */
+contract ParityVectorsTest is Test {
+    using ImmutablesLib for IBaseEscrow.Immutables;
+
+    string private constant _VECTORS = "/polkadot_contracts/parity_vectors/vectors.json";
+    uint256 private constant _VERSION = 4;
+
+    string private _json;
+
+    function setUp() public {
+        _json = vm.readFile(string.concat(vm.projectRoot(), _VECTORS));
+        assertEq(vm.parseJsonUint(_json, ".version"), _VERSION, "unsupported vectors.json version");
+    }
+
+    function test_EscrowPreimageAndSaltMatchImmutablesLib() public view {
+        uint256 count;
+        for (; _exists("escrows", count); count++) {
+            string memory key = _at("escrows", count);
+            IBaseEscrow.Immutables memory immutables = _immutables(key);
+
+            assertEq(abi.encode(immutables), vm.parseJsonBytes(_json, string.concat(key, ".preimage")), key);
+            assertEq(immutables.hashMem(), vm.parseJsonBytes32(_json, string.concat(key, ".salt")), key);
+        }
+        assertGt(count, 0, "no escrow vectors");
+    }
+
+    function test_TimelocksPackFromStages() public view {
+        uint256 count;
+        for (; _exists("escrows", count); count++) {
+            string memory key = _at("escrows", count);
+            Timelocks timelocks = _timelocks(key);
+
+            assertEq(
+                bytes32(Timelocks.unwrap(timelocks)), vm.parseJsonBytes32(_json, string.concat(key, ".timelocks")), key
+            );
+            uint256 deployedAt = _secs(key, "deployed_at");
+            uint256 srcCancellation = _secs(key, "src_cancellation");
+            if (srcCancellation >= deployedAt) {
+                assertEq(TimelocksLib.get(timelocks, TimelocksLib.Stage.SrcCancellation), srcCancellation, key);
+            }
+        }
+        assertGt(count, 0, "no escrow vectors");
+    }
+
+    function test_EscrowAddressMatchesCreate2() public view {
+        uint256 count;
+        for (; _exists("escrows", count); count++) {
+            string memory key = _at("escrows", count);
+            address computed = Create2.computeAddress(
+                vm.parseJsonBytes32(_json, string.concat(key, ".salt")),
+                vm.parseJsonBytes32(_json, string.concat(key, ".bytecode_hash")),
+                vm.parseJsonAddress(_json, string.concat(key, ".factory"))
+            );
+            assertEq(computed, vm.parseJsonAddress(_json, string.concat(key, ".ethereum_address")), key);
+        }
+        assertGt(count, 0, "no escrow vectors");
+    }
+
+    function test_HashlocksMatchKeccak() public view {
+        uint256 count;
+        for (; _exists("hashlocks", count); count++) {
+            string memory key = _at("hashlocks", count);
+            bytes memory secret = vm.parseJsonBytes(_json, string.concat(key, ".secret"));
+            assertEq(keccak256(secret), vm.parseJsonBytes32(_json, string.concat(key, ".keccak256")), key);
+        }
+        assertGt(count, 0, "no hashlock vectors");
+    }
+
+    function _immutables(string memory key) private view returns (IBaseEscrow.Immutables memory) {
+        return IBaseEscrow.Immutables({
+            orderHash: vm.parseJsonBytes32(_json, string.concat(key, ".order_hash")),
+            hashlock: vm.parseJsonBytes32(_json, string.concat(key, ".hash_lock")),
+            maker: Address.wrap(uint256(vm.parseJsonBytes32(_json, string.concat(key, ".maker")))),
+            taker: Address.wrap(uint256(vm.parseJsonBytes32(_json, string.concat(key, ".taker")))),
+            token: Address.wrap(uint256(vm.parseJsonBytes32(_json, string.concat(key, ".token")))),
+            amount: vm.parseJsonUint(_json, string.concat(key, ".amount")),
+            safetyDeposit: vm.parseJsonUint(_json, string.concat(key, ".safety_deposit")),
+            timelocks: _timelocks(key)
+        });
+    }
+
+    /// @dev Packs the millisecond stage starts: `deployedAt` and every stage in seconds, stages as
+    /// offsets from `deployedAt` (zero when earlier), all saturating at uint32.
+    function _timelocks(string memory key) private view returns (Timelocks) {
+        uint256 deployedAt = _secs(key, "deployed_at");
+        return TimelocksSettersLib.init(
+            _offset(key, "src_withdrawal", deployedAt),
+            _offset(key, "src_public_withdrawal", deployedAt),
+            _offset(key, "src_cancellation", deployedAt),
+            _offset(key, "src_public_cancellation", deployedAt),
+            _offset(key, "dst_withdrawal", deployedAt),
+            _offset(key, "dst_public_withdrawal", deployedAt),
+            _offset(key, "dst_cancellation", deployedAt),
+            uint32(deployedAt)
+        );
+    }
+
+    function _offset(string memory key, string memory stage, uint256 deployedAt) private view returns (uint32) {
+        uint256 start = _secs(key, stage);
+        return start > deployedAt ? uint32(start - deployedAt) : 0;
+    }
+
+    function _secs(string memory key, string memory field) private view returns (uint256) {
+        uint256 secs = vm.parseJsonUint(_json, string.concat(key, ".", field)) / 1000;
+        return secs > type(uint32).max ? type(uint32).max : secs;
+    }
+
+    function _exists(string memory list, uint256 index) private view returns (bool) {
+        return vm.keyExistsJson(_json, _at(list, index));
+    }
+
+    function _at(string memory list, uint256 index) private pure returns (string memory) {
+        return string.concat(".", list, "[", vm.toString(index), "]");
+    }
+}
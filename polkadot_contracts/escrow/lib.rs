@@ -0,0 +1,319 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+pub use fusion_common::{capabilities, psp22};
+
+/// Semantic version of the contract interface (major, minor, patch)
+pub const CONTRACT_VERSION: (u16, u16, u16) = (0, 1, 0);
+
+/// Per-order HTLC escrow instantiated by the factory/resolver: holds one leg of a swap plus the
+/// resolver's safety deposit until the secret is revealed or the order is cancelled
+#[ink::contract]
+pub mod fusion_escrow {
+    use super::{capabilities, psp22, CONTRACT_VERSION};
+    use fusion_common::GasBudget;
+    use scale::{Decode, Encode};
+
+    /// `EscrowImmutables.token` value denoting the chain's native currency
+    pub const NATIVE_TOKEN: [u8; 32] = [0u8; 32];
+
+    /// Which leg of the swap this escrow holds
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum EscrowSide {
+        Source,      // Maker's funds: withdrawn to the taker, refunded to the maker
+        Destination, // Taker's funds: withdrawn to the maker, refunded to the taker
+    }
+
+    /// Timelock stages, in `TimelocksLib.Stage` order
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Stage {
+        SrcWithdrawal,
+        SrcPublicWithdrawal,
+        SrcCancellation,
+        SrcPublicCancellation,
+        DstWithdrawal,
+        DstPublicWithdrawal,
+        DstCancellation,
+    }
+
+    /// Timelock structure (matches resolver): stage offsets from `deployed_at`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct TimeLocks {
+        pub src_withdrawal: u32,
+        pub src_public_withdrawal: u32,
+        pub src_cancellation: u32,
+        pub src_public_cancellation: u32,
+        pub dst_withdrawal: u32,
+        pub dst_public_withdrawal: u32,
+        pub dst_cancellation: u32,
+    }
+
+    impl TimeLocks {
+        /// When `stage` opens for an escrow deployed at `deployed_at`
+        pub fn stage_start(&self, stage: Stage, deployed_at: Timestamp) -> Timestamp {
+            let offset = match stage {
+                Stage::SrcWithdrawal => self.src_withdrawal,
+                Stage::SrcPublicWithdrawal => self.src_public_withdrawal,
+                Stage::SrcCancellation => self.src_cancellation,
+                Stage::SrcPublicCancellation => self.src_public_cancellation,
+                Stage::DstWithdrawal => self.dst_withdrawal,
+                Stage::DstPublicWithdrawal => self.dst_public_withdrawal,
+                Stage::DstCancellation => self.dst_cancellation,
+            };
+            deployed_at.saturating_add(offset as u64)
+        }
+    }
+
+    impl EscrowSide {
+        /// Private withdrawal, public withdrawal and cancellation stages for this side
+        pub fn withdrawal_stages(&self) -> (Stage, Stage, Stage) {
+            match self {
+                EscrowSide::Source => (Stage::SrcWithdrawal, Stage::SrcPublicWithdrawal, Stage::SrcCancellation),
+                EscrowSide::Destination => (Stage::DstWithdrawal, Stage::DstPublicWithdrawal, Stage::DstCancellation),
+            }
+        }
+
+        /// Private and public cancellation stages (destination escrows have no public cancellation)
+        pub fn cancellation_stages(&self) -> (Stage, Option<Stage>) {
+            match self {
+                EscrowSide::Source => (Stage::SrcCancellation, Some(Stage::SrcPublicCancellation)),
+                EscrowSide::Destination => (Stage::DstCancellation, None),
+            }
+        }
+    }
+
+    /// Immutable escrow parameters (matches resolver)
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct EscrowImmutables {
+        pub order_hash: [u8; 32],
+        pub hash_lock: [u8; 32],
+        pub maker: AccountId,
+        pub taker: AccountId,
+        pub token: AccountId, // NATIVE_TOKEN for the native currency, otherwise a PSP22 contract
+        pub amount: Balance,
+        pub safety_deposit: Balance,
+        pub timelocks: TimeLocks,
+        pub deployed_at: Option<Timestamp>,
+    }
+
+    #[ink(event)]
+    pub struct EscrowWithdrawal {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub secret: [u8; 32],
+        pub recipient: AccountId,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct EscrowCancelled {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub refund_to: AccountId,
+        pub amount: Balance,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        // Access control
+        InvalidCaller,
+        InvalidImmutables,
+        InvalidSecret,
+
+        // Timing
+        StageNotReached { unlocks_at: Timestamp, current_time: Timestamp },
+        StageClosed { closed_at: Timestamp, current_time: Timestamp },
+
+        // Funds
+        InsufficientFunds { required: Balance, provided: Balance },
+        TransferFailed,
+    }
+
+    #[ink(storage)]
+    pub struct FusionEscrow {
+        immutables_hash: [u8; 32], // Blake2x256 of the SCALE-encoded immutables, deployed_at set
+        side: EscrowSide,
+        deployed_at: Timestamp,
+    }
+
+    impl FusionEscrow {
+        /// Fund the escrow: the safety deposit (and the amount, for native escrows) must be
+        /// attached; PSP22 amounts are transferred in by the deployer in the same transaction,
+        /// which checks the escrow's balance. The stages always count from instantiation, so any
+        /// `deployed_at` the deployer passed is replaced.
+        #[ink(constructor, payable)]
+        pub fn new(mut immutables: EscrowImmutables, side: EscrowSide) -> Result<Self, Error> {
+            let deployed_at = Self::env().block_timestamp();
+            immutables.deployed_at = Some(deployed_at);
+
+            let required = if immutables.token == AccountId::from(NATIVE_TOKEN) {
+                immutables.amount.saturating_add(immutables.safety_deposit)
+            } else {
+                immutables.safety_deposit
+            };
+            let provided = Self::env().transferred_value();
+            if provided < required {
+                return Err(Error::InsufficientFunds { required, provided });
+            }
+
+            Ok(Self {
+                immutables_hash: Self::hash_immutables(&immutables),
+                side,
+                deployed_at,
+            })
+        }
+
+        /// Release the funds with the secret. The taker may withdraw from the private withdrawal
+        /// stage, anyone from the public one, until cancellation opens. The caller receives the
+        /// safety deposit and the escrow is removed.
+        #[ink(message)]
+        pub fn withdraw(&mut self, secret: [u8; 32], immutables: EscrowImmutables) -> Result<(), Error> {
+            self.ensure_valid_immutables(&immutables)?;
+
+            let caller = self.env().caller();
+            let (private_stage, public_stage, cancellation_stage) = self.side.withdrawal_stages();
+            let opens = if caller == immutables.taker { private_stage } else { public_stage };
+            self.ensure_after(&immutables, opens)?;
+            self.ensure_before(&immutables, cancellation_stage)?;
+
+            let computed_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&secret);
+            if computed_hash != immutables.hash_lock {
+                return Err(Error::InvalidSecret);
+            }
+
+            let recipient = match self.side {
+                EscrowSide::Source => immutables.taker,
+                EscrowSide::Destination => immutables.maker,
+            };
+            self.pay_out(&immutables, recipient)?;
+
+            self.env().emit_event(EscrowWithdrawal {
+                order_hash: immutables.order_hash,
+                secret,
+                recipient,
+                amount: immutables.amount,
+            });
+
+            self.settle(caller)
+        }
+
+        /// Return the funds once cancellation opens. Source escrows can be cancelled by the
+        /// taker, or by anyone after public cancellation; destination escrows by the taker only.
+        /// The caller receives the safety deposit and the escrow is removed.
+        #[ink(message)]
+        pub fn cancel(&mut self, immutables: EscrowImmutables) -> Result<(), Error> {
+            self.ensure_valid_immutables(&immutables)?;
+
+            let caller = self.env().caller();
+            let (private_stage, public_stage) = self.side.cancellation_stages();
+            let opens = match public_stage {
+                Some(stage) if caller != immutables.taker => stage,
+                None if caller != immutables.taker => return Err(Error::InvalidCaller),
+                _ => private_stage,
+            };
+            self.ensure_after(&immutables, opens)?;
+
+            let refund_to = match self.side {
+                EscrowSide::Source => immutables.maker,
+                EscrowSide::Destination => immutables.taker,
+            };
+            self.pay_out(&immutables, refund_to)?;
+
+            self.env().emit_event(EscrowCancelled {
+                order_hash: immutables.order_hash,
+                refund_to,
+                amount: immutables.amount,
+            });
+
+            self.settle(caller)
+        }
+
+        // --- View Functions ---
+
+        #[ink(message)]
+        pub fn get_immutables_hash(&self) -> [u8; 32] {
+            self.immutables_hash
+        }
+
+        #[ink(message)]
+        pub fn get_side(&self) -> EscrowSide {
+            self.side
+        }
+
+        /// Deployment time the immutables were hashed with
+        #[ink(message)]
+        pub fn get_deployed_at(&self) -> Timestamp {
+            self.deployed_at
+        }
+
+        /// Interface version, for SDK feature detection
+        #[ink(message)]
+        pub fn get_version(&self) -> (u16, u16, u16) {
+            CONTRACT_VERSION
+        }
+
+        /// Supported features as `capabilities` bitflags: native or PSP22 legs, Blake2x256
+        /// hashlocks
+        #[ink(message)]
+        pub fn get_capabilities(&self) -> u64 {
+            capabilities::PSP22_TOKENS | capabilities::HASH_BLAKE2X256
+        }
+
+        // --- Helper Functions ---
+
+        fn hash_immutables(immutables: &EscrowImmutables) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&immutables.encode(), &mut output);
+            output
+        }
+
+        fn ensure_valid_immutables(&self, immutables: &EscrowImmutables) -> Result<(), Error> {
+            if immutables.deployed_at != Some(self.deployed_at)
+                || Self::hash_immutables(immutables) != self.immutables_hash
+            {
+                return Err(Error::InvalidImmutables);
+            }
+            Ok(())
+        }
+
+        fn ensure_after(&self, immutables: &EscrowImmutables, stage: Stage) -> Result<(), Error> {
+            let unlocks_at = immutables.timelocks.stage_start(stage, self.deployed_at);
+            let current_time = self.env().block_timestamp();
+            if current_time < unlocks_at {
+                return Err(Error::StageNotReached { unlocks_at, current_time });
+            }
+            Ok(())
+        }
+
+        fn ensure_before(&self, immutables: &EscrowImmutables, stage: Stage) -> Result<(), Error> {
+            let closed_at = immutables.timelocks.stage_start(stage, self.deployed_at);
+            let current_time = self.env().block_timestamp();
+            if current_time >= closed_at {
+                return Err(Error::StageClosed { closed_at, current_time });
+            }
+            Ok(())
+        }
+
+        /// Send the escrowed amount (native or PSP22) to `to`
+        fn pay_out(&self, immutables: &EscrowImmutables, to: AccountId) -> Result<(), Error> {
+            if immutables.amount == 0 {
+                return Ok(());
+            }
+            if immutables.token == AccountId::from(NATIVE_TOKEN) {
+                return self.env().transfer(to, immutables.amount).map_err(|_| Error::TransferFailed);
+            }
+            psp22::transfer(immutables.token, to, immutables.amount, GasBudget::UNLIMITED)
+                .map_err(|_| Error::TransferFailed)
+        }
+
+        /// Remove the escrow: the remaining balance (safety deposit and any dust) and the
+        /// storage deposit go to the caller. Terminated calls return no output.
+        fn settle(&mut self, caller: AccountId) -> Result<(), Error> {
+            self.env().terminate_contract(caller)
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,70 @@
+//! Instantiation: the deposit must be attached and the stages count from the instantiation
+//! block, whatever `deployed_at` the deployer passed.
+
+use fusion_escrow::fusion_escrow::{EscrowImmutables, EscrowSide, Error, FusionEscrow, TimeLocks, NATIVE_TOKEN};
+use ink::env::{test, DefaultEnvironment};
+use ink::primitives::AccountId;
+use scale::Encode;
+
+type Env = DefaultEnvironment;
+
+const NOW: u64 = 1_700_000_000_000;
+const AMOUNT: u128 = 1_000_000;
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+fn immutables(deployed_at: Option<u64>) -> EscrowImmutables {
+    EscrowImmutables {
+        order_hash: [0x11; 32],
+        hash_lock: [0x42; 32],
+        maker: AccountId::from([0xaa; 32]),
+        taker: AccountId::from([0xbb; 32]),
+        token: AccountId::from(NATIVE_TOKEN),
+        amount: AMOUNT,
+        safety_deposit: SAFETY_DEPOSIT,
+        timelocks: TimeLocks {
+            src_withdrawal: 10,
+            src_public_withdrawal: 20,
+            src_cancellation: 30,
+            src_public_cancellation: 40,
+            dst_withdrawal: 5,
+            dst_public_withdrawal: 15,
+            dst_cancellation: 25,
+        },
+        deployed_at,
+    }
+}
+
+fn instantiate(immutables: EscrowImmutables, value: u128) -> Result<FusionEscrow, Error> {
+    test::set_block_timestamp::<Env>(NOW);
+    test::set_value_transferred::<Env>(value);
+    FusionEscrow::new(immutables, EscrowSide::Source)
+}
+
+#[test]
+fn deployed_at_is_the_instantiation_time() {
+    for passed in [None, Some(0), Some(NOW - 1), Some(NOW + 1)] {
+        test::run_test::<Env, _>(|_| {
+            let escrow = instantiate(immutables(passed), AMOUNT + SAFETY_DEPOSIT).expect("instantiate");
+            assert_eq!(escrow.get_deployed_at(), NOW, "{passed:?}");
+
+            let mut hash = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&immutables(Some(NOW)).encode(), &mut hash);
+            assert_eq!(escrow.get_immutables_hash(), hash, "{passed:?}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn native_escrows_need_the_amount_and_the_deposit() {
+    test::run_test::<Env, _>(|_| {
+        let required = AMOUNT + SAFETY_DEPOSIT;
+        assert_eq!(
+            instantiate(immutables(None), required - 1).err(),
+            Some(Error::InsufficientFunds { required, provided: required - 1 })
+        );
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
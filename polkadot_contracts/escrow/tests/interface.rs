@@ -0,0 +1,52 @@
+//! Feature detection: the escrow reports its interface version and capabilities like the
+//! contracts that deploy it.
+
+use fusion_escrow::fusion_escrow::{EscrowImmutables, EscrowSide, FusionEscrow, TimeLocks, NATIVE_TOKEN};
+use fusion_escrow::{capabilities, CONTRACT_VERSION};
+use ink::env::{test, DefaultEnvironment};
+use ink::primitives::AccountId;
+
+type Env = DefaultEnvironment;
+
+const AMOUNT: u128 = 1_000_000;
+
+fn escrow() -> FusionEscrow {
+    let immutables = EscrowImmutables {
+        order_hash: [0x11; 32],
+        hash_lock: [0x42; 32],
+        maker: AccountId::from([0xaa; 32]),
+        taker: AccountId::from([0xbb; 32]),
+        token: AccountId::from(NATIVE_TOKEN),
+        amount: AMOUNT,
+        safety_deposit: 0,
+        timelocks: TimeLocks {
+            src_withdrawal: 10,
+            src_public_withdrawal: 20,
+            src_cancellation: 30,
+            src_public_cancellation: 40,
+            dst_withdrawal: 5,
+            dst_public_withdrawal: 15,
+            dst_cancellation: 25,
+        },
+        deployed_at: None,
+    };
+    test::set_value_transferred::<Env>(AMOUNT);
+    FusionEscrow::new(immutables, EscrowSide::Source).expect("instantiate")
+}
+
+#[test]
+fn reports_version_and_capabilities() {
+    test::run_test::<Env, _>(|_| {
+        let escrow = escrow();
+        assert_eq!(escrow.get_version(), CONTRACT_VERSION);
+
+        let reported = escrow.get_capabilities();
+        assert_ne!(reported & capabilities::PSP22_TOKENS, 0);
+        assert_ne!(reported & capabilities::HASH_BLAKE2X256, 0);
+        for flag in [capabilities::PARTIAL_FILLS, capabilities::HASH_SHA256, capabilities::HASH_KECCAK256] {
+            assert_eq!(reported & flag, 0, "flag {flag:#x}");
+        }
+        Ok(())
+    })
+    .expect("off-chain env");
+}
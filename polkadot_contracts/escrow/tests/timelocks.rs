@@ -0,0 +1,47 @@
+//! Stage windows of the per-order escrow, computed from the resolver's `TimeLocks` offsets.
+
+use fusion_escrow::fusion_escrow::{EscrowSide, Stage, TimeLocks};
+
+const DEPLOYED_AT: u64 = 1_700_000_000_000;
+
+fn timelocks() -> TimeLocks {
+    TimeLocks {
+        src_withdrawal: 10,
+        src_public_withdrawal: 20,
+        src_cancellation: 30,
+        src_public_cancellation: 40,
+        dst_withdrawal: 5,
+        dst_public_withdrawal: 15,
+        dst_cancellation: 25,
+    }
+}
+
+#[test]
+fn stages_are_offsets_from_deployment() {
+    let timelocks = timelocks();
+    assert_eq!(timelocks.stage_start(Stage::SrcWithdrawal, DEPLOYED_AT), DEPLOYED_AT + 10);
+    assert_eq!(timelocks.stage_start(Stage::SrcPublicCancellation, DEPLOYED_AT), DEPLOYED_AT + 40);
+    assert_eq!(timelocks.stage_start(Stage::DstCancellation, DEPLOYED_AT), DEPLOYED_AT + 25);
+}
+
+#[test]
+fn stage_start_saturates() {
+    assert_eq!(timelocks().stage_start(Stage::SrcCancellation, u64::MAX - 1), u64::MAX);
+}
+
+#[test]
+fn each_side_uses_its_own_stages() {
+    assert_eq!(
+        EscrowSide::Source.withdrawal_stages(),
+        (Stage::SrcWithdrawal, Stage::SrcPublicWithdrawal, Stage::SrcCancellation),
+    );
+    assert_eq!(
+        EscrowSide::Destination.withdrawal_stages(),
+        (Stage::DstWithdrawal, Stage::DstPublicWithdrawal, Stage::DstCancellation),
+    );
+    assert_eq!(
+        EscrowSide::Source.cancellation_stages(),
+        (Stage::SrcCancellation, Some(Stage::SrcPublicCancellation)),
+    );
+    assert_eq!(EscrowSide::Destination.cancellation_stages(), (Stage::DstCancellation, None));
+}
\ No newline at end of file
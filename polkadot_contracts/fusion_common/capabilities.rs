@@ -0,0 +1,11 @@
+//! Capability bitflags reported by each contract's `get_capabilities`; bit positions are shared
+//! by all contracts
+
+pub const PSP22_TOKENS: u64 = 1 << 0;
+pub const PARTIAL_FILLS: u64 = 1 << 1;
+pub const HASH_BLAKE2X256: u64 = 1 << 2;
+pub const HASH_SHA256: u64 = 1 << 3;
+pub const HASH_KECCAK256: u64 = 1 << 4;
+pub const CHAIN_ETHEREUM: u64 = 1 << 5;
+/// Errors may be the context-carrying `*V2` variants
+pub const RICH_ERRORS: u64 = 1 << 6;
\ No newline at end of file
@@ -0,0 +1,107 @@
+//! Escrow address derivation with the same layout as the Ethereum escrow factory
+//!
+//! The salt is `keccak256(abi.encode(IBaseEscrow.Immutables))`, i.e. eight 32-byte big-endian
+//! words in field order: orderHash, hashlock, maker, taker, token, amount, safetyDeposit,
+//! timelocks (`ImmutablesLib.hashMem`). Ethereum addresses live in the low 20 bytes of a word,
+//! so an Ethereum account embedded in an `AccountId` as `[0u8; 12] ++ address` encodes the same.
+//!
+//! Timelocks are packed like `TimelocksLib`: `deployedAt` (seconds) in the top 32 bits and each
+//! stage as a u32 offset in seconds from it at `stage * 32` bits. Contracts keep millisecond
+//! timestamps, so both are converted here and every contract derives the same word.
+
+use crate::hashing::keccak256;
+use ink::primitives::AccountId;
+
+/// `ImmutablesLib.ESCROW_IMMUTABLES_SIZE`
+pub const ESCROW_IMMUTABLES_SIZE: usize = 0x100;
+
+/// `IBaseEscrow.Immutables` in contract units
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Immutables {
+    pub order_hash: [u8; 32],
+    pub hash_lock: [u8; 32],
+    pub maker: AccountId,
+    pub taker: AccountId,
+    pub token: AccountId,
+    pub amount: u128,
+    pub safety_deposit: u128,
+    pub deployed_at: u64,       // Milliseconds, 0 while undeployed
+    pub stage_starts: [u64; 7], // Milliseconds, in `TimelocksLib.Stage` order
+}
+
+/// Big-endian u128 left-padded to a uint256 word
+fn u128_word(value: u128) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[16..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+/// Millisecond timestamp to `TimelocksLib` seconds, saturating at u32::MAX
+fn to_secs(millis: u64) -> u32 {
+    u32::try_from(millis / 1000).unwrap_or(u32::MAX)
+}
+
+/// `Timelocks` uint256 for stages starting at `stage_starts`, relative to `deployed_at` (ms)
+pub fn pack_timelocks(stage_starts: &[u64; 7], deployed_at: u64) -> [u8; 32] {
+    let deployed = to_secs(deployed_at);
+    let mut word = [0u8; 32];
+    word[..4].copy_from_slice(&deployed.to_be_bytes());
+    for (stage, start) in stage_starts.iter().enumerate() {
+        let offset = to_secs(*start).saturating_sub(deployed);
+        let end = 32 - stage * 4;
+        word[end - 4..end].copy_from_slice(&offset.to_be_bytes());
+    }
+    word
+}
+
+/// `abi.encode(immutables)` as hashed by `ImmutablesLib.hashMem`
+pub fn immutables_preimage(immutables: &Immutables) -> [u8; ESCROW_IMMUTABLES_SIZE] {
+    let words: [[u8; 32]; 8] = [
+        immutables.order_hash,
+        immutables.hash_lock,
+        *immutables.maker.as_ref(),
+        *immutables.taker.as_ref(),
+        *immutables.token.as_ref(),
+        u128_word(immutables.amount),
+        u128_word(immutables.safety_deposit),
+        pack_timelocks(&immutables.stage_starts, immutables.deployed_at),
+    ];
+
+    let mut preimage = [0u8; ESCROW_IMMUTABLES_SIZE];
+    for (chunk, word) in preimage.chunks_exact_mut(32).zip(words.iter()) {
+        chunk.copy_from_slice(word);
+    }
+    preimage
+}
+
+/// CREATE2 salt shared by both chains
+pub fn escrow_salt(immutables: &Immutables) -> [u8; 32] {
+    keccak256(&immutables_preimage(immutables))
+}
+
+/// `Create2.computeAddress(salt, bytecodeHash, factory)` on Ethereum
+pub fn ethereum_escrow_address(
+    factory: &[u8; 20],
+    bytecode_hash: &[u8; 32],
+    salt: &[u8; 32],
+) -> [u8; 20] {
+    let mut preimage = [0u8; 85];
+    preimage[0] = 0xff;
+    preimage[1..21].copy_from_slice(factory);
+    preimage[21..53].copy_from_slice(salt);
+    preimage[53..].copy_from_slice(bytecode_hash);
+
+    let hash = keccak256(&preimage);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Polkadot counterpart: `keccak256(0xff ++ deployer ++ salt)`, kept at full 32 bytes
+pub fn polkadot_escrow_address(deployer: &AccountId, salt: &[u8; 32]) -> AccountId {
+    let mut preimage = [0u8; 65];
+    preimage[0] = 0xff;
+    preimage[1..33].copy_from_slice(deployer.as_ref());
+    preimage[33..].copy_from_slice(salt);
+    AccountId::from(keccak256(&preimage))
+}
@@ -0,0 +1,116 @@
+//! Ethereum-compatible hashing used for hashlocks, CREATE2 salts and EIP-712 digests
+//!
+//! By default keccak256 goes through the contracts host function. With the `pure-keccak`
+//! feature a vendored no_std implementation is used instead, for chains whose runtime does
+//! not expose it. Both produce Ethereum's keccak256 (original padding, not SHA3-256).
+
+#[cfg(not(feature = "pure-keccak"))]
+pub fn keccak256(input: &[u8]) -> [u8; 32] {
+    use ink::env::hash::{HashOutput, Keccak256};
+
+    let mut output = <Keccak256 as HashOutput>::Type::default();
+    ink::env::hash_bytes::<Keccak256>(input, &mut output);
+    output
+}
+
+#[cfg(feature = "pure-keccak")]
+pub fn keccak256(input: &[u8]) -> [u8; 32] {
+    keccak::keccak256(input)
+}
+
+/// Vendored keccak-f[1600] sponge (rate 136 bytes, capacity 512 bits)
+pub mod keccak {
+    const RATE: usize = 136;
+
+    const ROUND_CONSTANTS: [u64; 24] = [
+        0x0000_0000_0000_0001, 0x0000_0000_0000_8082, 0x8000_0000_0000_808a,
+        0x8000_0000_8000_8000, 0x0000_0000_0000_808b, 0x0000_0000_8000_0001,
+        0x8000_0000_8000_8081, 0x8000_0000_0000_8009, 0x0000_0000_0000_008a,
+        0x0000_0000_0000_0088, 0x0000_0000_8000_8009, 0x0000_0000_8000_000a,
+        0x0000_0000_8000_808b, 0x8000_0000_0000_008b, 0x8000_0000_0000_8089,
+        0x8000_0000_0000_8003, 0x8000_0000_0000_8002, 0x8000_0000_0000_0080,
+        0x0000_0000_0000_800a, 0x8000_0000_8000_000a, 0x8000_0000_8000_8081,
+        0x8000_0000_0000_8080, 0x0000_0000_8000_0001, 0x8000_0000_8000_8008,
+    ];
+
+    // Rotation offsets and lane order of the combined rho/pi step
+    const RHO: [u32; 24] = [
+        1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+    ];
+    const PI: [usize; 24] = [
+        10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+    ];
+
+    fn keccak_f(state: &mut [u64; 25]) {
+        for round_constant in ROUND_CONSTANTS.iter() {
+            // Theta
+            let mut columns = [0u64; 5];
+            for (x, column) in columns.iter_mut().enumerate() {
+                *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+            }
+            for x in 0..5 {
+                let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+                for y in 0..5 {
+                    state[y * 5 + x] ^= d;
+                }
+            }
+
+            // Rho and pi
+            let mut carry = state[1];
+            for (rotation, &lane) in RHO.iter().zip(PI.iter()) {
+                let next = state[lane];
+                state[lane] = carry.rotate_left(*rotation);
+                carry = next;
+            }
+
+            // Chi
+            for y in 0..5 {
+                let row = [
+                    state[y * 5],
+                    state[y * 5 + 1],
+                    state[y * 5 + 2],
+                    state[y * 5 + 3],
+                    state[y * 5 + 4],
+                ];
+                for x in 0..5 {
+                    state[y * 5 + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                }
+            }
+
+            // Iota
+            state[0] ^= round_constant;
+        }
+    }
+
+    fn absorb_block(state: &mut [u64; 25], block: &[u8]) {
+        for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(bytes);
+            *lane ^= u64::from_le_bytes(word);
+        }
+        keccak_f(state);
+    }
+
+    pub fn keccak256(input: &[u8]) -> [u8; 32] {
+        let mut state = [0u64; 25];
+
+        let mut blocks = input.chunks_exact(RATE);
+        for block in blocks.by_ref() {
+            absorb_block(&mut state, block);
+        }
+
+        // Keccak padding: 0x01 ... 0x80 (SHA3 would use 0x06)
+        let tail = blocks.remainder();
+        let mut last = [0u8; RATE];
+        last[..tail.len()].copy_from_slice(tail);
+        last[tail.len()] ^= 0x01;
+        last[RATE - 1] ^= 0x80;
+        absorb_block(&mut state, &last);
+
+        let mut output = [0u8; 32];
+        for (bytes, lane) in output.chunks_exact_mut(8).zip(state.iter()) {
+            bytes.copy_from_slice(&lane.to_le_bytes());
+        }
+        output
+    }
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+//! Primitives shared by the Fusion+ Polkadot contracts: Ethereum-compatible hashing, checked
+//! arithmetic, the EVM `U256` amount, maker signature verification, signature nonces, the
+//! capability bitflags, CREATE2 escrow addresses and the PSP22 client
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use scale::{Decode, Encode};
+
+pub mod capabilities;
+pub mod create2;
+pub mod hashing;
+pub mod math;
+pub mod nonces;
+pub mod psp22;
+pub mod signatures;
+
+/// Weight limit for one cross-contract call (0 in a field = no limit for that dimension)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct GasBudget {
+    pub ref_time: u64,
+    pub proof_size: u64,
+}
+
+impl GasBudget {
+    /// Lets the callee use whatever weight the caller has left
+    pub const UNLIMITED: Self = Self { ref_time: 0, proof_size: 0 };
+}
+
+/// 256-bit unsigned amount for EVM-denominated values, stored big-endian as in the EVM ABI
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct U256(pub [u8; 32]);
+
+impl U256 {
+    pub const ZERO: Self = Self([0u8; 32]);
+    pub const MAX: Self = Self([0xffu8; 32]);
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::ZERO
+    }
+
+    /// Checked conversion into a native `u128` balance
+    pub fn checked_to_u128(self) -> Option<u128> {
+        if self.0[..16].iter().any(|b| *b != 0) {
+            return None;
+        }
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&self.0[16..]);
+        Some(u128::from_be_bytes(low))
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let mut out = [0u8; 32];
+        let mut carry = 0u16;
+        for i in (0..32).rev() {
+            let sum = self.0[i] as u16 + other.0[i] as u16 + carry;
+            out[i] = sum as u8;
+            carry = sum >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+        Some(Self(out))
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        if self < other {
+            return None;
+        }
+        let mut out = [0u8; 32];
+        let mut borrow = 0i16;
+        for i in (0..32).rev() {
+            let mut diff = self.0[i] as i16 - other.0[i] as i16 - borrow;
+            borrow = 0;
+            if diff < 0 {
+                diff += 256;
+                borrow = 1;
+            }
+            out[i] = diff as u8;
+        }
+        Some(Self(out))
+    }
+}
+
+impl U256 {
+    /// Multiply by a small factor, `None` on overflow
+    pub fn checked_mul_small(self, factor: u32) -> Option<Self> {
+        let mut out = [0u8; 32];
+        let mut carry = 0u64;
+        for i in (0..32).rev() {
+            let prod = self.0[i] as u64 * factor as u64 + carry;
+            out[i] = prod as u8;
+            carry = prod >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+        Some(Self(out))
+    }
+
+    /// Divide by a small non-zero divisor, rounding down
+    pub fn div_small(self, divisor: u32) -> Self {
+        let mut out = [0u8; 32];
+        let mut rem = 0u64;
+        for (byte, limb) in out.iter_mut().zip(self.0.iter()) {
+            let cur = (rem << 8) | *limb as u64;
+            *byte = (cur / divisor as u64) as u8;
+            rem = cur % divisor as u64;
+        }
+        Self(out)
+    }
+}
+
+impl From<u128> for U256 {
+    fn from(value: u128) -> Self {
+        let mut bytes = [0u8; 32];
+        bytes[16..].copy_from_slice(&value.to_be_bytes());
+        Self(bytes)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,131 @@
+//! Checked no_std arithmetic for fees, price curves and decimal normalization
+//!
+//! Ratios are either basis points (`bps_of`) or 1e18-scaled `Fixed` values. Products are
+//! taken at 256-bit width before dividing, so `mul_div` only fails when the *result* does not
+//! fit in a u128 (or the denominator is zero). Everything rounds down.
+
+/// Basis-point denominator (100% = 10_000)
+pub const BPS_DENOMINATOR: u128 = 10_000;
+
+/// 1.0 in `Fixed` representation
+pub const FIXED_ONE: u128 = 1_000_000_000_000_000_000;
+
+const LOW_MASK: u128 = u64::MAX as u128;
+
+/// Full 256-bit product as (high, low) halves
+fn mul_wide(a: u128, b: u128) -> (u128, u128) {
+    let (a_hi, a_lo) = (a >> 64, a & LOW_MASK);
+    let (b_hi, b_lo) = (b >> 64, b & LOW_MASK);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & LOW_MASK) + (hi_lo & LOW_MASK);
+    let low = (lo_lo & LOW_MASK) | (mid << 64);
+    let high = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+    (high, low)
+}
+
+/// `a * b / denominator`, rounding down; `None` on a zero denominator or a u128 overflow
+pub fn mul_div(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    let (high, low) = mul_wide(a, b);
+    if high == 0 {
+        return Some(low / denominator);
+    }
+    if high >= denominator {
+        return None;
+    }
+
+    // Restoring long division of the 256-bit product; `high < denominator` keeps the
+    // quotient within 128 bits
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
+
+/// `amount * bps / 10_000`, rounding down
+pub fn bps_of(amount: u128, bps: u32) -> Option<u128> {
+    mul_div(amount, bps as u128, BPS_DENOMINATOR)
+}
+
+/// Straight line from `start` (at 0) to `end` (at `duration`), evaluated at `elapsed`
+/// (clamped to `duration`); used for decaying fees and Dutch-auction prices
+pub fn lerp(start: u128, end: u128, elapsed: u64, duration: u64) -> Option<u128> {
+    if duration == 0 {
+        return Some(end);
+    }
+    let elapsed = elapsed.min(duration) as u128;
+    let duration = duration as u128;
+    if end >= start {
+        start.checked_add(mul_div(end - start, elapsed, duration)?)
+    } else {
+        start.checked_sub(mul_div(start - end, elapsed, duration)?)
+    }
+}
+
+/// `10^exponent`, `None` past u128
+pub fn pow10(exponent: u8) -> Option<u128> {
+    10u128.checked_pow(exponent as u32)
+}
+
+/// Re-express `amount` from `from_decimals` to `to_decimals` base units (rounds down)
+pub fn rescale(amount: u128, from_decimals: u8, to_decimals: u8) -> Option<u128> {
+    if to_decimals >= from_decimals {
+        amount.checked_mul(pow10(to_decimals - from_decimals)?)
+    } else {
+        Some(amount / pow10(from_decimals - to_decimals)?)
+    }
+}
+
+/// Unsigned 1e18 fixed-point number
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Fixed(pub u128);
+
+impl Fixed {
+    pub const ZERO: Self = Self(0);
+    pub const ONE: Self = Self(FIXED_ONE);
+
+    /// `numerator / denominator`
+    pub fn from_ratio(numerator: u128, denominator: u128) -> Option<Self> {
+        mul_div(numerator, FIXED_ONE, denominator).map(Self)
+    }
+
+    pub fn from_bps(bps: u32) -> Self {
+        Self(bps as u128 * (FIXED_ONE / BPS_DENOMINATOR))
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        mul_div(self.0, other.0, FIXED_ONE).map(Self)
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        mul_div(self.0, FIXED_ONE, other.0).map(Self)
+    }
+
+    /// Apply to a plain integer amount, rounding down
+    pub fn mul_int(self, amount: u128) -> Option<u128> {
+        mul_div(amount, self.0, FIXED_ONE)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+//! Per-account nonce registry shared by every signature-accepting entry point
+//!
+//! A signer authorizes `digest(verifying_contract, payload_hash, nonce)` with its current nonce
+//! (`get_nonce`); accepting the signature consumes that nonce, so each signature is good for
+//! exactly one use on exactly one contract and signatures are accepted strictly in order. A
+//! signer revokes its outstanding signatures by skipping past their nonces.
+
+use ink::env::hash::{Blake2x256, HashOutput};
+use ink::primitives::AccountId;
+use ink::storage::Mapping;
+
+/// What a signer signs: Blake2x256 of SCALE `(verifying_contract, payload_hash, nonce)`
+pub fn digest(verifying_contract: AccountId, payload_hash: [u8; 32], nonce: u64) -> [u8; 32] {
+    let mut output = <Blake2x256 as HashOutput>::Type::default();
+    ink::env::hash_encoded::<Blake2x256, _>(&(verifying_contract, payload_hash, nonce), &mut output);
+    output
+}
+
+#[ink::storage_item]
+#[derive(Debug, Default)]
+pub struct NonceRegistry {
+    nonces: Mapping<AccountId, u64>, // Next nonce a signature from the account must cover
+}
+
+impl NonceRegistry {
+    /// Nonce the account's next signature must cover
+    pub fn get(&self, account: AccountId) -> u64 {
+        self.nonces.get(account).unwrap_or(0)
+    }
+
+    /// Retire the account's current nonce once a signature over it has been accepted
+    pub fn consume(&mut self, account: AccountId) -> u64 {
+        let nonce = self.get(account);
+        self.nonces.insert(account, &nonce.saturating_add(1));
+        nonce
+    }
+
+    /// Retire every nonce up to and including `nonce`; `false` if it was already retired
+    pub fn invalidate_through(&mut self, account: AccountId, nonce: u64) -> bool {
+        if nonce < self.get(account) {
+            return false;
+        }
+        self.nonces.insert(account, &nonce.saturating_add(1));
+        true
+    }
+}
\ No newline at end of file
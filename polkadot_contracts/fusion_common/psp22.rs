@@ -0,0 +1,86 @@
+//! Minimal PSP22 cross-contract client used for token-denominated flows
+
+use ink::env::call::{build_call, ExecutionInput, Selector};
+use ink::env::DefaultEnvironment;
+use ink::prelude::{string::String, vec::Vec};
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+
+use crate::GasBudget;
+
+// Standard PSP22 message selectors
+pub const TRANSFER_SELECTOR: [u8; 4] = [0xdb, 0x20, 0xf9, 0xf5];
+pub const TRANSFER_FROM_SELECTOR: [u8; 4] = [0x54, 0xb3, 0xc7, 0x6e];
+pub const BALANCE_OF_SELECTOR: [u8; 4] = [0x65, 0x68, 0x38, 0x2f];
+
+/// PSP22 standard error (decoded from the token's return value)
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Psp22Error {
+    Custom(String),
+    InsufficientBalance,
+    InsufficientAllowance,
+    ZeroRecipientAddress,
+    ZeroSenderAddress,
+    SafeTransferCheckFailed(String),
+}
+
+fn call_failed() -> Psp22Error {
+    Psp22Error::Custom(String::from("call failed"))
+}
+
+/// `PSP22::transfer` from the calling contract
+pub fn transfer(token: AccountId, to: AccountId, value: u128, gas: GasBudget) -> Result<(), Psp22Error> {
+    build_call::<DefaultEnvironment>()
+        .call(token)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(
+            ExecutionInput::new(Selector::new(TRANSFER_SELECTOR))
+                .push_arg(to)
+                .push_arg(value)
+                .push_arg(Vec::<u8>::new()),
+        )
+        .returns::<Result<(), Psp22Error>>()
+        .try_invoke()
+        .map_err(|_| call_failed())?
+        .map_err(|_| call_failed())?
+}
+
+/// `PSP22::transfer_from`, requires a prior allowance to the calling contract
+pub fn transfer_from(
+    token: AccountId,
+    from: AccountId,
+    to: AccountId,
+    value: u128,
+    gas: GasBudget,
+) -> Result<(), Psp22Error> {
+    build_call::<DefaultEnvironment>()
+        .call(token)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(
+            ExecutionInput::new(Selector::new(TRANSFER_FROM_SELECTOR))
+                .push_arg(from)
+                .push_arg(to)
+                .push_arg(value)
+                .push_arg(Vec::<u8>::new()),
+        )
+        .returns::<Result<(), Psp22Error>>()
+        .try_invoke()
+        .map_err(|_| call_failed())?
+        .map_err(|_| call_failed())?
+}
+
+/// `PSP22::balance_of`
+pub fn balance_of(token: AccountId, owner: AccountId, gas: GasBudget) -> Result<u128, Psp22Error> {
+    build_call::<DefaultEnvironment>()
+        .call(token)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(ExecutionInput::new(Selector::new(BALANCE_OF_SELECTOR)).push_arg(owner))
+        .returns::<u128>()
+        .try_invoke()
+        .map_err(|_| call_failed())?
+        .map_err(|_| call_failed())
+}
@@ -0,0 +1,54 @@
+//! Maker signature checks for sr25519 and ECDSA wallets
+//!
+//! Both accept the signature over the raw hash as well as the wrapped form browser wallets
+//! produce when asked to sign arbitrary bytes.
+
+use alloc::vec::Vec;
+use ink::env::hash::{Blake2x256, HashOutput};
+use ink::primitives::AccountId;
+
+/// Accepts a signature over the raw hash or the `<Bytes>`-wrapped payload wallets sign
+pub fn sr25519_signed(signer: &AccountId, hash: &[u8; 32], signature: &[u8; 64]) -> bool {
+    let pub_key: &[u8; 32] = signer.as_ref();
+    if ink::env::sr25519_verify(signature, hash, pub_key).is_ok() {
+        return true;
+    }
+
+    let mut wrapped = Vec::with_capacity(47);
+    wrapped.extend_from_slice(b"<Bytes>");
+    wrapped.extend_from_slice(hash);
+    wrapped.extend_from_slice(b"</Bytes>");
+    ink::env::sr25519_verify(signature, &wrapped, pub_key).is_ok()
+}
+
+/// Recovers the signer of the raw hash or of its EIP-191 `personal_sign` form; the signer
+/// is either the Substrate ECDSA account (Blake2x256 of the compressed key) or an
+/// Ethereum address left-padded into the account
+pub fn ecdsa_signed(signer: &AccountId, hash: &[u8; 32], signature: &[u8; 65]) -> bool {
+    let mut signature = *signature;
+    if signature[64] >= 27 {
+        signature[64] -= 27;
+    }
+
+    let mut personal = [0u8; 60];
+    personal[..28].copy_from_slice(b"\x19Ethereum Signed Message:\n32");
+    personal[28..].copy_from_slice(hash);
+    let personal_hash = crate::hashing::keccak256(&personal);
+
+    let signer: &[u8; 32] = signer.as_ref();
+    [*hash, personal_hash].iter().any(|message| {
+        let mut public_key = [0u8; 33];
+        if ink::env::ecdsa_recover(&signature, message, &mut public_key).is_err() {
+            return false;
+        }
+        let mut account = <Blake2x256 as HashOutput>::Type::default();
+        ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+        if account == *signer {
+            return true;
+        }
+        let mut eth_address = [0u8; 20];
+        ink::env::ecdsa_to_eth_address(&public_key, &mut eth_address).is_ok()
+            && signer[..12] == [0u8; 12]
+            && signer[12..] == eth_address
+    })
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+//! Fixed-point and basis-point helpers in `math`.
+
+use fusion_common::math::{self, Fixed, FIXED_ONE};
+
+#[test]
+fn mul_div_uses_a_wide_intermediate() {
+    // u128::MAX * 3 overflows u128 but the quotient fits
+    assert_eq!(math::mul_div(u128::MAX, 3, 3), Some(u128::MAX));
+    assert_eq!(math::mul_div(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+    assert_eq!(math::mul_div(1 << 100, 1 << 100, 1 << 90), Some(1 << 110));
+}
+
+#[test]
+fn mul_div_rejects_overflow_and_zero_denominator() {
+    assert_eq!(math::mul_div(u128::MAX, 2, 1), None);
+    assert_eq!(math::mul_div(1, 1, 0), None);
+}
+
+#[test]
+fn mul_div_rounds_down() {
+    assert_eq!(math::mul_div(10, 1, 3), Some(3));
+    assert_eq!(math::mul_div(u128::MAX, 2, 5), Some(u128::MAX / 5 * 2));
+    assert_eq!(math::mul_div(u128::MAX, 2, 3), Some(u128::MAX / 3 * 2));
+}
+
+#[test]
+fn bps_of_matches_naive_math_in_range() {
+    assert_eq!(math::bps_of(1_000_000, 30), Some(3_000));
+    assert_eq!(math::bps_of(9_999, 1), Some(0));
+    assert_eq!(math::bps_of(u128::MAX, 10_000), Some(u128::MAX));
+}
+
+#[test]
+fn lerp_moves_in_both_directions_and_clamps() {
+    assert_eq!(math::lerp(100, 200, 5, 10), Some(150));
+    assert_eq!(math::lerp(200, 100, 5, 10), Some(150));
+    assert_eq!(math::lerp(200, 100, 50, 10), Some(100));
+    assert_eq!(math::lerp(200, 100, 0, 0), Some(100));
+}
+
+#[test]
+fn rescale_between_decimals() {
+    assert_eq!(math::rescale(1_234, 10, 18), Some(123_400_000_000));
+    assert_eq!(math::rescale(123_456_789_000_000_000, 18, 10), Some(1_234_567_890));
+    assert_eq!(math::rescale(1, 0, 39), None);
+}
+
+#[test]
+fn fixed_point_ops() {
+    let half = Fixed::from_ratio(1, 2).unwrap();
+    assert_eq!(half, Fixed(FIXED_ONE / 2));
+    assert_eq!(Fixed::from_bps(5_000), half);
+    assert_eq!(half.checked_mul(half), Fixed::from_ratio(1, 4));
+    assert_eq!(Fixed::ONE.checked_div(half), Some(Fixed(2 * FIXED_ONE)));
+    assert_eq!(half.checked_add(half), Some(Fixed::ONE));
+    assert_eq!(Fixed::ZERO.checked_sub(half), None);
+    assert_eq!(half.mul_int(1_001), Some(500));
+    assert_eq!(Fixed::ONE.checked_div(Fixed::ZERO), None);
+}
\ No newline at end of file
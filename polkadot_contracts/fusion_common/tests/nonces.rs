@@ -0,0 +1,61 @@
+//! Nonce registry: digests bind the verifying contract, payload and nonce, and each account's
+//! nonces are consumed once, strictly in order.
+
+use fusion_common::nonces::{digest, NonceRegistry};
+use ink::env::{test, DefaultEnvironment};
+use ink::primitives::AccountId;
+
+type Env = DefaultEnvironment;
+
+const ORDER_HASH: [u8; 32] = [0x11; 32];
+const CONTRACT: [u8; 32] = [0xc0; 32];
+
+#[test]
+fn digest_binds_contract_payload_and_nonce() {
+    let mut encoded = CONTRACT.to_vec();
+    encoded.extend_from_slice(&ORDER_HASH);
+    encoded.extend_from_slice(&7u64.to_le_bytes());
+    let mut expected = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&encoded, &mut expected);
+
+    let contract = AccountId::from(CONTRACT);
+    assert_eq!(digest(contract, ORDER_HASH, 7), expected);
+    assert_ne!(digest(contract, ORDER_HASH, 7), digest(contract, ORDER_HASH, 8));
+    assert_ne!(digest(contract, ORDER_HASH, 7), digest(contract, [0x12; 32], 7));
+    assert_ne!(digest(contract, ORDER_HASH, 7), digest(AccountId::from([0xc1; 32]), ORDER_HASH, 7));
+}
+
+#[test]
+fn nonces_are_consumed_in_order_per_account() {
+    test::run_test::<Env, _>(|_| {
+        let alice = AccountId::from([0x01; 32]);
+        let bob = AccountId::from([0x02; 32]);
+        let mut registry = NonceRegistry::default();
+
+        assert_eq!(registry.get(alice), 0);
+        assert_eq!(registry.consume(alice), 0);
+        assert_eq!(registry.consume(alice), 1);
+        assert_eq!(registry.get(alice), 2);
+        assert_eq!(registry.get(bob), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn invalidating_skips_every_nonce_through_the_given_one() {
+    test::run_test::<Env, _>(|_| {
+        let alice = AccountId::from([0x01; 32]);
+        let mut registry = NonceRegistry::default();
+
+        assert!(registry.invalidate_through(alice, 4));
+        assert_eq!(registry.get(alice), 5);
+        // Retired nonces stay retired
+        assert!(!registry.invalidate_through(alice, 4));
+        assert!(!registry.invalidate_through(alice, 0));
+        assert!(registry.invalidate_through(alice, 5));
+        assert_eq!(registry.consume(alice), 6);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
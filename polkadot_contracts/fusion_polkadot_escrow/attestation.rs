@@ -0,0 +1,43 @@
+//! Canonical attestation of an Ethereum event log, shared by the escrow, the relayer and the
+//! Solidity escrow
+//!
+//! The digest is `keccak256(abi.encode(chainId, emitter, txHash, logIndex, dataHash))`: five
+//! 32-byte words, integers big-endian and left-padded, the emitter address in the low 20 bytes.
+//! `data_hash` is `keccak256` of the log's data field. Fixture vectors in
+//! `tests/attestation.rs` pin the layout for the other implementations.
+
+use scale::{Decode, Encode};
+
+use crate::hashing::keccak256;
+
+/// `abi.encode` size of an attestation
+pub const ATTESTATION_SIZE: usize = 5 * 32;
+
+/// What is being proven: one log emitted by `emitter` in `tx_hash` on `chain_id`
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct EthereumLogAttestation {
+    pub chain_id: u64,
+    pub emitter: [u8; 20],   // Contract that emitted the log
+    pub tx_hash: [u8; 32],
+    pub log_index: u32,      // Index within the block, as in the receipt
+    pub data_hash: [u8; 32],
+}
+
+impl EthereumLogAttestation {
+    /// `abi.encode(chainId, emitter, txHash, logIndex, dataHash)`
+    pub fn preimage(&self) -> [u8; ATTESTATION_SIZE] {
+        let mut preimage = [0u8; ATTESTATION_SIZE];
+        preimage[24..32].copy_from_slice(&self.chain_id.to_be_bytes());
+        preimage[44..64].copy_from_slice(&self.emitter);
+        preimage[64..96].copy_from_slice(&self.tx_hash);
+        preimage[124..128].copy_from_slice(&self.log_index.to_be_bytes());
+        preimage[128..160].copy_from_slice(&self.data_hash);
+        preimage
+    }
+
+    /// Digest signed or proven by the attesting side
+    pub fn digest(&self) -> [u8; 32] {
+        keccak256(&self.preimage())
+    }
+}
\ No newline at end of file
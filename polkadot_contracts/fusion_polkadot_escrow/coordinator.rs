@@ -0,0 +1,65 @@
+//! Cross-contract client for the relayer/resolver coordinator
+
+use ink::env::call::{build_call, ExecutionInput, Selector};
+use ink::env::{DefaultEnvironment, Environment};
+use ink::primitives::AccountId;
+
+use crate::fusion_polkadot_escrow::GasBudget;
+
+type Balance = <DefaultEnvironment as Environment>::Balance;
+
+/// Coordinator message selectors
+pub const GET_REPUTATION_SELECTOR: [u8; 4] = ink::selector_bytes!("get_reputation");
+pub const GET_STAKE_SELECTOR: [u8; 4] = ink::selector_bytes!("get_stake");
+pub const ORDER_SETTLED_SELECTOR: [u8; 4] = ink::selector_bytes!("order_settled");
+
+/// `get_reputation(account) -> u32` on the coordinator; `None` if the call fails
+pub fn get_reputation(coordinator: AccountId, account: AccountId, gas: GasBudget) -> Option<u32> {
+    build_call::<DefaultEnvironment>()
+        .call(coordinator)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(ExecutionInput::new(Selector::new(GET_REPUTATION_SELECTOR)).push_arg(account))
+        .returns::<u32>()
+        .try_invoke()
+        .ok()?
+        .ok()
+}
+
+
+/// `get_stake(account) -> Balance` on the coordinator; `None` if the call fails
+pub fn get_stake(coordinator: AccountId, account: AccountId, gas: GasBudget) -> Option<Balance> {
+    build_call::<DefaultEnvironment>()
+        .call(coordinator)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(ExecutionInput::new(Selector::new(GET_STAKE_SELECTOR)).push_arg(account))
+        .returns::<Balance>()
+        .try_invoke()
+        .ok()?
+        .ok()
+}
+
+
+/// `order_settled(order_hash, resolver, executed)` on the coordinator; `false` if the call fails
+pub fn order_settled(
+    coordinator: AccountId,
+    order_hash: [u8; 32],
+    resolver: AccountId,
+    executed: bool,
+    gas: GasBudget,
+) -> bool {
+    build_call::<DefaultEnvironment>()
+        .call(coordinator)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(
+            ExecutionInput::new(Selector::new(ORDER_SETTLED_SELECTOR))
+                .push_arg(order_hash)
+                .push_arg(resolver)
+                .push_arg(executed),
+        )
+        .returns::<()>()
+        .try_invoke()
+        .is_ok_and(|result| result.is_ok())
+}
\ No newline at end of file
@@ -1,14 +1,182 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink::storage::Mapping;
-use scale::{Decode, Encode};
+use ink::storage::{traits::StorageKey, Lazy, Mapping};
+use scale::{Decode, Encode, EncodeLike};
+
+pub mod attestation;
+pub mod coordinator;
+pub mod runtime_transfer;
+pub mod signature_validator;
+
+pub use fusion_common::{capabilities, create2, hashing, math, nonces, psp22, U256};
+
+/// Semantic version of the contract interface (major, minor, patch)
+pub const CONTRACT_VERSION: (u16, u16, u16) = (0, 2, 0);
+
+/// Storage layout version this code expects; `migrate` brings older storage up to it
+pub const STORAGE_VERSION: u32 = 1;
 
 /// Main Fusion+ Cross-Chain Escrow Contract for Polkadot - Compatible with Resolver
 #[ink::contract]
-mod fusion_polkadot_escrow {
+pub mod fusion_polkadot_escrow {
     use super::*;
     use ink::prelude::vec::Vec; // Import Vec for no_std environment
 
+    /// Fee share ledger key: (recipient, asset), `None` asset is native
+    type FeeShareKey = (AccountId, Option<AccountId>);
+
+    /// Claimable balance key: (payee, asset), `None` asset is native
+    type ClaimKey = (AccountId, Option<AccountId>);
+
+    /// Unreleased safety deposit of a timed-out resolver, split before the cancelled order is
+    /// committed and paid out after
+    struct TimedOutDeposit {
+        resolver: AccountId,
+        tip: Balance,         // To a third-party canceller
+        to_maker: Balance,
+        to_treasury: Balance,
+    }
+
+    /// How one fill of an order's source funds is split, shared by full and partial execution
+    struct FillSplit {
+        maker_fee: Balance,
+        taker_fee: Balance,          // Charged: owed, capped at the resolver's payout
+        taker_fee_owed: Balance,     // After the reputation discount
+        taker_fee_discount: Balance,
+        underwriter_fee: Balance,    // Out of the resolver fee
+        resolver_fee: Balance,       // After late-fee decay and the underwriter's share
+        late_fee_rebate: Balance,    // Decayed part of the resolver fee, back to the maker
+        to_maker: Balance,           // Net amount plus rebate (EthereumToPolkadot payout)
+        resolver_payout: Balance,    // Net of the taker fee
+    }
+
+    /// Order index cell key: (list key, index within the list)
+    type IndexKey<K> = (K, u32);
+
+    /// Maximum size of the integrator metadata attached to an order
+    pub const MAX_ORDER_METADATA_LEN: usize = 64;
+
+    /// Maximum number of resolvers an order can exclude
+    pub const MAX_BLOCKED_RESOLVERS: usize = 16;
+
+    /// Maximum size of the encrypted memo passed to the resolver
+    pub const MAX_ENCRYPTED_MEMO_LEN: usize = 256;
+
+    /// Maximum length of a `ExternalAddress::Raw` counterpart address
+    pub const MAX_RAW_ADDRESS_LEN: usize = 64;
+
+    /// `src_token` of every order: the source amount is native value attached to `create_order`
+    pub const NATIVE_SRC_TOKEN: [u8; 32] = [0u8; 32];
+
+    /// Decimals assumed for source tokens without registry metadata (DOT)
+    pub const DEFAULT_SRC_DECIMALS: u8 = 10;
+    /// Decimals assumed for Ethereum tokens without registry metadata
+    pub const DEFAULT_DST_DECIMALS: u8 = 18;
+    /// Upper bound for registered token decimals
+    pub const MAX_TOKEN_DECIMALS: u8 = 36;
+
+    /// Maximum size of the emergency guardian set
+    pub const MAX_GUARDIANS: u32 = 10;
+
+    /// Maximum size of the owner recovery council
+    pub const MAX_RECOVERY_COUNCIL: u32 = 10;
+    /// Shortest allowed delay between a recovery proposal and its execution (7 days)
+    pub const MIN_RECOVERY_DELAY: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// Shortest allowed delay between `request_rescue` and `rescue_funds` (1 day)
+    pub const MIN_RESCUE_DELAY: Timestamp = 24 * 60 * 60 * 1000;
+    /// Default rescue delay (7 days)
+    pub const DEFAULT_RESCUE_DELAY: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// Delay between `announce_upgrade` and `upgrade` (7 days)
+    pub const UPGRADE_DELAY: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// Maximum number of protocol fee recipients
+    pub const MAX_FEE_RECIPIENTS: usize = 8;
+
+    /// Maximum number of express-lane size tiers
+    pub const MAX_SIZE_TIERS: usize = 4;
+
+    /// Maximum number of reputation-based taker-fee discount tiers
+    pub const MAX_REPUTATION_DISCOUNTS: usize = 4;
+
+    /// Maximum number of price levels `get_depth` returns
+    pub const MAX_DEPTH_LEVELS: u32 = 20;
+
+    /// Maximum number of extra PSP22 legs in a basket order
+    pub const MAX_BASKET_LEGS: usize = 8;
+
+    /// Maximum number of child orders `split_order` may create
+    pub const MAX_SPLIT_PARTS: u32 = 16;
+
+    /// Maximum number of partial fills per order; the completing fill is always accepted
+    pub const MAX_PARTIAL_FILLS: u32 = 32;
+
+    /// Default lifetime of a resolver's hash-lock pre-commitment (2 minutes)
+    pub const DEFAULT_COMMITMENT_TTL: Timestamp = 2 * 60 * 1000;
+
+    /// Longest secret preimage accepted by the `*_with_preimage` paths
+    pub const MAX_SECRET_LEN: usize = 64;
+
+    /// Longest evidence blob a relayer may attach to `relayer_initiated_refund`
+    pub const MAX_REFUND_EVIDENCE_LEN: usize = 256;
+
+    /// Default exclusive execution window granted by `commit_execution` (1 minute)
+    pub const DEFAULT_EXECUTION_EXCLUSIVITY: Timestamp = 60 * 1000;
+
+    /// Default head start a maker's `preferred_resolver` gets before other resolvers (30 seconds)
+    pub const DEFAULT_REFERRAL_EXCLUSIVITY: Timestamp = 30 * 1000;
+
+    /// Default share of a timed-out resolver's safety deposit paid to the maker; the rest goes
+    /// to the protocol fee vault
+    pub const DEFAULT_SLASH_MAKER_SHARE_BPS: u32 = 5_000;
+
+    /// Default shortest order lifetime `create_order` accepts (5 minutes)
+    pub const DEFAULT_MIN_ORDER_DURATION: Timestamp = 5 * 60 * 1000;
+
+    /// Default longest order lifetime `create_order` accepts (30 days)
+    pub const DEFAULT_MAX_ORDER_DURATION: Timestamp = 30 * 24 * 60 * 60 * 1000;
+
+    /// Default delay between `unregister_resolver` and the stake becoming withdrawable (7 days)
+    pub const DEFAULT_RESOLVER_UNBONDING_PERIOD: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// Default budget for PSP22 token calls
+    pub const DEFAULT_TOKEN_CALL_GAS: GasBudget = GasBudget { ref_time: 10_000_000_000, proof_size: 256 * 1024 };
+    /// Default budget for coordinator queries
+    pub const DEFAULT_COORDINATOR_CALL_GAS: GasBudget = GasBudget { ref_time: 5_000_000_000, proof_size: 128 * 1024 };
+    /// Default budget for contract-wallet signature checks
+    pub const DEFAULT_SIGNATURE_CALL_GAS: GasBudget = GasBudget { ref_time: 5_000_000_000, proof_size: 128 * 1024 };
+
+    /// Maximum number of cache entries one `refresh_remote_cache` call re-reads
+    pub const MAX_REMOTE_REFRESH_KEYS: usize = 16;
+
+    /// Coordinator reputation at or above which `get_order_risk` sees no resolver risk
+    pub const RISK_REFERENCE_REPUTATION: u32 = 100;
+
+    /// Safety deposit, in bps of the order, at or above which `get_order_risk` sees no deposit risk
+    pub const RISK_REFERENCE_DEPOSIT_BPS: u32 = 1_000;
+
+    /// Maximum number of orders one page (`export_orders`, `get_orders_by_*`) or batch
+    /// (`import_orders`, `prune_expired_orders`) covers
+    pub const MAX_PAGE_LEN: u32 = 50;
+
+    /// Rough weight of an execution's own logic and storage, excluding payouts and calls
+    pub const EXECUTE_BASE_WEIGHT: GasBudget = GasBudget { ref_time: 3_000_000_000, proof_size: 64 * 1024 };
+    /// Rough weight of crediting one payout to a claimable balance (a storage write and its event)
+    pub const PAYOUT_CREDIT_WEIGHT: GasBudget = GasBudget { ref_time: 250_000_000, proof_size: 4 * 1024 };
+
+    /// Default window after `fill_deadline` in which a valid secret still executes (5 minutes)
+    pub const DEFAULT_EXECUTION_GRACE_PERIOD: Timestamp = 5 * 60 * 1000;
+
+    /// Approximate bytes stored per order (body, hot state, index entries) excluding metadata
+    pub const ORDER_STORAGE_BASE_BYTES: u32 = 512;
+
+    /// Default time a settled order stays in full before it may be archived (30 days)
+    pub const DEFAULT_ARCHIVE_RETENTION: Timestamp = 30 * 24 * 60 * 60 * 1000;
+
+    /// Default time after creation until the maker may cancel privately (30 minutes)
+    pub const DEFAULT_PRIVATE_CANCELLATION_PERIOD: Timestamp = 30 * 60 * 1000;
+
     // --- Core Types (Aligned with Resolver) ---
 
     /// Cross-chain swap direction
@@ -29,22 +197,112 @@ mod fusion_polkadot_escrow {
         Executed,     // Successfully completed
         Cancelled,    // Cancelled before execution
         Refunded,     // Refunded after timelock expiry
+        Split,        // Replaced by child orders
     }
 
-    /// Time locks structure (matches resolver)
-    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    /// Time locks structure (matches resolver). `fill_deadline` and `private_cancellation` are
+    /// set at creation; the Fusion+ stage starts (absolute ms, inclusive) are stamped when the
+    /// order is locked and stay zero before that
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct TimeLocks {
         pub fill_deadline: Timestamp,      // When order expires
         pub private_cancellation: Timestamp, // Early cancellation period
+        pub src_withdrawal: Timestamp,          // Finality lock ends, resolver-only withdrawal
+        pub src_public_withdrawal: Timestamp,   // Any secret holder may withdraw
+        pub src_cancellation: Timestamp,        // Withdrawal closes, resolver-only cancellation
+        pub src_public_cancellation: Timestamp, // Anyone may cancel
+        pub dst_withdrawal: Timestamp,          // Counterpart escrow stages, packed for the
+        pub dst_public_withdrawal: Timestamp,   // Ethereum factory only
+        pub dst_cancellation: Timestamp,
+    }
+
+    impl TimeLocks {
+        /// Stage starts in `TimelocksLib.Stage` order, as `create2` packs them
+        pub fn stage_starts(&self) -> [Timestamp; 7] {
+            [
+                self.src_withdrawal,
+                self.src_public_withdrawal,
+                self.src_cancellation,
+                self.src_public_cancellation,
+                self.dst_withdrawal,
+                self.dst_public_withdrawal,
+                self.dst_cancellation,
+            ]
+        }
+
+        /// Source-escrow stage at `now`; only meaningful once the order is locked
+        pub fn src_stage(&self, now: Timestamp) -> SrcStage {
+            if now >= self.src_public_cancellation {
+                SrcStage::PublicCancellation
+            } else if now >= self.src_cancellation {
+                SrcStage::PrivateCancellation
+            } else if now >= self.src_public_withdrawal {
+                SrcStage::PublicWithdrawal
+            } else if now >= self.src_withdrawal {
+                SrcStage::PrivateWithdrawal
+            } else {
+                SrcStage::FinalityLock
+            }
+        }
+    }
+
+    /// Fusion+ stage of a locked order's source escrow, in `TimelocksLib.Stage` order
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum SrcStage {
+        FinalityLock,        // Nobody may withdraw or cancel yet
+        PrivateWithdrawal,   // Only the resolver withdraws
+        PublicWithdrawal,    // Any secret holder withdraws
+        PrivateCancellation, // Only the resolver cancels
+        PublicCancellation,  // Anyone cancels
+    }
+
+    /// How payouts outside settlement leave the contract (settlement payouts are always
+    /// claimed); fixed at construction
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum TransferBackend {
+        Direct,                          // `env().transfer`
+        ChainExtension { func_id: u32 }, // Runtime transfer function behind the chain extension
+        PullPayment,                     // Credited to the payee per asset, collected with `claim`
+    }
+
+    /// Timelock boundary an order passed without anyone acting, in the order they are reached
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum RiskBoundary {
+        FillDeadline,       // Still pending after its fill deadline
+        Cancellation,       // Locked and the resolver has not executed by the cutoff
+        PublicCancellation, // Locked and nobody has cancelled it privately either
+    }
+
+    /// Lifecycle of an order's hash lock
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum HashLockState {
+        Unset,              // No resolver has locked the order yet
+        Set([u8; 32]),      // Active: the matching secret executes the order
+        Consumed([u8; 32]), // Released by execution or cancellation
+    }
+
+    impl HashLockState {
+        /// The lock hash, whether active or consumed
+        pub fn hash(&self) -> Option<[u8; 32]> {
+            match self {
+                HashLockState::Unset => None,
+                HashLockState::Set(hash) | HashLockState::Consumed(hash) => Some(*hash),
+            }
+        }
     }
 
     /// Hash lock information
     #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct HashLockInfo {
-        pub hash_lock: [u8; 32],
-        pub secret: Option<[u8; 32]>,
+        pub hash_lock: HashLockState,
+        pub secret: Option<Vec<u8>>, // Revealed preimage, 1..=MAX_SECRET_LEN bytes
+        pub hash_algo: HashAlgo,     // Must match the counterpart escrow's hashlock
     }
 
     /// Ethereum escrow details
@@ -69,7 +327,7 @@ mod fusion_polkadot_escrow {
         pub src_token: AccountId,        // Source token (Polkadot)
         pub dst_token: [u8; 20],        // Dest token (Ethereum address)
         pub src_amount: Balance,         // Amount to swap from
-        pub dst_amount: Balance,         // Minimum amount to receive
+        pub dst_amount: U256,            // Minimum amount to receive (EVM units)
         
         // Cross-chain info
         pub direction: SwapDirection,
@@ -83,1007 +341,6809 @@ mod fusion_polkadot_escrow {
         pub status: OrderStatus,
         pub filled_amount: Balance,      // For partial fills
         pub safety_deposit: Balance,     // Resolver stake
+        pub safety_deposit_token: Option<AccountId>, // PSP22 deposit asset (None = native)
         
         // Fees and resolver
         pub resolver: Option<AccountId>,
         pub resolver_fee: Balance,
         pub created_at: Timestamp,
+
+        // Integrator tag / client id / encrypted memo (opaque, bounded)
+        pub metadata: Vec<u8>,
+
+        // Maker protection
+        pub min_resolver_reputation: Option<u32>, // Checked against the coordinator on assignment
+        pub late_fee_schedule: Option<LateFeeSchedule>,
+
+        // Set on child orders created by `split_order`
+        pub parent_order: Option<[u8; 32]>,
+
+        // Order nonce used in the hash preimage (split index for child orders)
+        pub nonce: u64,
+
+        // Extra PSP22 source legs escrowed with the native leg, released/refunded together
+        pub basket: Vec<BasketLeg>,
+
+        // Part of safety_deposit already returned to the resolver by partial fills
+        pub deposit_released: Balance,
+
+        // Counterpart-chain recipient of the destination tokens
+        pub recipient: ExternalAddress,
+
+        // Ciphertext for the resolver (destination memo, invoice id); never interpreted on-chain
+        pub encrypted_memo: Option<Vec<u8>>,
+
+        // Resolvers the maker excluded from this order (on top of their persistent block list)
+        pub blocked_resolvers: Vec<AccountId>,
+
+        // Express-lane parameters fixed at creation (None = the direction's defaults)
+        pub size_tier: Option<SizeTier>,
+
+        // Referred resolver; only it may lock the order before `preferred_until`
+        pub preferred_resolver: Option<AccountId>,
+        pub preferred_until: Timestamp,
+
+        // Time-decaying destination amount (None = fixed `dst_amount`)
+        pub auction: Option<DutchAuction>,
+
+        // Destination amount the resolver took the order at; `dst_amount` stays the signed minimum
+        pub taken_dst_amount: Option<U256>,
+
+        // When the resolver locked it, the source escrow's `deployed_at`
+        pub locked_at: Option<Timestamp>,
+    }
+
+    /// One PSP22 source leg of a basket order
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct BasketLeg {
+        pub token: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Fields hashed (SCALE-encoded, Blake2x256) into a root order's hash
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct OrderHashPreimage {
+        pub maker: AccountId,
+        pub src_token: AccountId,
+        pub dst_token: [u8; 20],
+        pub src_amount: Balance,
+        pub min_dst_amount: U256,
+        pub fill_deadline: Timestamp,
+        pub nonce: u64,
+        pub created_at: Timestamp,
+        pub basket: Vec<BasketLeg>,
+    }
+
+    /// How an order hash was derived: `Blake2x256(SCALE(preimage))` in both cases
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum HashPreimage {
+        Root(OrderHashPreimage),
+        Split { parent_order: [u8; 32], index: u32 },
     }
 
     /// Order creation parameters (matches resolver interface)
-    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct CreateOrderParams {
         pub direction: SwapDirection,
         pub src_token: AccountId,
         pub dst_token: [u8; 20],         // Ethereum token address
         pub src_amount: Balance,
-        pub min_dst_amount: Balance,
+        pub min_dst_amount: U256,
         pub fill_deadline: Timestamp,
-        pub ethereum_recipient: [u8; 20], // Where to send tokens on Ethereum
+        pub recipient: ExternalAddress,   // Where to send tokens on the counterpart chain
         pub max_resolver_fee: Balance,
+        pub metadata: Vec<u8>,            // Opaque, at most MAX_ORDER_METADATA_LEN bytes
+        pub min_resolver_reputation: Option<u32>,
+        pub late_fee_schedule: Option<LateFeeSchedule>,
+        pub basket: Vec<BasketLeg>,       // Extra PSP22 legs pulled via `transfer_from` (Polkadot -> Ethereum only)
+        pub encrypted_memo: Option<Vec<u8>>, // At most MAX_ENCRYPTED_MEMO_LEN bytes
+        pub blocked_resolvers: Vec<AccountId>, // At most MAX_BLOCKED_RESOLVERS
+        pub executor_tip: Option<ExecutorTip>, // Budget (`remaining`) is paid on top of src_amount
+        pub hash_algo: HashAlgo,          // Hashlock algorithm of the counterpart escrow
+        pub preferred_resolver: Option<AccountId>, // Frontend referral: exclusive head start
+        pub auction: Option<DutchAuction>,         // Decaying dst amount, floored at min_dst_amount
     }
 
-    /// Resolver assignment parameters (matches resolver interface)
-    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub struct ResolverParams {
-        pub resolver: AccountId,
-        pub hash_lock: [u8; 32],
-        pub ethereum_escrow_address: [u8; 20],
-        pub resolver_fee: Balance,
+    /// Address on the counterpart chain, tagged by address format
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum ExternalAddress {
+        Evm([u8; 20]),
+        Substrate(AccountId),
+        Raw(Vec<u8>),       // Anything else (e.g. Bitcoin scripts), 1..=MAX_RAW_ADDRESS_LEN bytes
     }
 
-    /// Immutable escrow parameters for resolver compatibility
+    impl ExternalAddress {
+        /// Well-formed for its format: non-zero fixed-size addresses, bounded raw bytes
+        pub fn is_valid(&self) -> bool {
+            match self {
+                ExternalAddress::Evm(address) => *address != [0u8; 20],
+                ExternalAddress::Substrate(account) => *account != AccountId::from([0u8; 32]),
+                ExternalAddress::Raw(bytes) => !bytes.is_empty() && bytes.len() <= MAX_RAW_ADDRESS_LEN,
+            }
+        }
+    }
+
+    impl OrderStatus {
+        /// Executed, Cancelled, Refunded and Split orders accept no further transitions
+        pub fn is_terminal(&self) -> bool {
+            matches!(
+                self,
+                OrderStatus::Executed | OrderStatus::Cancelled | OrderStatus::Refunded | OrderStatus::Split
+            )
+        }
+    }
+
+    /// Resolver fee decay as execution nears the fill deadline
     #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
-    pub struct EscrowImmutables {
-        pub order_hash: [u8; 32],
-        pub hash_lock: [u8; 32],
-        pub maker: AccountId,
-        pub taker: AccountId,
-        pub token: AccountId,
-        pub amount: Balance,
-        pub safety_deposit: Balance,
-        pub timelocks: TimeLocks,
-        pub deployed_at: Option<Timestamp>,
+    pub struct LateFeeSchedule {
+        pub window: Timestamp,           // Decay starts `window` ms before fill_deadline
+        pub min_fee_bps: u32,            // Fee floor at the deadline, in bps of resolver_fee
     }
 
-    // --- Events (Compatible with Resolver expectations) ---
+    /// Dutch auction on the destination amount: `start_amount` until `start_time`, falling
+    /// linearly to `end_amount` at `end_time`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct DutchAuction {
+        pub start_amount: U256,          // Must fit in a u128
+        pub end_amount: U256,            // Floor, at least the order's min_dst_amount
+        pub start_time: Timestamp,
+        pub end_time: Timestamp,         // At most fill_deadline
+    }
 
-    #[ink(event)]
-    pub struct OrderCreated {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]
-        pub maker: AccountId,
-        pub direction: SwapDirection,
-        pub src_amount: Balance,
-        pub dst_amount: Balance,
-        pub fill_deadline: Timestamp,
+    impl DutchAuction {
+        /// One child's auction when an order is split into `parts`: amounts divided like
+        /// `dst_amount`, the remainder going to the last child
+        fn share(&self, parts: u32, last: bool) -> Option<Self> {
+            let share = |amount: U256| {
+                let part = amount.div_small(parts);
+                if last {
+                    amount.checked_sub(part.checked_mul_small(parts - 1)?)
+                } else {
+                    Some(part)
+                }
+            };
+            Some(Self {
+                start_amount: share(self.start_amount)?,
+                end_amount: share(self.end_amount)?,
+                ..self.clone()
+            })
+        }
     }
 
-    #[ink(event)]
-    pub struct EscrowDeployed {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]
-        pub resolver: AccountId,
-        pub hash_lock: [u8; 32],
-        pub ethereum_escrow: [u8; 20],
-        pub safety_deposit: Balance,
+    /// Frequently mutated order fields, stored apart from the order body
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct OrderState {
+        pub status: OrderStatus,
+        pub filled_amount: Balance,
+        pub settled_at: Option<Timestamp>, // Set on first terminal transition
     }
 
-    #[ink(event)]
-    pub struct SwapExecuted {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]
-        pub resolver: AccountId,
-        pub secret: [u8; 32],
-        pub amount_filled: Balance,
+    /// Snapshot of every runtime parameter, for deploy tooling and monitoring
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EscrowConfig {
+        pub owner: AccountId,
+        pub paused: bool,
+        pub guardian_count: u32,
+        pub recovery_threshold: u32,    // 0 when no recovery council is configured
+        pub recovery_delay: Timestamp,
+        pub rescue_delay: Timestamp,
+        pub maker_fee_bps: u32,
+        pub taker_fee_bps: u32,
+        pub execution_grace_period: Timestamp,
+        pub stage_delays: StageDelays,
+        pub min_order_duration: Timestamp,
+        pub max_order_duration: Timestamp,
+        pub commitment_ttl: Timestamp,
+        pub min_resolver_stake: Balance,
+        pub permissionless_resolvers: bool,
+        pub resolver_unbonding_period: Timestamp,
+        pub execution_exclusivity: Timestamp,
+        pub referral_exclusivity: Timestamp,
+        pub min_safety_deposit: Balance,
+        pub deposit_token: Option<AccountId>,
+        pub min_token_safety_deposit: Balance,
+        pub access_token: Option<AccountId>,
+        pub min_access_token_balance: Balance,
+        pub coordinator: Option<AccountId>,
+        pub remote_cache_ttl: Timestamp,
+        pub underwriter_fee_share_bps: u32,
+        pub slash_maker_share_bps: u32,
+        pub cancellation_tip_bps: u32,
+        pub watchtower_fee: Balance,
+        pub collateral_ratio_bps: u32,
+        pub ethereum_resolver: [u8; 20],
+        pub ethereum_chain_id: u32,
+        pub src_token_list_mode: TokenListMode,
+        pub event_version: EventVersion,
+        pub allow_all_dst_tokens: bool,
+        pub archive_retention: Timestamp,
+        pub storage_deposit_per_byte: Balance,
+        pub relayer_quorum: u32,
+        pub transfer_backend: TransferBackend,
     }
 
-    #[ink(event)]
-    pub struct OrderCancelled {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        pub refund_amount: Balance,
-        pub reason: CancelReason,
+    /// Aggregated protocol figures for status pages
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ProtocolStats {
+        pub open_orders: u32,
+        pub locked_value: Vec<(AccountId, Balance)>, // Per src_token, open orders only
+        pub total_volume: Balance,
+        pub total_protocol_fees: Balance,
+        pub active_resolvers: u32,      // Resolvers with at least one open assigned order
+        pub total_escrows_created: u64,
     }
 
-    #[ink(event)]
-    pub struct PartialFillExecuted {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        pub filled_amount: Balance,
-        pub remaining_amount: Balance,
+    /// What the contract owes, per asset, by purpose
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct AssetLedger {
+        pub escrowed: Balance,     // Order src amounts and basket legs
+        pub deposits: Balance,     // Safety and storage deposits, resolver stakes, underwriting collateral
+        pub fees: Balance,         // Protocol fees not yet claimed
+        pub tips: Balance,         // Unspent executor tip budgets
+        pub payouts: Balance,      // Claimable by payees (pull-payment backend, failed payouts)
     }
 
-    // Resolver-compatible events
-    #[ink(event)]
-    pub struct SrcEscrowDeployed {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]  
-        pub escrow_address: AccountId,
-        pub immutables: EscrowImmutables,
-        pub safety_deposit: Balance,
+    impl AssetLedger {
+        pub fn total(&self) -> Balance {
+            self.escrowed
+                .saturating_add(self.deposits)
+                .saturating_add(self.fees)
+                .saturating_add(self.tips)
+                .saturating_add(self.payouts)
+        }
     }
 
-    #[ink(event)]
-    pub struct DstEscrowDeployed {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]
-        pub escrow_address: AccountId,
-        pub immutables: EscrowImmutables,
-        pub src_cancellation_timestamp: Timestamp,
+    /// Ledger entry moved by an accounting update
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LedgerEntry {
+        Escrowed,
+        Deposits,
+        Fees,
+        Tips,
+        Payouts,
     }
 
-    #[ink(event)]
-    pub struct EscrowWithdrawal {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]
-        pub escrow_address: AccountId,
-        pub secret: [u8; 32],
+    /// An asset's ledger checked against what the contract actually holds
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct AssetReconciliation {
+        pub asset: Option<AccountId>,
+        pub ledger: AssetLedger,
+        pub held: Balance,         // Contract balance (PSP22 `balance_of` for tokens)
+        pub surplus: Balance,      // Held beyond the ledger (donations, overpayment)
+        pub shortfall: Balance,    // Ledger not covered by `held`; non-zero is an accounting bug
+    }
+
+    /// Storage deposit collected for an order and who gets it back on cleanup
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct StorageDeposit {
+        pub payer: AccountId,
         pub amount: Balance,
     }
 
-    #[ink(event)]
-    pub struct EscrowCancellation {
-        #[ink(topic)]
-        pub order_hash: [u8; 32],
-        #[ink(topic)]
-        pub escrow_address: AccountId,
-        pub refund_amount: Balance,
+    /// Maker-funded tip for whoever performs a permissionless action on the order (public
+    /// execution, timed-out cancellation, archival)
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ExecutorTip {
+        pub per_action: Balance,
+        pub remaining: Balance,    // Unspent budget; all but the archival tip is refunded at settlement
     }
 
-    /// Cancellation reasons
+    /// Share of every accrued protocol fee (treasury, insurance fund, relayer pool, ...)
     #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum CancelReason {
-        MakerCancellation,
-        TimelockExpired,
-        ResolverTimeout,
-        EmergencyStop,
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct FeeRecipient {
+        pub account: AccountId,
+        pub share_bps: u32,        // Shares across all recipients add up to 10_000
     }
 
-    /// Contract errors
-    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-    pub enum Error {
-        // Order errors
-        OrderNotFound,
-        OrderAlreadyExists,
-        InvalidOrderStatus,
-        InvalidOrderHash,
-        
-        // Authorization
-        Unauthorized,
-        OnlyMaker,
-        OnlyResolver,
-        OnlyOwner,
-        
-        // Timing
-        DeadlineExpired,
-        TimelockNotExpired,
-        PrivateCancellationExpired,
-        
-        // HTLC
-        InvalidSecret,
-        InvalidHashLock,
-        HashLockAlreadyUsed,
-        InvalidImmutables,
-        
-        // Amounts
-        InsufficientFunds,
-        InsufficientDeposit,
-        InvalidAmount,
-        
-        // System
-        ContractPaused,
-        ArithmeticOverflow,
-        TransferFailed,
-        NativeTokenSendingFailure,
-        
-        // Cross-chain
-        EthereumEscrowNotSet,
-        InvalidEthereumAddress,
-        UnsupportedDirection,
-        EscrowNotFound,
-        
-        // General
-        LengthMismatch,
-        InvalidLength,
+    /// Third-party collateral backing a resolver on a specific order
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Underwriting {
+        pub underwriter: AccountId,
+        pub collateral: Balance,
+        pub fee_share_bps: u32,          // Share of the resolver fee, fixed when underwritten
     }
 
-    /// Main contract storage
-    #[ink(storage)]
-    pub struct FusionPolkadotEscrow {
-        // Core storage
-        orders: Mapping<[u8; 32], FusionOrder>,
-        active_hash_locks: Mapping<[u8; 32], [u8; 32]>, // hash_lock -> order_hash
-        escrow_addresses: Mapping<[u8; 32], AccountId>, // order_hash -> escrow_address
-        
-        // Configuration
-        owner: AccountId,
-        paused: bool,
-        protocol_fee_bps: u32,          // Basis points (100 = 1%)
-        min_safety_deposit: Balance,     // Minimum resolver stake
-        
-        // Resolver management
-        approved_resolvers: Mapping<AccountId, bool>,
-        resolver_stakes: Mapping<AccountId, Balance>,
-        
-        // Cross-chain coordination (resolver compatibility)
-        ethereum_resolver: [u8; 20],     // Ethereum counterpart address
-        trusted_relayers: Mapping<AccountId, bool>,
-        ethereum_chain_id: u32,
-        
-        // Metrics
-        order_nonce: u64,
-        total_volume: Balance,
-        total_escrows_created: u64,
+    /// Underwriter a resolver accepted for its locked order
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct UnderwriterApproval {
+        pub underwriter: AccountId,
+        pub max_fee_share_bps: u32,      // Most of the resolver fee the resolver agreed to share
     }
 
-    impl FusionPolkadotEscrow {
-        /// Constructor
-        #[ink(constructor)]
-        pub fn new(
-            protocol_fee_bps: u32,
-            min_safety_deposit: Balance,
-            ethereum_chain_id: u32,
-            ethereum_resolver: [u8; 20],
-        ) -> Self {
-            Self {
-                orders: Mapping::default(),
-                active_hash_locks: Mapping::default(),
-                escrow_addresses: Mapping::default(),
-                owner: Self::env().caller(),
-                paused: false,
-                protocol_fee_bps,
-                min_safety_deposit,
-                approved_resolvers: Mapping::default(),
-                resolver_stakes: Mapping::default(),
-                ethereum_resolver,
-                trusted_relayers: Mapping::default(),
-                ethereum_chain_id,
-                order_nonce: 0,
-                total_volume: 0,
-                total_escrows_created: 0,
+    /// Coordinator value held in the remote read cache
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum RemoteKey {
+        Reputation(AccountId), // `get_reputation(resolver)`
+        Stake(AccountId),      // `get_stake(resolver)`
+    }
+
+    /// Cached result of a coordinator read
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct CachedValue {
+        pub value: Balance,
+        pub fetched_at: Timestamp,
+        pub epoch: u32, // Stale once the coordinator changes
+    }
+
+    // Weight limit for one cross-contract call, shared with the resolver's PSP22 client
+    pub use fusion_common::GasBudget;
+
+    /// Kind of contract on the other end of a cross-contract call
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Callee {
+        Token,              // PSP22 source, basket and deposit tokens
+        Coordinator,
+        SignatureValidator, // Contract-wallet makers
+    }
+
+    /// Protocol parameters that may differ by swap direction (who fronts liquidity)
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct DirectionParams {
+        pub maker_fee_bps: u32,
+        pub taker_fee_bps: u32,
+        pub min_safety_deposit: Balance,
+        pub private_cancellation_period: Timestamp, // Creation to private cancellation, in ms
+        pub stage_delays: StageDelays,
+    }
+
+    /// Per-direction override of `DirectionParams`; each `None` field falls back to the current
+    /// global setting
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct DirectionOverrides {
+        pub maker_fee_bps: Option<u32>,
+        pub taker_fee_bps: Option<u32>,
+        pub min_safety_deposit: Option<Balance>,
+        pub private_cancellation_period: Option<Timestamp>,
+        pub stage_delays: Option<StageDelays>,
+    }
+
+    /// Fusion+ stage offsets applied when an order is locked, in ms. Withdrawal and destination
+    /// stages count from lock time; source cancellation opens right after the execution cutoff
+    /// and `src_public_cancellation` counts from there
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct StageDelays {
+        pub src_withdrawal: Timestamp,          // Finality lock
+        pub src_public_withdrawal: Timestamp,
+        pub src_public_cancellation: Timestamp, // After source cancellation opens
+        pub dst_withdrawal: Timestamp,
+        pub dst_public_withdrawal: Timestamp,
+        pub dst_cancellation: Timestamp,
+    }
+
+    /// Express-lane parameter set for orders whose size is at most `max_src_amount`, counted in
+    /// `DEFAULT_SRC_DECIMALS` units over the source and basket legs; replaces the direction's
+    /// deposit, cancellation period and stage delays
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct SizeTier {
+        pub max_src_amount: Balance,
+        pub min_safety_deposit: Balance,
+        pub private_cancellation_period: Timestamp,
+        pub stage_delays: StageDelays,
+    }
+
+    /// Taker-fee discount for resolvers whose coordinator reputation is at least `min_reputation`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ReputationDiscount {
+        pub min_reputation: u32,
+        pub discount_bps: u32,           // Off the taker fee
+    }
+
+    /// Per-callee budgets applied at every cross-contract call site
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct GasBudgets {
+        pub token: GasBudget,
+        pub coordinator: GasBudget,
+        pub signature_validator: GasBudget,
+    }
+
+    /// Bounty paid to third parties for keeper-callable maintenance (public cancellation,
+    /// archival, ...), as a share of the value the call releases
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct KeeperConfig {
+        pub bounty_bps: u32,
+        pub max_bounty: Balance,
+        pub allow_list_only: bool, // Only registered keepers earn bounties
+    }
+
+    /// Upper-bound weight of executing an order, for resolvers budgeting gas. Executions
+    /// transfer nothing: every payout is credited and collected later with `claim`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ExecutionEstimate {
+        pub ref_time: u64,
+        pub proof_size: u64,
+        pub payout_credits: u32,     // Claimable balances written
+        pub coordinator_calls: u32,  // Each bounded by the coordinator gas budget
+    }
+
+    /// Stake released by `unregister_resolver`, withdrawable once `unlocks_at` passes
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ResolverUnbonding {
+        pub amount: Balance,
+        pub unlocks_at: Timestamp,
+    }
+
+    /// Resolver's claim on an order: only this hash lock from this resolver may deploy until expiry
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct HashLockCommitment {
+        pub resolver: AccountId,
+        pub hash_lock: [u8; 32],
+        pub expires_at: Timestamp,
+    }
+
+    /// Pending commit-reveal execution: `commitment` = Blake2x256(secret ++ executor)
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ExecutionCommitment {
+        pub executor: AccountId,
+        pub commitment: [u8; 32],
+        pub committed_at_block: BlockNumber, // Reveal must land in a later block
+        pub exclusive_until: Timestamp,
+    }
+
+    /// Live order with the per-order state that travels with it between deployments
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ExportedOrder {
+        pub order: FusionOrder,
+        pub storage_deposit: Option<StorageDeposit>,
+        pub executor_tip: Option<ExecutorTip>,
+        pub underwriting: Option<Underwriting>,
+    }
+
+    /// Compact record kept for settled orders after archival
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ArchivedOrder {
+        pub record_hash: [u8; 32],       // Blake2x256 of the full SCALE-encoded final order
+        pub status: OrderStatus,
+        pub src_amount: Balance,
+        pub settled_at: Timestamp,
+    }
+
+    /// What a resolver delivered in one fill, kept for accounting until the order is archived
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct FillReceipt {
+        pub order_hash: [u8; 32],
+        pub resolver: AccountId,
+        pub fill_amount: Balance,
+        pub total_filled: Balance,      // Cumulative, including this fill
+        pub resolver_fee: Balance,      // Net of taker fee and underwriter share
+        pub maker_fee: Balance,
+        pub taker_fee: Balance,
+        pub deposit_released: Balance,
+        pub filled_at: Timestamp,
+        pub block_number: BlockNumber,
+    }
+
+    /// How `src_token` is screened at order creation
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum TokenListMode {
+        Open,      // Any token accepted
+        DenyList,  // Any token except denied ones
+        AllowList, // Only explicitly allowed tokens
+    }
+
+    /// Which event generation is emitted for orders, escrows and executions.
+    ///
+    /// Cutover path: `V1` (default) -> `Both` while indexers add V2 handlers -> `V2` once
+    /// every consumer reads the V2 events.
+    ///
+    /// V1 shapes are frozen as of V2's introduction; new fields go to V2 only. They are not the
+    /// originally released shapes: `metadata`, the `src_token` topic, the U256 `dst_amount` and
+    /// `SwapExecuted`'s `late_fee_rebate` / `in_grace_period` were added in place before the
+    /// freeze, so indexers decoding the original V1 layout must be updated whatever the version.
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum EventVersion {
+        V1,
+        Both,
+        V2,
+    }
+
+    impl EventVersion {
+        pub fn emits_v1(&self) -> bool {
+            matches!(self, EventVersion::V1 | EventVersion::Both)
+        }
+
+        pub fn emits_v2(&self) -> bool {
+            matches!(self, EventVersion::V2 | EventVersion::Both)
+        }
+    }
+
+    /// Hash function binding a secret to its hash lock
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum HashAlgo {
+        Blake2x256,
+        Keccak256,
+        Sha256,
+    }
+
+    impl HashAlgo {
+        /// Hash lock of `secret` under this algorithm
+        pub fn hash(&self, secret: &[u8]) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            match self {
+                HashAlgo::Blake2x256 => ink::env::hash_bytes::<ink::env::hash::Blake2x256>(secret, &mut output),
+                HashAlgo::Keccak256 => output = crate::hashing::keccak256(secret),
+                HashAlgo::Sha256 => ink::env::hash_bytes::<ink::env::hash::Sha2x256>(secret, &mut output),
+            }
+            output
+        }
+    }
+
+    /// Supported Ethereum-side token metadata
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct DstTokenInfo {
+        pub address: [u8; 20],
+        pub symbol_hash: [u8; 32],      // keccak/blake hash of the ticker, for display checks
+        pub decimals: u8,
+        pub enabled: bool,
+    }
+
+    /// Resolver assignment parameters (matches resolver interface)
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ResolverParams {
+        pub resolver: AccountId,
+        pub hash_lock: [u8; 32],
+        pub ethereum_escrow_address: [u8; 20],
+        pub resolver_fee: Balance,
+        pub token_safety_deposit: Balance, // Non-zero: deposit in the configured PSP22 stablecoin
+    }
+
+    /// Maker authorization for an order hash
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum MakerSignature {
+        Ecdsa([u8; 65]),  // r(32) + s(32) + v(1)
+        Sr25519([u8; 64]), // Substrate wallet key; the maker AccountId is the public key
+        Contract(Vec<u8>), // Opaque, checked by the maker contract's `SignatureValidator`
+    }
+
+    /// Immutable escrow parameters for resolver compatibility
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct EscrowImmutables {
+        pub order_hash: [u8; 32],
+        pub hash_lock: [u8; 32],
+        pub maker: AccountId,
+        pub taker: AccountId,
+        pub token: AccountId,
+        pub amount: Balance,
+        pub safety_deposit: Balance,
+        pub timelocks: TimeLocks,
+        pub deployed_at: Option<Timestamp>,
+    }
+
+    impl EscrowImmutables {
+        /// The `IBaseEscrow.Immutables` the CREATE2 salt is taken over
+        pub fn create2(&self) -> create2::Immutables {
+            create2::Immutables {
+                order_hash: self.order_hash,
+                hash_lock: self.hash_lock,
+                maker: self.maker,
+                taker: self.taker,
+                token: self.token,
+                amount: self.amount,
+                safety_deposit: self.safety_deposit,
+                deployed_at: self.deployed_at.unwrap_or(0),
+                stage_starts: self.timelocks.stage_starts(),
+            }
+        }
+    }
+
+    /// In-flight owner rotation proposed by the recovery council
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct OwnerRecovery {
+        pub new_owner: AccountId,
+        pub approvals: Vec<AccountId>, // Council members that approved, proposer included
+        pub proposed_at: Timestamp,
+    }
+
+    /// PSP22 balance resolvers must hold to lock orders
+    #[derive(Debug, Clone, Default, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct AccessTokenConfig {
+        pub token: Option<AccountId>, // None disables the requirement
+        pub min_balance: Balance,
+    }
+
+    /// Owner-announced withdrawal of funds sent to the contract outside the order flow
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct FundsRescue {
+        pub token: Option<AccountId>, // None = native
+        pub amount: Balance,
+        pub recipient: AccountId,
+        pub executable_at: Timestamp,
+    }
+
+    /// Owner-announced code swap
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct CodeUpgrade {
+        pub code_hash: Hash,
+        pub executable_at: Timestamp,
+    }
+
+    /// A trusted relayer's report that the Ethereum leg of an order failed
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct RefundEvidence {
+        pub relayer: AccountId,
+        pub evidence: Vec<u8>,     // Opaque (tx hash, receipt proof); kept for disputes
+        pub submitted_at: Timestamp,
+    }
+
+    // --- Events (Compatible with Resolver expectations) ---
+
+    #[ink(event)]
+    pub struct OrderCreated {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub maker: AccountId,
+        #[ink(topic)]
+        pub src_token: AccountId,
+        pub direction: SwapDirection,
+        pub src_amount: Balance,
+        pub dst_amount: U256,
+        pub fill_deadline: Timestamp,
+        pub metadata: Vec<u8>,
+    }
+
+    #[ink(event)]
+    pub struct EscrowDeployed {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        #[ink(topic)]
+        pub src_token: AccountId,
+        pub hash_lock: [u8; 32],
+        pub ethereum_escrow: [u8; 20],
+        pub safety_deposit: Balance,
+    }
+
+    #[ink(event)]
+    pub struct SwapExecuted {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        #[ink(topic)]
+        pub src_token: AccountId,
+        pub secret: [u8; 32],
+        pub amount_filled: Balance,
+        pub late_fee_rebate: Balance,    // Resolver fee forgone to the maker for late execution
+        pub in_grace_period: bool,       // Executed after fill_deadline, within the grace window
+        pub metadata: Vec<u8>,
+    }
+
+    // --- V2 events: V1 fields plus counterpart chain, hash algorithm, recipient and the order
+    // options added since (memo, preferred resolver, variable-length secrets, fee discounts) ---
+
+    #[ink(event)]
+    pub struct OrderCreatedV2 {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub maker: AccountId,
+        #[ink(topic)]
+        pub src_token: AccountId,
+        pub direction: SwapDirection,
+        pub src_amount: Balance,
+        pub dst_amount: U256,
+        pub fill_deadline: Timestamp,
+        pub metadata: Vec<u8>,
+        pub recipient: ExternalAddress,
+        pub encrypted_memo: Option<Vec<u8>>,
+        pub preferred_resolver: Option<AccountId>,
+        pub chain_id: u32,
+        pub hash_algo: HashAlgo,
+    }
+
+    #[ink(event)]
+    pub struct EscrowDeployedV2 {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        #[ink(topic)]
+        pub src_token: AccountId,
+        pub hash_lock: [u8; 32],
+        pub counterpart_escrow: ExternalAddress,
+        pub safety_deposit: Balance,
+        pub chain_id: u32,
+        pub hash_algo: HashAlgo,
+    }
+
+    #[ink(event)]
+    pub struct SwapExecutedV2 {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        #[ink(topic)]
+        pub src_token: AccountId,
+        pub secret: Vec<u8>,
+        pub amount_filled: Balance,
+        pub late_fee_rebate: Balance,
+        pub in_grace_period: bool,
+        pub metadata: Vec<u8>,
+        pub recipient: ExternalAddress,
+        pub chain_id: u32,
+        pub hash_algo: HashAlgo,
+    }
+
+    #[ink(event)]
+    pub struct OrderCancelled {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub refund_amount: Balance,
+        pub reason: CancelReason,
+        pub metadata: Vec<u8>,
+    }
+
+    #[ink(event)]
+    pub struct PartialFillExecuted {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub filled_amount: Balance,
+        pub remaining_amount: Balance,
+        pub deposit_released: Balance,   // Safety deposit returned to the resolver by this fill
+    }
+
+    #[ink(event)]
+    pub struct KeeperPaid {
+        #[ink(topic)]
+        pub keeper: AccountId,
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub action: OrderAction,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ExecutorTipPaid {
+        #[ink(topic)]
+        pub executor: AccountId,
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub action: OrderAction,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OrderArchived {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub record_hash: [u8; 32],
+        pub status: OrderStatus,
+        pub src_amount: Balance,
+        pub filled_amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OrderSplit {
+        #[ink(topic)]
+        pub parent_order: [u8; 32],
+        #[ink(topic)]
+        pub maker: AccountId,
+        pub children: Vec<[u8; 32]>,
+    }
+
+    #[ink(event)]
+    pub struct HashLockCommitted {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub hash_lock: [u8; 32],
+        pub expires_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct ExecutionCommitted {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub executor: AccountId,
+        pub exclusive_until: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct OrderUnderwritten {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub underwriter: AccountId,
+        pub collateral: Balance,
+        pub fee_share_bps: u32,
+    }
+
+    #[ink(event)]
+    pub struct UnderwriterApproved {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        #[ink(topic)]
+        pub underwriter: AccountId,
+        pub max_fee_share_bps: u32,
+    }
+
+    #[ink(event)]
+    pub struct ProtocolFeeCharged {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub maker_fee: Balance,
+        pub taker_fee: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ProtocolFeeChargedV2 {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        pub maker_fee: Balance,
+        pub taker_fee: Balance,
+        pub taker_fee_discount: Balance, // Waived for the resolver's reputation
+    }
+
+    /// The taker fee owed exceeded the resolver's payout and only the payout was charged
+    #[ink(event)]
+    pub struct TakerFeeCapped {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub taker: AccountId,
+        pub owed: Balance,
+        pub charged: Balance,
+    }
+
+    #[ink(event)]
+    pub struct UnderwritingSettled {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub underwriter: AccountId,
+        pub returned_to_underwriter: Balance,
+        pub paid_to_maker: Balance,
+        pub fee_share: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ResolverRegistered {
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub stake: Balance,              // Total bonded stake after this deposit
+    }
+
+    #[ink(event)]
+    pub struct ResolverUnbondingStarted {
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub amount: Balance,
+        pub unlocks_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct ResolverStakeWithdrawn {
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ResolverSlashed {
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub amount: Balance,
+        pub beneficiary: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct SafetyDepositSlashed {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub to_maker: Balance,
+        pub to_treasury: Balance,
+    }
+
+    #[ink(event)]
+    pub struct SlashMakerShareSet {
+        pub share_bps: u32,
+    }
+
+    #[ink(event)]
+    pub struct SizeTiersSet {
+        pub tiers: Vec<SizeTier>,
+    }
+
+    #[ink(event)]
+    pub struct CancellationTipSet {
+        pub tip_bps: u32,
+    }
+
+    #[ink(event)]
+    pub struct DirectionParamsSet {
+        #[ink(topic)]
+        pub direction: SwapDirection,
+        pub overrides: DirectionOverrides,
+    }
+
+    #[ink(event)]
+    pub struct KeeperConfigSet {
+        pub config: KeeperConfig,
+    }
+
+    #[ink(event)]
+    pub struct KeeperSet {
+        #[ink(topic)]
+        pub keeper: AccountId,
+        pub allowed: bool,
+    }
+
+    #[ink(event)]
+    pub struct WatchtowerRegistered {
+        #[ink(topic)]
+        pub watchtower: AccountId,
+        pub fee: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OrderAtRisk {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub watchtower: AccountId,
+        pub boundary: RiskBoundary,
+        pub boundary_at: Timestamp, // When the boundary was crossed
+    }
+
+    #[ink(event)]
+    pub struct UpgradeAnnounced {
+        pub code_hash: Hash,
+        pub executable_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct UpgradeCancelled {
+        pub code_hash: Hash,
+        #[ink(topic)]
+        pub cancelled_by: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub storage_version: u32, // Version of the storage the new code starts from
+    }
+
+    #[ink(event)]
+    pub struct StorageMigrated {
+        pub from_version: u32,
+        pub to_version: u32,
+    }
+
+    #[ink(event)]
+    pub struct PermissionlessResolversSet {
+        pub permissionless: bool,
+    }
+
+    #[ink(event)]
+    pub struct ResolverApproved {
+        #[ink(topic)]
+        pub resolver: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ResolverRevoked {
+        #[ink(topic)]
+        pub resolver: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OperatorProposed {
+        #[ink(topic)]
+        pub resolver: AccountId,
+        #[ink(topic)]
+        pub operator: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct OperatorSet {
+        #[ink(topic)]
+        pub resolver: AccountId,
+        pub operator: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct ResolverBlocked {
+        #[ink(topic)]
+        pub maker: AccountId,
+        #[ink(topic)]
+        pub resolver: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct ResolverUnblocked {
+        #[ink(topic)]
+        pub maker: AccountId,
+        #[ink(topic)]
+        pub resolver: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct TrustedRelayerAdded {
+        #[ink(topic)]
+        pub relayer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct TrustedRelayerRemoved {
+        #[ink(topic)]
+        pub relayer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RelayerQuorumSet {
+        pub quorum: u32,
+    }
+
+    #[ink(event)]
+    pub struct FeeRecipientsUpdated {
+        pub recipients: Vec<FeeRecipient>,
+    }
+
+    #[ink(event)]
+    pub struct FeesWithdrawn {
+        #[ink(topic)]
+        pub recipient: AccountId,
+        pub token: Option<AccountId>, // None = native
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct PayoutDeferred {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub token: Option<AccountId>,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct PayoutsWithdrawn {
+        #[ink(topic)]
+        pub payee: AccountId,
+        pub token: Option<AccountId>,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OrdersImported {
+        pub count: u32,
+        pub total_imported: u32,
+        pub state_root: [u8; 32], // `state_root` of the open-index page the batch landed in
+    }
+
+    #[ink(event)]
+    pub struct ImportFinalized {
+        pub total_imported: u32,
+    }
+
+    #[ink(event)]
+    pub struct RefundEvidenceSubmitted {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub relayer: AccountId,
+        pub attestations: u32,     // Counting this one
+        pub quorum: u32,
+    }
+
+    #[ink(event)]
+    pub struct FeeExemptionAdded {
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct FeeExemptionRemoved {
+        #[ink(topic)]
+        pub account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GuardianAdded {
+        #[ink(topic)]
+        pub guardian: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GuardianRemoved {
+        #[ink(topic)]
+        pub guardian: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct GuardianPaused {
+        #[ink(topic)]
+        pub guardian: AccountId,
+        pub timestamp: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryCouncilUpdated {
+        pub members: Vec<AccountId>,
+        pub threshold: u32,
+        pub delay: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryProposed {
+        #[ink(topic)]
+        pub proposer: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+        pub executable_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryApproved {
+        #[ink(topic)]
+        pub member: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+        pub approvals: u32,
+    }
+
+    #[ink(event)]
+    pub struct RecoveryCancelled {
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct RescueRequested {
+        #[ink(topic)]
+        pub token: Option<AccountId>,
+        #[ink(topic)]
+        pub recipient: AccountId,
+        pub amount: Balance,
+        pub executable_at: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct RescueCancelled {
+        #[ink(topic)]
+        pub token: Option<AccountId>,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct FundsRescued {
+        #[ink(topic)]
+        pub token: Option<AccountId>,
+        #[ink(topic)]
+        pub recipient: AccountId,
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipRecovered {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// A signer revoked its signatures over every nonce up to and including `nonce`
+    #[ink(event)]
+    pub struct NonceInvalidated {
+        #[ink(topic)]
+        pub account: AccountId,
+        pub nonce: u64,
+    }
+
+    // Resolver-compatible events
+    #[ink(event)]
+    pub struct SrcEscrowDeployed {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]  
+        pub escrow_address: AccountId,
+        pub immutables: EscrowImmutables,
+        pub safety_deposit: Balance,
+    }
+
+    #[ink(event)]
+    pub struct DstEscrowDeployed {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub escrow_address: AccountId,
+        pub immutables: EscrowImmutables,
+        pub src_cancellation_timestamp: Timestamp,
+    }
+
+    #[ink(event)]
+    pub struct EscrowWithdrawal {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub escrow_address: AccountId,
+        pub secret: [u8; 32],
+        pub amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct EscrowCancellation {
+        #[ink(topic)]
+        pub order_hash: [u8; 32],
+        #[ink(topic)]
+        pub escrow_address: AccountId,
+        pub refund_amount: Balance,
+    }
+
+    /// Something a party may do to an order right now
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum OrderAction {
+        DeployEscrow,
+        Execute,
+        CancelOrder,
+        Archive,
+    }
+
+    /// Who may take an `OrderAction`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ActionActor {
+        Maker,
+        Resolver(AccountId),   // Only this resolver (e.g. holder of a hash-lock commitment)
+        AnyResolver,           // Any resolver passing the order's resolver checks
+        SecretHolder,          // Anyone able to reveal the secret
+        Anyone,
+    }
+
+    /// All timestamps relevant to an order plus what is allowed at `current_time`
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct OrderTimeline {
+        pub status: OrderStatus,
+        pub current_time: Timestamp,
+        pub created_at: Timestamp,
+        pub private_cancellation_end: Timestamp, // Maker-only cancel of a pending order until here
+        pub fill_deadline: Timestamp,
+        pub execution_cutoff: Timestamp,         // Last instant a secret executes (stamped at lock)
+        pub public_cancellation_start: Timestamp, // Anyone may cancel a locked order from here
+        pub src_stage: Option<SrcStage>,         // Current stage of a locked order
+        pub settled_at: Option<Timestamp>,
+        pub archivable_at: Option<Timestamp>,
+        pub allowed_actions: Vec<(OrderAction, ActionActor)>,
+    }
+
+    /// Coarse bucket of an `OrderRisk` score
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum RiskLevel {
+        Low,    // score below 1/3
+        Medium, // score below 2/3
+        High,
+    }
+
+    /// Fillable flow of one token pair at one implied price
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct DepthLevel {
+        pub price: u128,                 // dst per src after decimal normalization, 1e18-scaled, floored to the tick
+        pub src_amount: Balance,         // Summed over the level's orders
+        pub dst_amount: U256,            // Current (auction) amounts asked, summed
+        pub order_count: u32,
+    }
+
+    /// Risk of relying on a live order; each component runs from 0 (safe) to 10_000 bps and
+    /// the score is their average
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct OrderRisk {
+        pub level: RiskLevel,
+        pub score_bps: u32,
+        pub resolver_reputation: Option<u32>, // None until assigned or without a coordinator reading
+        pub reputation_risk_bps: u32,         // Shortfall against RISK_REFERENCE_REPUTATION
+        pub deposit_ratio_bps: u32,           // Safety deposit per src_amount
+        pub deposit_risk_bps: u32,            // Shortfall against RISK_REFERENCE_DEPOSIT_BPS
+        pub time_remaining: Timestamp,        // Until the execution cutoff
+        pub time_risk_bps: u32,               // Share of the execution window already used
+        pub size_risk_bps: u32,               // Share of src_amount not covered by the resolver's stake
+    }
+
+    /// Cancellation reasons
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum CancelReason {
+        MakerCancellation,
+        TimelockExpired,
+        ResolverTimeout,
+        EmergencyStop,
+        RelayerRefund,             // Relayer quorum reported the Ethereum leg failed
+    }
+
+    /// Contract errors
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        // Order errors
+        OrderNotFound,
+        OrderAlreadyExists,
+        InvalidOrderStatus,
+        InvalidOrderHash,
+        
+        // Authorization
+        Unauthorized,
+        OnlyMaker,
+        OnlyResolver,
+        OnlyOwner,
+        
+        // Timing
+        DeadlineExpired,
+        TimelockNotExpired,
+        PrivateCancellationExpired,
+        
+        // HTLC
+        InvalidSecret,
+        InvalidHashLock,
+        HashLockAlreadyUsed,
+        InvalidImmutables,
+        
+        // Amounts
+        InsufficientFunds,
+        InsufficientDeposit,
+        InvalidAmount,
+        
+        // System
+        ContractPaused,
+        ArithmeticOverflow,
+        TransferFailed,          // Payout to an order participant (maker, resolver, recipient)
+        NativeTokenSendingFailure,
+        
+        // Cross-chain
+        EthereumEscrowNotSet,
+        InvalidEthereumAddress,
+        UnsupportedDirection,
+        EscrowNotFound,
+        
+        // General
+        LengthMismatch,
+        InvalidLength,
+
+        // Appended in release order so existing variant indices are unchanged; the `*V2`
+        // variants carry context for their unit counterparts
+        InvalidDecimals,
+        UnsupportedToken,
+        CoordinatorNotSet,
+        InsufficientReputation,
+        AlreadyUnderwritten,
+        OnlyGuardian,
+        TooManyGuardians,
+        OnlyRecoveryCouncil,
+        InvalidRecoveryConfig,
+        RecoveryNotPending,
+        RecoveryAlreadyPending,
+        RecoveryAlreadyApproved,
+        RecoveryThresholdNotMet,
+        InvalidSignature,
+        IndivisibleBasket,
+        InsufficientFundsV2 { required: Balance, provided: Balance },
+        InsufficientDepositV2 { required: Balance, provided: Balance },
+        DeadlineExpiredV2 { deadline: Timestamp, current_time: Timestamp },
+        TimelockNotExpiredV2 { unlocks_at: Timestamp, current_time: Timestamp },
+        HashLockCommitted,
+        ExecutionCommitted,
+        ExecutionNotCommitted,
+        RevealTooEarly,
+        OrderDurationOutOfRange { duration: Timestamp, min: Timestamp, max: Timestamp },
+        UnderCollateralized { exposure: Balance, limit: Balance },
+        InvalidExternalAddress,
+        ResolverNotRegistered,
+        ResolverBlocked,
+        ProtocolTransferFailed,  // Protocol-side payout (fee claim); never raised during settlement
+        OperatorInUse,
+        RefundAlreadyAttested,
+        ResolverRejected,
+        InvalidFeeSplit,
+        InvalidTimelocks,
+        PreferredResolverOnly,
+        ImportClosed,
+        OrderNotAtRisk,
+        WatchtowerAlreadyRegistered,
+        UpgradeFailed,
+        StorageUpToDate,
+        RescueNotPending,
+        RescueAlreadyPending,
+        RescueExceedsSurplus { surplus: Balance, amount: Balance },
+        AccessTokenRequired { required: Balance, held: Balance },
+        PayoutNotFound,
+        NonceAlreadyUsed,
+        CommitmentCooldown,
+        TooManyFills,
+        UpgradeNotPending,
+        UpgradeAlreadyPending,
+        UnderwritingNotApproved,
+        ResolverHasOpenOrders,
+        MigrationPending,
+    }
+
+    /// Main contract storage
+    #[ink(storage)]
+    pub struct FusionPolkadotEscrow {
+        // Core storage
+        orders: Mapping<[u8; 32], FusionOrder>,         // cold body; status/filled_amount live in order_states
+        order_states: Mapping<[u8; 32], OrderState>,    // hot fields, authoritative
+        open_orders: Mapping<u32, [u8; 32]>,            // index -> non-terminal order hash
+        open_order_count: Lazy<u32>,                    // Entries in open_orders; own storage key, unset reads as 0
+        open_order_positions: Mapping<[u8; 32], u32>,   // order_hash -> index in open_orders
+        maker_orders: Mapping<IndexKey<AccountId>, [u8; 32]>, // (maker, index) -> unarchived order hash
+        maker_order_counts: Mapping<AccountId, u32>,    // Entries per maker in maker_orders
+        maker_order_positions: Mapping<[u8; 32], u32>,  // order_hash -> index in maker_orders
+        status_orders: Mapping<IndexKey<OrderStatus>, [u8; 32]>, // (status, index) -> unarchived order hash
+        status_order_counts: Mapping<OrderStatus, u32>, // Entries per status in status_orders
+        status_order_positions: Mapping<[u8; 32], u32>, // order_hash -> index in status_orders
+        archived_orders: Mapping<[u8; 32], ArchivedOrder>,
+        fill_receipts: Mapping<IndexKey<[u8; 32]>, FillReceipt>, // (order_hash, index) -> one per fill, dropped on archival
+        fill_receipt_counts: Mapping<[u8; 32], u32>,    // Receipts per order in fill_receipts
+        archive_retention: Timestamp,                   // Settled orders archivable after this
+        storage_deposits: Mapping<[u8; 32], StorageDeposit>,
+        executor_tips: Mapping<[u8; 32], ExecutorTip>,  // Dropped on archival
+        storage_deposit_per_byte: Balance,
+        active_hash_locks: Mapping<[u8; 32], [u8; 32]>, // hash_lock -> order_hash
+        consumed_hash_locks: Mapping<[u8; 32], [u8; 32]>, // hash_lock -> order that retired it (permanent)
+        escrow_addresses: Mapping<[u8; 32], AccountId>, // order_hash -> escrow_address
+        imported_orders: u32,                           // Orders migrated in through `import_orders`
+        import_finalized: bool,                         // `import_orders` closed for good
+        storage_version: u32,                           // Layout version, see `STORAGE_VERSION`
+        transfer_backend: Lazy<TransferBackend>,        // Own storage key; unset reads as Direct
+        claimable: Mapping<ClaimKey, Balance>,           // Deferred and failed payouts awaiting `claim`
+        nonces: nonces::NonceRegistry,                  // Consumed by every accepted signature
+        
+        // Configuration
+        owner: AccountId,
+        paused: bool,
+        guardians: Mapping<AccountId, bool>, // May pause, never unpause or move funds
+        guardian_count: u32,
+        recovery_council: Vec<AccountId>, // Optional M-of-N council able to rotate the owner
+        recovery_threshold: u32,
+        recovery_delay: Timestamp,
+        pending_recovery: Option<OwnerRecovery>,
+        rescue_delay: Lazy<Timestamp>,  // Between `request_rescue` and `rescue_funds`; unset reads as the default
+        pending_rescue: Lazy<Option<FundsRescue>>, // Own storage key, see `migrate`
+        pending_upgrade: Lazy<Option<CodeUpgrade>>, // Own storage key, see `migrate`
+        maker_fee_bps: u32,             // Basis points of src_amount, out of the swapped amount
+        taker_fee_bps: u32,             // Basis points of src_amount, out of the resolver's payout
+        execution_grace_period: Timestamp, // Post-deadline window for secret-based execution
+        stage_delays: StageDelays,         // Fusion+ stage offsets without a direction override
+        min_order_duration: Timestamp,     // Bounds on fill_deadline - creation time
+        max_order_duration: Timestamp,
+        min_safety_deposit: Balance,     // Minimum resolver stake
+        deposit_token: Option<AccountId>, // Accepted PSP22 stablecoin for safety deposits
+        min_token_safety_deposit: Balance,
+        access_token: Lazy<AccessTokenConfig>, // Own storage key, unset reads as disabled
+        
+        // Resolver management
+        approved_resolvers: Mapping<AccountId, bool>,   // false = revoked, overrides stake
+        maker_blocked_resolvers: Mapping<(AccountId, AccountId), bool>, // (maker, resolver)
+        resolver_stakes: Mapping<AccountId, Balance>,   // Self-bonded stake from `register_resolver`
+        resolver_unbondings: Mapping<AccountId, ResolverUnbonding>,
+        min_resolver_stake: Balance,     // 0 disables self-service registration
+        permissionless_resolvers: bool,  // Permissionless mode: any resolver may lock orders
+        resolver_unbonding_period: Timestamp,
+        coordinator: Option<AccountId>,  // Relayer/resolver coordinator (reputation source)
+        remote_cache: Mapping<RemoteKey, CachedValue>, // Coordinator reads reused on hot paths
+        remote_cache_ttl: Timestamp,     // 0 disables the cache
+        remote_cache_epoch: u32,         // Bumped on coordinator change to invalidate the cache
+        gas_budgets: GasBudgets,         // Cross-contract call limits per callee
+        resolver_operators: Mapping<AccountId, AccountId>, // resolver -> hot operator key
+        operator_resolvers: Mapping<AccountId, AccountId>, // operator -> resolver it acts for
+        proposed_operators: Mapping<AccountId, AccountId>, // resolver -> operator yet to accept
+        keeper_config: KeeperConfig,
+        keepers: Mapping<AccountId, bool>,
+        watchtowers: Mapping<AccountId, Timestamp>, // watchtower -> registered at
+        watchtower_fee: Balance,         // Native registration fee, accrued to the fee vault
+        risk_flags: Mapping<[u8; 32], RiskBoundary>, // Latest boundary reported per order
+        direction_overrides: Mapping<SwapDirection, DirectionOverrides>, // Per-field overrides of the global defaults
+        size_tiers: Vec<SizeTier>,       // Express lane, ascending by max_src_amount
+        reputation_discounts: Lazy<Vec<ReputationDiscount>>, // Ascending by min_reputation; own storage key, unset reads as none
+        hash_lock_commitments: Mapping<[u8; 32], HashLockCommitment>, // order_hash -> resolver pre-commitment
+        commitment_ttl: Timestamp,
+        execution_commitments: Mapping<[u8; 32], ExecutionCommitment>, // order_hash -> commit-reveal claim
+        execution_exclusivity: Timestamp,
+        referral_exclusivity: Timestamp, // Head start of a maker's preferred resolver
+
+        // Underwriting
+        underwritings: Mapping<[u8; 32], Underwriting>,
+        underwriter_approvals: Mapping<[u8; 32], UnderwriterApproval>,
+        underwriter_fee_share_bps: u32,  // Share of resolver fee paid to underwriters
+        slash_maker_share_bps: u32,      // Share of a timed-out resolver's deposit paid to the maker
+        cancellation_tip_bps: u32,       // Share of the safety deposit paid to a public canceller
+        
+        // Cross-chain coordination (resolver compatibility)
+        ethereum_resolver: [u8; 20],     // Ethereum counterpart address
+        trusted_relayers: Mapping<AccountId, bool>,
+        relayer_quorum: u32,             // Relayer reports needed for a refund; 0 disables
+        refund_evidence: Mapping<[u8; 32], Vec<RefundEvidence>>, // Kept after the refund for disputes
+        fee_exempt: Mapping<AccountId, bool>,  // Makers/resolvers charged no protocol fee
+        ethereum_chain_id: u32,
+        event_version: EventVersion,
+
+        // Token metadata
+        src_token_decimals: Mapping<AccountId, u8>,
+        src_token_list_mode: TokenListMode,
+        denied_src_tokens: Mapping<AccountId, bool>,
+        allowed_src_tokens: Mapping<AccountId, bool>,
+        dst_tokens: Mapping<[u8; 20], DstTokenInfo>,
+        allow_all_dst_tokens: bool,     // Permissionless mode: skip dst_token registry check
+        
+        // Metrics
+        order_nonce: u64,
+        total_volume: Balance,
+        total_escrows_created: u64,
+        total_protocol_fees: Balance,
+        fee_vault: Mapping<Option<AccountId>, Balance>,  // Protocol fees per asset (None = native), not yet withdrawn
+        fee_recipients: Vec<FeeRecipient>,               // Empty: every fee accrues to the owner
+        accrued_fee_shares: Mapping<FeeShareKey, Balance>, // Per recipient and asset, not yet claimed
+        locked_value: Mapping<AccountId, Balance>,       // src_token -> value in open orders
+        asset_ledgers: Mapping<Option<AccountId>, AssetLedger>, // Owed per asset (None = native)
+        locked_tokens: Mapping<u32, AccountId>,          // index -> token ever locked (for enumeration)
+        locked_token_count: Lazy<u32>,                   // Entries in locked_tokens; own storage key, unset reads as 0
+        resolver_open_orders: Mapping<AccountId, u32>,
+        active_resolvers: u32,
+        resolver_exposure: Mapping<AccountId, Balance>,  // Normalized value of a resolver's open orders
+        collateral_ratio_bps: u32,                       // Max exposure vs coordinator stake (0 = off)
+    }
+
+    impl FusionPolkadotEscrow {
+        /// Constructor
+        #[ink(constructor)]
+        pub fn new(
+            maker_fee_bps: u32,
+            min_safety_deposit: Balance,
+            ethereum_chain_id: u32,
+            ethereum_resolver: [u8; 20],
+        ) -> Self {
+            Self::new_with_transfer_backend(
+                maker_fee_bps,
+                min_safety_deposit,
+                ethereum_chain_id,
+                ethereum_resolver,
+                TransferBackend::Direct,
+            )
+        }
+
+        /// Constructor for chains where direct balance transfers to some accounts are restricted
+        #[ink(constructor)]
+        pub fn new_with_transfer_backend(
+            maker_fee_bps: u32,
+            min_safety_deposit: Balance,
+            ethereum_chain_id: u32,
+            ethereum_resolver: [u8; 20],
+            transfer_backend: TransferBackend,
+        ) -> Self {
+            let mut contract = Self {
+                orders: Mapping::default(),
+                order_states: Mapping::default(),
+                open_orders: Mapping::default(),
+                open_order_count: Lazy::default(),
+                open_order_positions: Mapping::default(),
+                maker_orders: Mapping::default(),
+                maker_order_counts: Mapping::default(),
+                maker_order_positions: Mapping::default(),
+                status_orders: Mapping::default(),
+                status_order_counts: Mapping::default(),
+                status_order_positions: Mapping::default(),
+                archived_orders: Mapping::default(),
+                fill_receipts: Mapping::default(),
+                fill_receipt_counts: Mapping::default(),
+                archive_retention: DEFAULT_ARCHIVE_RETENTION,
+                storage_deposits: Mapping::default(),
+                executor_tips: Mapping::default(),
+                storage_deposit_per_byte: 0,
+                active_hash_locks: Mapping::default(),
+                consumed_hash_locks: Mapping::default(),
+                escrow_addresses: Mapping::default(),
+                imported_orders: 0,
+                import_finalized: false,
+                storage_version: STORAGE_VERSION,
+                transfer_backend: Lazy::default(),
+                claimable: Mapping::default(),
+                nonces: Default::default(),
+                owner: Self::env().caller(),
+                paused: false,
+                guardians: Mapping::default(),
+                guardian_count: 0,
+                recovery_council: Vec::new(),
+                recovery_threshold: 0,
+                recovery_delay: MIN_RECOVERY_DELAY,
+                pending_recovery: None,
+                rescue_delay: Lazy::default(),
+                pending_rescue: Lazy::default(),
+                pending_upgrade: Lazy::default(),
+                maker_fee_bps,
+                taker_fee_bps: 0,
+                execution_grace_period: DEFAULT_EXECUTION_GRACE_PERIOD,
+                stage_delays: StageDelays::default(),
+                min_order_duration: DEFAULT_MIN_ORDER_DURATION,
+                max_order_duration: DEFAULT_MAX_ORDER_DURATION,
+                min_safety_deposit,
+                deposit_token: None,
+                min_token_safety_deposit: 0,
+                access_token: Lazy::default(),
+                approved_resolvers: Mapping::default(),
+                maker_blocked_resolvers: Mapping::default(),
+                resolver_stakes: Mapping::default(),
+                resolver_unbondings: Mapping::default(),
+                direction_overrides: Mapping::default(),
+                size_tiers: Vec::new(),
+                reputation_discounts: Lazy::default(),
+                resolver_operators: Mapping::default(),
+                operator_resolvers: Mapping::default(),
+                proposed_operators: Mapping::default(),
+                keeper_config: KeeperConfig {
+                    bounty_bps: 0,
+                    max_bounty: 0,
+                    allow_list_only: false,
+                },
+                keepers: Mapping::default(),
+                watchtowers: Mapping::default(),
+                watchtower_fee: 0,
+                risk_flags: Mapping::default(),
+                gas_budgets: GasBudgets {
+                    token: DEFAULT_TOKEN_CALL_GAS,
+                    coordinator: DEFAULT_COORDINATOR_CALL_GAS,
+                    signature_validator: DEFAULT_SIGNATURE_CALL_GAS,
+                },
+                min_resolver_stake: 0,
+                permissionless_resolvers: false,
+                resolver_unbonding_period: DEFAULT_RESOLVER_UNBONDING_PERIOD,
+                coordinator: None,
+                remote_cache: Mapping::default(),
+                remote_cache_ttl: 0,
+                remote_cache_epoch: 0,
+                hash_lock_commitments: Mapping::default(),
+                commitment_ttl: DEFAULT_COMMITMENT_TTL,
+                execution_commitments: Mapping::default(),
+                execution_exclusivity: DEFAULT_EXECUTION_EXCLUSIVITY,
+                referral_exclusivity: DEFAULT_REFERRAL_EXCLUSIVITY,
+                underwritings: Mapping::default(),
+                underwriter_approvals: Mapping::default(),
+                underwriter_fee_share_bps: 0,
+                slash_maker_share_bps: DEFAULT_SLASH_MAKER_SHARE_BPS,
+                cancellation_tip_bps: 0,
+                ethereum_resolver,
+                trusted_relayers: Mapping::default(),
+                relayer_quorum: 0,
+                refund_evidence: Mapping::default(),
+                fee_exempt: Mapping::default(),
+                ethereum_chain_id,
+                event_version: EventVersion::V1,
+                src_token_decimals: Mapping::default(),
+                src_token_list_mode: TokenListMode::Open,
+                denied_src_tokens: Mapping::default(),
+                allowed_src_tokens: Mapping::default(),
+                dst_tokens: Mapping::default(),
+                allow_all_dst_tokens: false,
+                order_nonce: 0,
+                total_volume: 0,
+                total_escrows_created: 0,
+                total_protocol_fees: 0,
+                fee_vault: Mapping::default(),
+                fee_recipients: Vec::new(),
+                accrued_fee_shares: Mapping::default(),
+                locked_value: Mapping::default(),
+                asset_ledgers: Mapping::default(),
+                locked_tokens: Mapping::default(),
+                locked_token_count: Lazy::default(),
+                resolver_open_orders: Mapping::default(),
+                active_resolvers: 0,
+                resolver_exposure: Mapping::default(),
+                collateral_ratio_bps: 0,
+            };
+            contract.transfer_backend.set(&transfer_backend);
+            contract
+        }
+
+        // --- Admin Functions ---
+
+        #[ink(message)]
+        pub fn set_paused(&mut self, paused: bool) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.paused = paused;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn add_guardian(&mut self, guardian: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.guardians.contains(guardian) {
+                return Ok(());
+            }
+            if self.guardian_count >= MAX_GUARDIANS {
+                return Err(Error::TooManyGuardians);
+            }
+            self.guardians.insert(guardian, &true);
+            self.guardian_count = self.guardian_count.saturating_add(1);
+            self.env().emit_event(GuardianAdded { guardian });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn remove_guardian(&mut self, guardian: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.guardians.take(guardian).is_some() {
+                self.guardian_count = self.guardian_count.saturating_sub(1);
+                self.env().emit_event(GuardianRemoved { guardian });
+            }
+            Ok(())
+        }
+
+        /// Emergency pause by any guardian; unpausing stays with the owner
+        #[ink(message)]
+        pub fn guardian_pause(&mut self) -> Result<(), Error> {
+            let guardian = self.env().caller();
+            if !self.guardians.contains(guardian) {
+                return Err(Error::OnlyGuardian);
+            }
+            self.paused = true;
+            self.env().emit_event(GuardianPaused {
+                guardian,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn approve_resolver(&mut self, resolver: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.operator_resolvers.contains(resolver) {
+                return Err(Error::OperatorInUse);
+            }
+            self.approved_resolvers.insert(resolver, &true);
+            self.env().emit_event(ResolverApproved { resolver });
+            Ok(())
+        }
+
+        /// Exclude a resolver, including one qualifying through bonded stake
+        #[ink(message)]
+        pub fn revoke_resolver(&mut self, resolver: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.approved_resolvers.insert(resolver, &false);
+            self.env().emit_event(ResolverRevoked { resolver });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_min_resolver_stake(&mut self, min_stake: Balance) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.min_resolver_stake = min_stake;
+            Ok(())
+        }
+
+        /// Let any resolver lock orders instead of only approved or staked ones
+        #[ink(message)]
+        pub fn set_permissionless_resolvers(&mut self, permissionless: bool) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.permissionless_resolvers = permissionless;
+            self.env().emit_event(PermissionlessResolversSet { permissionless });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_resolver_unbonding_period(&mut self, period: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.resolver_unbonding_period = period;
+            Ok(())
+        }
+
+        /// Slash up to `amount` of a resolver's stake (bonded first, then unbonding) to the owner
+        #[ink(message)]
+        pub fn slash_resolver(&mut self, resolver: AccountId, amount: Balance) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let owner = self.owner;
+            self.slash_resolver_stake(resolver, amount, owner)
+        }
+
+        #[ink(message)]
+        pub fn add_trusted_relayer(&mut self, relayer: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.trusted_relayers.insert(relayer, &true);
+            self.env().emit_event(TrustedRelayerAdded { relayer });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn remove_trusted_relayer(&mut self, relayer: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.trusted_relayers.take(relayer).is_some() {
+                self.env().emit_event(TrustedRelayerRemoved { relayer });
+            }
+            Ok(())
+        }
+
+        /// Distinct trusted-relayer reports `relayer_initiated_refund` needs (0 disables it)
+        #[ink(message)]
+        pub fn set_relayer_quorum(&mut self, quorum: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.relayer_quorum = quorum;
+            self.env().emit_event(RelayerQuorumSet { quorum });
+            Ok(())
+        }
+
+        /// Exempt a maker or resolver (e.g. internal market making) from protocol fees
+        #[ink(message)]
+        pub fn add_fee_exemption(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if !self.fee_exempt.contains(account) {
+                self.fee_exempt.insert(account, &true);
+                self.env().emit_event(FeeExemptionAdded { account });
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn remove_fee_exemption(&mut self, account: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.fee_exempt.take(account).is_some() {
+                self.env().emit_event(FeeExemptionRemoved { account });
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_coordinator(&mut self, coordinator: Option<AccountId>) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.coordinator = coordinator;
+            self.remote_cache_epoch = self.remote_cache_epoch.wrapping_add(1);
+            Ok(())
+        }
+
+        /// How long coordinator reads (reputation, stake) are reused before being re-read;
+        /// 0 reads through on every call
+        #[ink(message)]
+        pub fn set_remote_cache_ttl(&mut self, ttl: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.remote_cache_ttl = ttl;
+            Ok(())
+        }
+
+        /// Re-read `keys` from the coordinator now, e.g. so a stake or reputation change applies
+        /// before the cached value expires. Failed reads evict the entry.
+        #[ink(message)]
+        pub fn refresh_remote_cache(&mut self, keys: Vec<RemoteKey>) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            if keys.len() > MAX_REMOTE_REFRESH_KEYS {
+                return Err(Error::InvalidLength);
+            }
+            let coordinator = self.coordinator.ok_or(Error::CoordinatorNotSet)?;
+            for key in keys.iter() {
+                match self.fetch_remote(coordinator, key) {
+                    Some(value) => self.cache_remote(key, value),
+                    None => self.remote_cache.remove(key),
+                }
+            }
+            Ok(())
+        }
+
+        /// Cap each resolver's open order value at `ratio_bps / 10_000` times its coordinator
+        /// stake; 0 disables the check
+        #[ink(message)]
+        pub fn set_collateral_ratio(&mut self, ratio_bps: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.collateral_ratio_bps = ratio_bps;
+            Ok(())
+        }
+
+        /// Retune a callee's budget, e.g. after a runtime upgrade changes weights
+        #[ink(message)]
+        pub fn set_gas_budget(&mut self, callee: Callee, budget: GasBudget) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            match callee {
+                Callee::Token => self.gas_budgets.token = budget,
+                Callee::Coordinator => self.gas_budgets.coordinator = budget,
+                Callee::SignatureValidator => self.gas_budgets.signature_validator = budget,
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_keeper_config(&mut self, config: KeeperConfig) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if config.bounty_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            self.keeper_config = config.clone();
+            self.env().emit_event(KeeperConfigSet { config });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_keeper(&mut self, keeper: AccountId, allowed: bool) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if allowed {
+                self.keepers.insert(keeper, &true);
+            } else {
+                self.keepers.remove(keeper);
+            }
+            self.env().emit_event(KeeperSet { keeper, allowed });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_watchtower_fee(&mut self, fee: Balance) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.watchtower_fee = fee;
+            Ok(())
+        }
+
+        /// Register the caller as a watchtower, paying `watchtower_fee` into the fee vault
+        #[ink(message, payable)]
+        pub fn register_watchtower(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let watchtower = self.env().caller();
+            if self.watchtowers.contains(watchtower) {
+                return Err(Error::WatchtowerAlreadyRegistered);
+            }
+            let fee = self.env().transferred_value();
+            if fee < self.watchtower_fee {
+                return Err(Error::InsufficientFundsV2 { required: self.watchtower_fee, provided: fee });
+            }
+            self.accrue_protocol_fee(None, fee)?;
+            self.watchtowers.insert(watchtower, &self.env().block_timestamp());
+            self.env().emit_event(WatchtowerRegistered { watchtower, fee });
+            Ok(())
+        }
+
+        /// Leave the registry; the fee is not refunded
+        #[ink(message)]
+        pub fn unregister_watchtower(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            if self.watchtowers.take(self.env().caller()).is_none() {
+                return Err(Error::Unauthorized);
+            }
+            Ok(())
+        }
+
+        /// Emit `OrderAtRisk` for the latest timelock boundary the order has passed without
+        /// action (registered watchtowers only, once per boundary)
+        #[ink(message)]
+        pub fn flag_order_at_risk(&mut self, order_hash: [u8; 32]) -> Result<RiskBoundary, Error> {
+            self.ensure_migrated()?;
+            let watchtower = self.env().caller();
+            if !self.watchtowers.contains(watchtower) {
+                return Err(Error::Unauthorized);
+            }
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            if order.status.is_terminal() {
+                return Err(Error::InvalidOrderStatus);
+            }
+            let (boundary, boundary_at) = self
+                .risk_boundary(&order, self.env().block_timestamp())
+                .ok_or(Error::OrderNotAtRisk)?;
+            if self.risk_flags.get(order_hash).is_some_and(|flagged| flagged >= boundary) {
+                return Err(Error::OrderNotAtRisk);
+            }
+
+            self.risk_flags.insert(order_hash, &boundary);
+            self.env().emit_event(OrderAtRisk { order_hash, watchtower, boundary, boundary_at });
+            Ok(boundary)
+        }
+
+        #[ink(message)]
+        pub fn is_watchtower(&self, account: AccountId) -> bool {
+            self.watchtowers.contains(account)
+        }
+
+        /// Latest boundary a watchtower has flagged for the order
+        #[ink(message)]
+        pub fn get_risk_flag(&self, order_hash: [u8; 32]) -> Option<RiskBoundary> {
+            self.risk_flags.get(order_hash)
+        }
+
+        #[ink(message)]
+        pub fn set_execution_grace_period(&mut self, grace_period: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.execution_grace_period = grace_period;
+            Ok(())
+        }
+
+        /// Stage offsets stamped onto orders locked from now on (directions without an override)
+        #[ink(message)]
+        pub fn set_stage_delays(&mut self, delays: StageDelays) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            Self::validate_stage_delays(&delays)?;
+            self.stage_delays = delays;
+            Ok(())
+        }
+
+        /// Express-lane tiers, ascending by `max_src_amount`. New orders take the first tier
+        /// they fit; larger orders keep the direction's parameters. Existing orders are unaffected.
+        #[ink(message)]
+        pub fn set_size_tiers(&mut self, tiers: Vec<SizeTier>) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if tiers.len() > MAX_SIZE_TIERS {
+                return Err(Error::InvalidLength);
+            }
+            if tiers.first().is_some_and(|tier| tier.max_src_amount == 0)
+                || tiers.windows(2).any(|pair| pair[1].max_src_amount <= pair[0].max_src_amount)
+            {
+                return Err(Error::InvalidAmount);
+            }
+            for tier in tiers.iter() {
+                Self::validate_stage_delays(&tier.stage_delays)?;
+            }
+            self.size_tiers = tiers.clone();
+            self.env().emit_event(SizeTiersSet { tiers });
+            Ok(())
+        }
+
+        /// Taker-fee discount tiers, ascending by `min_reputation`; a resolver gets the highest
+        /// tier its coordinator reputation reaches at execution
+        #[ink(message)]
+        pub fn set_reputation_discounts(&mut self, discounts: Vec<ReputationDiscount>) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if discounts.len() > MAX_REPUTATION_DISCOUNTS {
+                return Err(Error::InvalidLength);
+            }
+            if discounts.iter().any(|discount| discount.discount_bps > 10_000)
+                || discounts.windows(2).any(|pair| pair[1].min_reputation <= pair[0].min_reputation)
+            {
+                return Err(Error::InvalidAmount);
+            }
+            self.reputation_discounts.set(&discounts);
+            Ok(())
+        }
+
+        /// Override fees, minimum deposit, cancellation period and stage delays for one
+        /// direction; every `None` field follows the global setting, so an all-`None` override
+        /// clears it
+        #[ink(message)]
+        pub fn set_direction_params(
+            &mut self,
+            direction: SwapDirection,
+            overrides: DirectionOverrides,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if overrides.maker_fee_bps.unwrap_or(0) > 10_000 || overrides.taker_fee_bps.unwrap_or(0) > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            if let Some(stage_delays) = &overrides.stage_delays {
+                Self::validate_stage_delays(stage_delays)?;
+            }
+            if overrides == DirectionOverrides::default() {
+                self.direction_overrides.remove(&direction);
+            } else {
+                self.direction_overrides.insert(&direction, &overrides);
+            }
+            self.env().emit_event(DirectionParamsSet { direction, overrides });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_order_duration_bounds(&mut self, min: Timestamp, max: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if min == 0 || min > max {
+                return Err(Error::InvalidAmount);
+            }
+            self.min_order_duration = min;
+            self.max_order_duration = max;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_commitment_ttl(&mut self, ttl: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if ttl == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            self.commitment_ttl = ttl;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_execution_exclusivity(&mut self, window: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if window == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            self.execution_exclusivity = window;
+            Ok(())
+        }
+
+        /// Head start given to a maker's `preferred_resolver`; 0 turns referrals into a hint only
+        #[ink(message)]
+        pub fn set_referral_exclusivity(&mut self, window: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.referral_exclusivity = window;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_maker_fee_bps(&mut self, fee_bps: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if fee_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            self.maker_fee_bps = fee_bps;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_taker_fee_bps(&mut self, fee_bps: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if fee_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            self.taker_fee_bps = fee_bps;
+            Ok(())
+        }
+
+        /// Send the vault's protocol fees in `token` (None = native) to `recipient`; on failure
+        /// they stay in the vault
+        #[ink(message)]
+        pub fn withdraw_fees(&mut self, recipient: AccountId, token: Option<AccountId>) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let amount = self.fee_vault.get(token).unwrap_or(0);
+            if amount == 0 {
+                return Ok(0);
+            }
+            self.fee_vault.remove(token);
+            if let Err(error) = self.pay_out_fees(token, recipient, amount) {
+                self.fee_vault.insert(token, &amount);
+                return Err(error);
+            }
+            self.env().emit_event(FeesWithdrawn { recipient, token, amount });
+            self.debug_assert_reconciled();
+            Ok(amount)
+        }
+
+        /// Split future protocol fees between `recipients`; shares must add up to 10_000 bps.
+        /// An empty list sends them to the owner again. Already accrued shares are unaffected.
+        #[ink(message)]
+        pub fn set_fee_recipients(&mut self, recipients: Vec<FeeRecipient>) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if recipients.len() > MAX_FEE_RECIPIENTS {
+                return Err(Error::InvalidLength);
+            }
+            let mut total_bps: u32 = 0;
+            for (i, recipient) in recipients.iter().enumerate() {
+                if recipient.share_bps == 0
+                    || recipients[..i].iter().any(|other| other.account == recipient.account)
+                {
+                    return Err(Error::InvalidFeeSplit);
+                }
+                total_bps = total_bps.saturating_add(recipient.share_bps);
+            }
+            if !recipients.is_empty() && total_bps != 10_000 {
+                return Err(Error::InvalidFeeSplit);
+            }
+
+            self.fee_recipients = recipients.clone();
+            self.env().emit_event(FeeRecipientsUpdated { recipients });
+            Ok(())
+        }
+
+        /// Send the caller's accrued fee share in `token` to the caller; on failure it stays accrued
+        #[ink(message)]
+        pub fn claim_fee_share(&mut self, token: Option<AccountId>) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            let recipient = self.env().caller();
+            let amount = self.accrued_fee_shares.get((recipient, token)).unwrap_or(0);
+            if amount == 0 {
+                return Ok(0);
+            }
+            self.accrued_fee_shares.remove((recipient, token));
+            if let Err(error) = self.pay_out_fees(token, recipient, amount) {
+                self.accrued_fee_shares.insert((recipient, token), &amount);
+                return Err(error);
+            }
+            self.env().emit_event(FeesWithdrawn { recipient, token, amount });
+            self.debug_assert_reconciled();
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        pub fn set_underwriter_fee_share(&mut self, fee_share_bps: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if fee_share_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            self.underwriter_fee_share_bps = fee_share_bps;
+            Ok(())
+        }
+
+        /// Share of a timed-out resolver's safety deposit paid to the maker; the rest accrues
+        /// to the protocol fee vault
+        #[ink(message)]
+        pub fn set_slash_maker_share(&mut self, share_bps: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if share_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            self.slash_maker_share_bps = share_bps;
+            self.env().emit_event(SlashMakerShareSet { share_bps });
+            Ok(())
+        }
+
+        /// Tip, in basis points of the safety deposit, paid to a third party cancelling a locked
+        /// order once public cancellation opens (the Ethereum escrows' `publicCancel` incentive).
+        /// It is the only incentive such a cancel pays.
+        #[ink(message)]
+        pub fn set_cancellation_tip(&mut self, tip_bps: u32) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if tip_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+            self.cancellation_tip_bps = tip_bps;
+            self.env().emit_event(CancellationTipSet { tip_bps });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.owner = new_owner;
+            Ok(())
+        }
+
+        /// Announce an `upgrade` to `code_hash` (owner only); executable after `UPGRADE_DELAY`
+        #[ink(message)]
+        pub fn announce_upgrade(&mut self, code_hash: Hash) -> Result<(), Error> {
+            self.ensure_owner()?;
+            if self.pending_upgrade.get().flatten().is_some() {
+                return Err(Error::UpgradeAlreadyPending);
+            }
+
+            let executable_at = self.env().block_timestamp().saturating_add(UPGRADE_DELAY);
+            self.pending_upgrade.set(&Some(CodeUpgrade { code_hash, executable_at }));
+            self.env().emit_event(UpgradeAnnounced { code_hash, executable_at });
+            Ok(())
+        }
+
+        /// Drop the pending upgrade; guardians may veto it as well as the owner
+        #[ink(message)]
+        pub fn cancel_upgrade(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner && !self.guardians.contains(caller) {
+                return Err(Error::Unauthorized);
+            }
+            let upgrade = self.pending_upgrade.get().flatten().ok_or(Error::UpgradeNotPending)?;
+            self.pending_upgrade.set(&None);
+            self.env().emit_event(UpgradeCancelled { code_hash: upgrade.code_hash, cancelled_by: caller });
+            Ok(())
+        }
+
+        /// Swap the contract code in place, keeping storage and balance (owner only); must match
+        /// the pending `announce_upgrade`. Call `migrate` next if the new code's
+        /// `STORAGE_VERSION` is ahead; until then other state-changing messages fail with
+        /// `MigrationPending`.
+        #[ink(message)]
+        pub fn upgrade(&mut self, code_hash: Hash) -> Result<(), Error> {
+            self.ensure_owner()?;
+            let upgrade = self.pending_upgrade.get().flatten().ok_or(Error::UpgradeNotPending)?;
+            if upgrade.code_hash != code_hash {
+                return Err(Error::UpgradeNotPending);
+            }
+            let current_time = self.env().block_timestamp();
+            if current_time < upgrade.executable_at {
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at: upgrade.executable_at, current_time });
+            }
+
+            self.pending_upgrade.set(&None);
+            self.env().set_code_hash(&code_hash).map_err(|_| Error::UpgradeFailed)?;
+            self.env().emit_event(CodeUpgraded { code_hash, storage_version: self.storage_version });
+            Ok(())
+        }
+
+        /// Run the storage migrations between the stored version and `STORAGE_VERSION` (owner
+        /// only). Fields added by later versions must live under their own storage keys (`Lazy`,
+        /// `Mapping`) so the root struct still decodes before migrating; the root's packed fields
+        /// are those of version 1.
+        #[ink(message)]
+        pub fn migrate(&mut self) -> Result<(), Error> {
+            self.ensure_owner()?;
+            let from_version = self.storage_version;
+            if from_version >= STORAGE_VERSION {
+                return Err(Error::StorageUpToDate);
+            }
+            // Version steps go here, oldest first: `if self.storage_version < N { ...; }`
+            self.storage_version = STORAGE_VERSION;
+            self.env().emit_event(StorageMigrated { from_version, to_version: STORAGE_VERSION });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_storage_version(&self) -> u32 {
+            self.storage_version
+        }
+
+        /// Send the caller everything credited to them in `token` (None = native): every
+        /// settlement payout, and other payouts under the pull-payment backend
+        #[ink(message)]
+        pub fn claim(&mut self, token: Option<AccountId>) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            let payee = self.env().caller();
+            self.send_claimable(payee, token, payee)
+        }
+
+        /// `claim` to another destination, for payees whose own account cannot take the transfer
+        #[ink(message)]
+        pub fn claim_to(&mut self, token: Option<AccountId>, to: AccountId) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            let payee = self.env().caller();
+            self.send_claimable(payee, token, to)
+        }
+
+        /// `claim` of the caller's native balance
+        #[ink(message)]
+        pub fn withdraw_payouts(&mut self) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            self.claim(None)
+        }
+
+        /// Push `payee`'s claimable balance in `token` to it; the payee or a registered keeper may
+        /// call it. If the transfer fails the balance stays claimable, e.g. for `claim_to`.
+        /// Settlement never pushes a leg, so no transfer fails inside it and there is no
+        /// per-failure queue: the `(payee, token)` claimable balance is what keepers retry.
+        #[ink(message)]
+        pub fn retry_payout(&mut self, payee: AccountId, token: Option<AccountId>) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            let caller = self.env().caller();
+            if caller != payee && !self.keepers.get(caller).unwrap_or(false) {
+                return Err(Error::Unauthorized);
+            }
+            self.send_claimable(payee, token, payee)
+        }
+
+        /// Nonce `account`'s next signature must cover (see `nonces::digest`)
+        #[ink(message)]
+        pub fn get_nonce(&self, account: AccountId) -> u64 {
+            self.nonces.get(account)
+        }
+
+        /// Revoke the caller's outstanding signatures over every nonce up to and including `nonce`
+        #[ink(message)]
+        pub fn invalidate_nonce(&mut self, nonce: u64) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let account = self.env().caller();
+            if !self.nonces.invalidate_through(account, nonce) {
+                return Err(Error::NonceAlreadyUsed);
+            }
+            self.env().emit_event(NonceInvalidated { account, nonce });
+            Ok(())
+        }
+
+        /// Native balance credited to `payee` and not yet withdrawn
+        #[ink(message)]
+        pub fn get_pending_payout(&self, payee: AccountId) -> Balance {
+            self.get_claimable(payee, None)
+        }
+
+        /// Balance in `token` (None = native) credited to `payee` and not yet claimed
+        #[ink(message)]
+        pub fn get_claimable(&self, payee: AccountId, token: Option<AccountId>) -> Balance {
+            self.claimable.get((payee, token)).unwrap_or(0)
+        }
+
+        /// Replace the recovery council; an empty member list disables recovery
+        #[ink(message)]
+        pub fn set_recovery_council(
+            &mut self,
+            members: Vec<AccountId>,
+            threshold: u32,
+            delay: Timestamp,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let size = members.len() as u32;
+            if size > MAX_RECOVERY_COUNCIL
+                || delay < MIN_RECOVERY_DELAY
+                || (size > 0 && (threshold == 0 || threshold > size))
+            {
+                return Err(Error::InvalidRecoveryConfig);
+            }
+            for (i, member) in members.iter().enumerate() {
+                if members[..i].contains(member) {
+                    return Err(Error::InvalidRecoveryConfig);
+                }
+            }
+
+            // Membership changes invalidate collected approvals
+            if let Some(recovery) = self.pending_recovery.take() {
+                self.env().emit_event(RecoveryCancelled { new_owner: recovery.new_owner });
+            }
+            self.recovery_council = members.clone();
+            self.recovery_threshold = threshold;
+            self.recovery_delay = delay;
+            self.env().emit_event(RecoveryCouncilUpdated { members, threshold, delay });
+            Ok(())
+        }
+
+        /// Council member starts rotating the owner to `new_owner`
+        #[ink(message)]
+        pub fn propose_recovery(&mut self, new_owner: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let proposer = self.ensure_recovery_council()?;
+            if self.pending_recovery.is_some() {
+                return Err(Error::RecoveryAlreadyPending);
+            }
+
+            let proposed_at = self.env().block_timestamp();
+            self.pending_recovery = Some(OwnerRecovery {
+                new_owner,
+                approvals: ink::prelude::vec![proposer],
+                proposed_at,
+            });
+            self.env().emit_event(RecoveryProposed {
+                proposer,
+                new_owner,
+                executable_at: proposed_at.saturating_add(self.recovery_delay),
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn approve_recovery(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let member = self.ensure_recovery_council()?;
+            let mut recovery = self.pending_recovery.take().ok_or(Error::RecoveryNotPending)?;
+            if recovery.approvals.contains(&member) {
+                self.pending_recovery = Some(recovery);
+                return Err(Error::RecoveryAlreadyApproved);
+            }
+
+            recovery.approvals.push(member);
+            self.env().emit_event(RecoveryApproved {
+                member,
+                new_owner: recovery.new_owner,
+                approvals: recovery.approvals.len() as u32,
+            });
+            self.pending_recovery = Some(recovery);
+            Ok(())
+        }
+
+        /// Current owner vetoes a pending recovery during the delay
+        #[ink(message)]
+        pub fn cancel_recovery(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let recovery = self.pending_recovery.take().ok_or(Error::RecoveryNotPending)?;
+            self.env().emit_event(RecoveryCancelled { new_owner: recovery.new_owner });
+            Ok(())
+        }
+
+        /// Anyone may finalize once the delay elapsed with enough approvals
+        #[ink(message)]
+        pub fn execute_recovery(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let recovery = self.pending_recovery.as_ref().ok_or(Error::RecoveryNotPending)?;
+            let unlocks_at = recovery.proposed_at.saturating_add(self.recovery_delay);
+            let current_time = self.env().block_timestamp();
+            if current_time < unlocks_at {
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at, current_time });
+            }
+            if (recovery.approvals.len() as u32) < self.recovery_threshold {
+                return Err(Error::RecoveryThresholdNotMet);
+            }
+
+            let new_owner = recovery.new_owner;
+            let previous_owner = self.owner;
+            self.owner = new_owner;
+            self.pending_recovery = None;
+            self.env().emit_event(OwnershipRecovered { previous_owner, new_owner });
+            Ok(())
+        }
+
+        /// Applies to rescues requested afterwards
+        #[ink(message)]
+        pub fn set_rescue_delay(&mut self, delay: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if delay < MIN_RESCUE_DELAY {
+                return Err(Error::InvalidTimelocks);
+            }
+            self.rescue_delay.set(&delay);
+            Ok(())
+        }
+
+        /// Announce a `rescue_funds` of stray `token` (None = native); executable after the
+        /// rescue delay
+        #[ink(message)]
+        pub fn request_rescue(
+            &mut self,
+            token: Option<AccountId>,
+            amount: Balance,
+            recipient: AccountId,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if amount == 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if self.get_pending_rescue().is_some() {
+                return Err(Error::RescueAlreadyPending);
+            }
+
+            let executable_at = self.env().block_timestamp().saturating_add(self.get_rescue_delay());
+            self.pending_rescue.set(&Some(FundsRescue { token, amount, recipient, executable_at }));
+            self.env().emit_event(RescueRequested { token, recipient, amount, executable_at });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn cancel_rescue(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let rescue = self.get_pending_rescue().ok_or(Error::RescueNotPending)?;
+            self.pending_rescue.set(&None);
+            self.env().emit_event(RescueCancelled { token: rescue.token, amount: rescue.amount });
+            Ok(())
+        }
+
+        /// Send funds held beyond the asset ledger to `recipient`; must match the pending
+        /// `request_rescue` and never touches escrowed order funds, deposits or fees
+        #[ink(message)]
+        pub fn rescue_funds(
+            &mut self,
+            token: Option<AccountId>,
+            amount: Balance,
+            recipient: AccountId,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let rescue = self.get_pending_rescue().ok_or(Error::RescueNotPending)?;
+            if rescue.token != token || rescue.amount != amount || rescue.recipient != recipient {
+                return Err(Error::RescueNotPending);
+            }
+            let current_time = self.env().block_timestamp();
+            if current_time < rescue.executable_at {
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at: rescue.executable_at, current_time });
+            }
+            let surplus = self.reconcile(token)?.surplus;
+            if amount > surplus {
+                return Err(Error::RescueExceedsSurplus { surplus, amount });
+            }
+
+            self.pending_rescue.set(&None);
+            self.payout(token, recipient, amount)?;
+            self.env().emit_event(FundsRescued { token, recipient, amount });
+            self.debug_assert_reconciled();
+            Ok(())
+        }
+
+        /// Configure a PSP22 stablecoin accepted for safety deposits (`None` disables token deposits)
+        #[ink(message)]
+        pub fn set_deposit_token(
+            &mut self,
+            token: Option<AccountId>,
+            min_deposit: Balance,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.deposit_token = token;
+            self.min_token_safety_deposit = min_deposit;
+            Ok(())
+        }
+
+        /// Require resolvers to hold `min_balance` of a PSP22 `token` to lock orders (`None`
+        /// disables the requirement)
+        #[ink(message)]
+        pub fn set_access_token(&mut self, token: Option<AccountId>, min_balance: Balance) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.access_token.set(&AccessTokenConfig { token, min_balance });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_storage_deposit_per_byte(&mut self, price: Balance) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.storage_deposit_per_byte = price;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_archive_retention(&mut self, retention: Timestamp) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.archive_retention = retention;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_event_version(&mut self, version: EventVersion) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.event_version = version;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_src_token_list_mode(&mut self, mode: TokenListMode) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.src_token_list_mode = mode;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_src_token_denied(&mut self, token: AccountId, denied: bool) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if denied {
+                self.denied_src_tokens.insert(token, &true);
+            } else {
+                self.denied_src_tokens.remove(token);
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_src_token_allowed(&mut self, token: AccountId, allowed: bool) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if allowed {
+                self.allowed_src_tokens.insert(token, &true);
+            } else {
+                self.allowed_src_tokens.remove(token);
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_src_token_decimals(&mut self, token: AccountId, decimals: u8) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if decimals > MAX_TOKEN_DECIMALS {
+                return Err(Error::InvalidDecimals);
+            }
+            self.src_token_decimals.insert(token, &decimals);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn register_dst_token(
+            &mut self,
+            token: [u8; 20],
+            symbol_hash: [u8; 32],
+            decimals: u8,
+            enabled: bool,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if token == [0u8; 20] {
+                return Err(Error::InvalidEthereumAddress);
+            }
+            if decimals > MAX_TOKEN_DECIMALS {
+                return Err(Error::InvalidDecimals);
+            }
+            self.dst_tokens.insert(token, &DstTokenInfo {
+                address: token,
+                symbol_hash,
+                decimals,
+                enabled,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_dst_token_enabled(&mut self, token: [u8; 20], enabled: bool) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            let mut info = self.dst_tokens.get(token).ok_or(Error::UnsupportedToken)?;
+            info.enabled = enabled;
+            self.dst_tokens.insert(token, &info);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_allow_all_dst_tokens(&mut self, allow_all: bool) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            self.allow_all_dst_tokens = allow_all;
+            Ok(())
+        }
+
+        // --- Resolver Registration ---
+
+        /// Bond native stake; resolvers at or above `min_resolver_stake` count as approved
+        #[ink(message, payable)]
+        pub fn register_resolver(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            if self.min_resolver_stake == 0 {
+                return Err(Error::Unauthorized);
+            }
+
+            let resolver = self.env().caller();
+            if self.operator_resolvers.contains(resolver) {
+                return Err(Error::OperatorInUse);
+            }
+            let stake = self.resolver_stakes.get(resolver).unwrap_or(0)
+                .checked_add(self.env().transferred_value())
+                .ok_or(Error::ArithmeticOverflow)?;
+            if stake < self.min_resolver_stake {
+                return Err(Error::InsufficientDepositV2 {
+                    required: self.min_resolver_stake,
+                    provided: stake,
+                });
+            }
+            self.resolver_stakes.insert(resolver, &stake);
+            self.adjust_ledger(None, LedgerEntry::Deposits, self.env().transferred_value(), true);
+
+            self.env().emit_event(ResolverRegistered { resolver, stake });
+            Ok(())
+        }
+
+        /// Start unbonding the caller's whole stake once it has no open orders left; approval via
+        /// stake ends immediately
+        #[ink(message)]
+        pub fn unregister_resolver(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let resolver = self.env().caller();
+            self.ensure_no_open_orders(resolver)?;
+            let stake = self.resolver_stakes.take(resolver).ok_or(Error::ResolverNotRegistered)?;
+
+            let unlocks_at = self.env().block_timestamp()
+                .checked_add(self.resolver_unbonding_period)
+                .ok_or(Error::ArithmeticOverflow)?;
+            let pending = self.resolver_unbondings.get(resolver).map(|u| u.amount).unwrap_or(0);
+            let amount = pending.checked_add(stake).ok_or(Error::ArithmeticOverflow)?;
+            self.resolver_unbondings.insert(resolver, &ResolverUnbonding { amount, unlocks_at });
+
+            self.env().emit_event(ResolverUnbondingStarted { resolver, amount, unlocks_at });
+            Ok(())
+        }
+
+        /// Withdraw stake whose unbonding period has passed; orders locked meanwhile (under another
+        /// approval) keep it bonded until they settle
+        #[ink(message)]
+        pub fn withdraw_resolver_stake(&mut self) -> Result<Balance, Error> {
+            self.ensure_migrated()?;
+            let resolver = self.env().caller();
+            self.ensure_no_open_orders(resolver)?;
+            let unbonding = self.resolver_unbondings.get(resolver).ok_or(Error::ResolverNotRegistered)?;
+            let current_time = self.env().block_timestamp();
+            if current_time < unbonding.unlocks_at {
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at: unbonding.unlocks_at, current_time });
+            }
+
+            self.resolver_unbondings.remove(resolver);
+            self.payout(None, resolver, unbonding.amount)?;
+            self.adjust_ledger(None, LedgerEntry::Deposits, unbonding.amount, false);
+
+            self.env().emit_event(ResolverStakeWithdrawn { resolver, amount: unbonding.amount });
+            Ok(unbonding.amount)
+        }
+
+        /// Offer a hot key deploy/commit/execute rights as the caller's resolver identity
+        /// (stake and approval stay with the identity); the key binds once it calls
+        /// `accept_operator`. `None` withdraws the offer and revokes the current operator.
+        #[ink(message)]
+        pub fn propose_operator(&mut self, operator: Option<AccountId>) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let resolver = self.env().caller();
+            match operator {
+                Some(operator) => {
+                    self.ensure_operator_available(operator, resolver)?;
+                    self.proposed_operators.insert(resolver, &operator);
+                    self.env().emit_event(OperatorProposed { resolver, operator });
+                }
+                None => {
+                    self.proposed_operators.remove(resolver);
+                    if let Some(previous) = self.resolver_operators.take(resolver) {
+                        self.operator_resolvers.remove(previous);
+                    }
+                    self.env().emit_event(OperatorSet { resolver, operator: None });
+                }
+            }
+            Ok(())
+        }
+
+        /// Bind the caller as `resolver`'s operator, replacing its previous one; the caller
+        /// must have been proposed and may neither be a resolver nor act for another
+        #[ink(message)]
+        pub fn accept_operator(&mut self, resolver: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let operator = self.env().caller();
+            if self.proposed_operators.get(resolver) != Some(operator) {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_operator_available(operator, resolver)?;
+
+            self.proposed_operators.remove(resolver);
+            if let Some(previous) = self.resolver_operators.take(resolver) {
+                self.operator_resolvers.remove(previous);
+            }
+            self.resolver_operators.insert(resolver, &operator);
+            self.operator_resolvers.insert(operator, &resolver);
+
+            self.env().emit_event(OperatorSet { resolver, operator: Some(operator) });
+            Ok(())
+        }
+
+        /// Exclude `resolver` from all of the caller's orders, current and future
+        #[ink(message)]
+        pub fn block_resolver(&mut self, resolver: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let maker = self.env().caller();
+            self.maker_blocked_resolvers.insert((maker, resolver), &true);
+            self.env().emit_event(ResolverBlocked { maker, resolver });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn unblock_resolver(&mut self, resolver: AccountId) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let maker = self.env().caller();
+            if self.maker_blocked_resolvers.take((maker, resolver)).is_some() {
+                self.env().emit_event(ResolverUnblocked { maker, resolver });
+            }
+            Ok(())
+        }
+
+        // --- Core Order Functions ---
+
+        /// Create a new cross-chain fusion order
+        #[ink(message, payable)]
+        pub fn create_order(&mut self, params: CreateOrderParams) -> Result<[u8; 32], Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            
+            let caller = self.env().caller();
+            let current_time = self.env().block_timestamp();
+            let transferred = self.env().transferred_value();
+
+            // Validate timing
+            if params.fill_deadline <= current_time {
+                return Err(Error::DeadlineExpiredV2 { deadline: params.fill_deadline, current_time });
+            }
+            let duration = params.fill_deadline - current_time;
+            if duration < self.min_order_duration || duration > self.max_order_duration {
+                return Err(Error::OrderDurationOutOfRange {
+                    duration,
+                    min: self.min_order_duration,
+                    max: self.max_order_duration,
+                });
+            }
+
+            // Validate payment (order amount plus storage deposit)
+            let memo_len = params.encrypted_memo.as_ref().map_or(0, |memo| memo.len());
+            let storage_len = params.metadata.len()
+                .saturating_add(params.basket.encoded_size())
+                .saturating_add(memo_len)
+                .saturating_add(params.blocked_resolvers.encoded_size());
+            let storage_cost = self.quote_storage_cost(storage_len as u32);
+            let tip_budget = params.executor_tip.as_ref().map_or(0, |tip| tip.remaining);
+            let required = params.src_amount
+                .checked_add(storage_cost)
+                .and_then(|required| required.checked_add(tip_budget))
+                .ok_or(Error::ArithmeticOverflow)?;
+            if transferred < required {
+                return Err(Error::InsufficientFundsV2 { required, provided: transferred });
+            }
+            // Anything above the order's obligations would sit untracked in the contract
+            if transferred > required {
+                return Err(Error::InvalidAmount);
+            }
+
+            // The source leg is the attached native value; PSP22 assets travel as basket legs
+            if params.src_token != AccountId::from(NATIVE_SRC_TOKEN) {
+                return Err(Error::UnsupportedToken);
+            }
+            // Validate source token against deny/allow lists
+            if !self.is_src_token_allowed(params.src_token) {
+                return Err(Error::UnsupportedToken);
+            }
+
+            // Validate destination token
+            if !self.is_dst_token_allowed(params.dst_token) {
+                return Err(Error::UnsupportedToken);
+            }
+
+            if let Some(schedule) = &params.late_fee_schedule {
+                if schedule.min_fee_bps > 10_000 {
+                    return Err(Error::InvalidAmount);
+                }
+            }
+
+            if let Some(tip) = &params.executor_tip {
+                if tip.per_action == 0 || tip.per_action > tip.remaining {
+                    return Err(Error::InvalidAmount);
+                }
+            }
+
+            if let Some(auction) = &params.auction {
+                if auction.start_amount.checked_to_u128().is_none()
+                    || auction.start_amount < auction.end_amount
+                    || auction.end_amount < params.min_dst_amount
+                {
+                    return Err(Error::InvalidAmount);
+                }
+                if auction.start_time >= auction.end_time || auction.end_time > params.fill_deadline {
+                    return Err(Error::InvalidTimelocks);
+                }
+            }
+
+            // Validate metadata size
+            if params.metadata.len() > MAX_ORDER_METADATA_LEN
+                || memo_len > MAX_ENCRYPTED_MEMO_LEN
+                || params.blocked_resolvers.len() > MAX_BLOCKED_RESOLVERS
+            {
+                return Err(Error::InvalidLength);
+            }
+
+            self.validate_basket(&params)?;
+            self.validate_recipient(&params.direction, &params.recipient)?;
+            if params.preferred_resolver.is_some_and(|resolver| params.blocked_resolvers.contains(&resolver)) {
+                return Err(Error::ResolverBlocked);
+            }
+
+            // Pull the basket legs, recording what actually arrived (fee-on-transfer tokens)
+            let mut basket = Vec::with_capacity(params.basket.len());
+            for leg in params.basket.iter() {
+                let received = self.pull_psp22(leg.token, caller, leg.amount)?;
+                basket.push(BasketLeg { token: leg.token, amount: received });
+            }
+
+            // Calculate private cancellation time (grace period configured per direction, or by
+            // the express lane for small orders)
+            let size_tier = self.size_tier_for(params.src_token, params.src_amount, &basket);
+            let private_cancellation_period = match &size_tier {
+                Some(tier) => tier.private_cancellation_period,
+                None => self.params_for(&params.direction).private_cancellation_period,
+            };
+            let private_cancellation = current_time
+                .checked_add(private_cancellation_period)
+                .ok_or(Error::ArithmeticOverflow)?;
+
+            // Generate order hash (similar to 1inch)
+            let preimage = HashPreimage::Root(OrderHashPreimage {
+                maker: caller,
+                src_token: params.src_token,
+                dst_token: params.dst_token,
+                src_amount: params.src_amount,
+                min_dst_amount: params.min_dst_amount,
+                fill_deadline: params.fill_deadline,
+                nonce: self.order_nonce,
+                created_at: current_time,
+                basket: basket.clone(),
+            });
+            let order_hash = self.hash_preimage(&preimage);
+
+            // Check for duplicate
+            if self.orders.contains(order_hash) || self.archived_orders.contains(order_hash) {
+                return Err(Error::OrderAlreadyExists);
+            }
+
+            // Create order
+            let order = FusionOrder {
+                order_hash,
+                maker: caller,
+                taker: None,
+                src_token: params.src_token,
+                dst_token: params.dst_token,
+                src_amount: params.src_amount,
+                dst_amount: params.min_dst_amount,
+                direction: params.direction.clone(),
+                ethereum_escrow: None,
+                hash_lock_info: HashLockInfo {
+                    hash_lock: HashLockState::Unset,
+                    secret: None,
+                    hash_algo: params.hash_algo.clone(),
+                },
+                time_locks: TimeLocks {
+                    fill_deadline: params.fill_deadline,
+                    private_cancellation,
+                    ..Default::default()
+                },
+                status: OrderStatus::Pending,
+                filled_amount: 0,
+                safety_deposit: 0,
+                safety_deposit_token: None,
+                resolver: None,
+                resolver_fee: params.max_resolver_fee,
+                created_at: current_time,
+                metadata: params.metadata.clone(),
+                min_resolver_reputation: params.min_resolver_reputation,
+                late_fee_schedule: params.late_fee_schedule.clone(),
+                parent_order: None,
+                nonce: self.order_nonce,
+                basket,
+                deposit_released: 0,
+                recipient: params.recipient.clone(),
+                encrypted_memo: params.encrypted_memo.clone(),
+                blocked_resolvers: params.blocked_resolvers.clone(),
+                size_tier,
+                preferred_resolver: params.preferred_resolver,
+                preferred_until: match params.preferred_resolver {
+                    Some(_) => current_time.saturating_add(self.referral_exclusivity),
+                    None => 0,
+                },
+                auction: params.auction.clone(),
+                taken_dst_amount: None,
+                locked_at: None,
+            };
+
+            self.save_order(&order);
+            self.adjust_ledger(None, LedgerEntry::Escrowed, order.src_amount, true);
+            for leg in order.basket.iter() {
+                self.adjust_ledger(Some(leg.token), LedgerEntry::Escrowed, leg.amount, true);
+            }
+            self.adjust_ledger(None, LedgerEntry::Deposits, storage_cost, true);
+            self.adjust_ledger(None, LedgerEntry::Tips, tip_budget, true);
+            if storage_cost > 0 {
+                self.storage_deposits.insert(order_hash, &StorageDeposit {
+                    payer: caller,
+                    amount: storage_cost,
+                });
+            }
+            if let Some(tip) = &params.executor_tip {
+                self.executor_tips.insert(order_hash, tip);
+            }
+            self.order_nonce = self.order_nonce.checked_add(1).ok_or(Error::ArithmeticOverflow)?;
+            // Live traffic ends the migration window
+            self.import_finalized = true;
+
+            self.emit_order_created(OrderCreatedV2 {
+                order_hash,
+                maker: caller,
+                src_token: params.src_token,
+                direction: params.direction,
+                src_amount: params.src_amount,
+                dst_amount: params.min_dst_amount,
+                fill_deadline: params.fill_deadline,
+                metadata: params.metadata,
+                recipient: params.recipient,
+                encrypted_memo: params.encrypted_memo,
+                preferred_resolver: params.preferred_resolver,
+                chain_id: self.ethereum_chain_id,
+                hash_algo: params.hash_algo,
+            });
+
+            self.debug_assert_reconciled();
+            Ok(order_hash)
+        }
+
+        /// Deploy source escrow (resolver-compatible interface)
+        #[ink(message, payable)]
+        pub fn deploy_src(
+            &mut self,
+            immutables: EscrowImmutables,
+            order_hash: [u8; 32],
+            signature: MakerSignature,
+            _amount: Balance,     // prefixed with underscore
+            _args: Vec<u8>,       // prefixed with underscore
+        ) -> Result<AccountId, Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            
+            // Get and validate order
+            let mut order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            if immutables.order_hash != order_hash
+                || immutables.maker != order.maker
+                || immutables.token != order.src_token
+                || immutables.amount != order.src_amount
+            {
+                return Err(Error::InvalidImmutables);
+            }
+
+            let safety_deposit = self.env().transferred_value();
+            let min_safety_deposit = self.min_safety_deposit_for(&order);
+            if safety_deposit < min_safety_deposit {
+                return Err(Error::InsufficientDepositV2 {
+                    required: min_safety_deposit,
+                    provided: safety_deposit,
+                });
+            }
+            
+            if order.status != OrderStatus::Pending {
+                return Err(Error::InvalidOrderStatus);
+            }
+
+            // The maker signs the order hash for this contract together with their current nonce
+            let nonce = self.nonces.get(order.maker);
+            let digest = nonces::digest(self.env().account_id(), order_hash, nonce);
+            self.verify_maker_signature(&order, digest, signature)?;
+            self.nonces.consume(order.maker);
+
+            // The taker being vetted must be the identity making this call
+            let caller = self.resolver_identity(self.env().caller());
+            if immutables.taker != caller {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_resolver_vetted(immutables.taker)?;
+            self.ensure_access_token_held(caller)?;
+            self.ensure_preferred_resolver(&order, immutables.taker)?;
+            self.ensure_resolver_not_blocked(&order, immutables.taker)?;
+            self.ensure_resolver_reputation(&order, immutables.taker)?;
+            self.ensure_collateralized(immutables.taker, self.order_exposure(&order))?;
+            self.ensure_hash_lock_unused(immutables.hash_lock)?;
+            self.take_hash_lock_commitment(order_hash, caller, immutables.hash_lock)?;
+
+            // Lock the order; the resolver takes it at the current auction price
+            let locked_at = self.env().block_timestamp();
+            order.time_locks = self.lock_time_locks(&order, locked_at)?;
+            order.locked_at = Some(locked_at);
+            order.taken_dst_amount = Some(Self::current_dst_amount(&order, locked_at)?);
+            order.status = OrderStatus::Locked;
+            order.safety_deposit = safety_deposit;
+            order.hash_lock_info.hash_lock = HashLockState::Set(immutables.hash_lock);
+            order.resolver = Some(immutables.taker); // taker is resolver in this context
+            order.taker = Some(immutables.taker);
+            self.track_resolver_order(immutables.taker, &order, true);
+
+            // Escrow address from the immutables `get_escrow_immutables` reports
+            let immutables_mem = Self::escrow_immutables(order_hash, &order).ok_or(Error::InvalidImmutables)?;
+            let escrow_address = self.compute_escrow_address(&immutables_mem)?;
+
+            // Store escrow data
+            self.save_order(&order);
+            self.adjust_ledger(None, LedgerEntry::Deposits, safety_deposit, true);
+            self.escrow_addresses.insert(order_hash, &escrow_address);
+            self.active_hash_locks.insert(immutables.hash_lock, &order_hash);
+            
+            self.total_escrows_created = self.total_escrows_created.saturating_add(1);
+
+            self.env().emit_event(SrcEscrowDeployed {
+                order_hash: immutables_mem.order_hash,
+                escrow_address,
+                immutables: immutables_mem,
+                safety_deposit,
+            });
+
+            Ok(escrow_address)
+        }
+
+        /// Deploy destination escrow (resolver-compatible interface)
+        #[ink(message, payable)]
+        pub fn deploy_dst(
+            &mut self,
+            dst_immutables: EscrowImmutables,
+            src_cancellation_timestamp: Timestamp,
+        ) -> Result<AccountId, Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            
+            let _deposit_amount = self.env().transferred_value(); // prefixed with underscore
+            let order_hash = dst_immutables.order_hash;
+            
+            // Get and validate order
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+
+            // Only the vetted resolver that locked the order records its destination escrow
+            let caller = self.resolver_identity(self.env().caller());
+            self.ensure_resolver_vetted(caller)?;
+            if order.resolver != Some(caller) {
+                return Err(Error::OnlyResolver);
+            }
+            
+            // Compute escrow address
+            let escrow_address = self.compute_escrow_address(&dst_immutables)?;
+
+            // Store escrow data; the Ethereum escrow is only recorded once it is known
+            self.escrow_addresses.insert(order_hash, &escrow_address);
+
+            self.env().emit_event(DstEscrowDeployed {
+                order_hash,
+                escrow_address,
+                immutables: dst_immutables,
+                src_cancellation_timestamp,
+            });
+
+            Ok(escrow_address)
+        }
+
+        /// Deploy escrow with resolver (primary interface)
+        #[ink(message, payable)]
+        pub fn deploy_escrow(
+            &mut self,
+            order_hash: [u8; 32],
+            params: ResolverParams,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            
+            let caller = self.resolver_identity(self.env().caller());
+            let native_deposit = self.env().transferred_value();
+
+            // Get and validate order
+            let mut order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+
+            // Validate resolver stake (native or configured stablecoin)
+            let (safety_deposit, safety_deposit_token) = if params.token_safety_deposit > 0 {
+                let token = self.deposit_token.ok_or(Error::UnsupportedToken)?;
+                if native_deposit > 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                if params.token_safety_deposit < self.min_token_safety_deposit {
+                    return Err(Error::InsufficientDepositV2 {
+                        required: self.min_token_safety_deposit,
+                        provided: params.token_safety_deposit,
+                    });
+                }
+                (params.token_safety_deposit, Some(token))
+            } else {
+                let min_safety_deposit = self.min_safety_deposit_for(&order);
+                if native_deposit < min_safety_deposit {
+                    return Err(Error::InsufficientDepositV2 {
+                        required: min_safety_deposit,
+                        provided: native_deposit,
+                    });
+                }
+                (native_deposit, None)
+            };
+            
+            if order.status != OrderStatus::Pending {
+                return Err(Error::InvalidOrderStatus);
+            }
+
+            // The resolver being vetted must be the identity making this call
+            if params.resolver != caller {
+                return Err(Error::Unauthorized);
+            }
+            self.ensure_resolver_vetted(params.resolver)?;
+            self.ensure_access_token_held(caller)?;
+            self.ensure_preferred_resolver(&order, params.resolver)?;
+            self.ensure_resolver_not_blocked(&order, params.resolver)?;
+            self.ensure_resolver_reputation(&order, params.resolver)?;
+            self.ensure_collateralized(params.resolver, self.order_exposure(&order))?;
+
+            let locked_at = self.env().block_timestamp();
+            order.time_locks = self.lock_time_locks(&order, locked_at)?;
+
+            // Validate hash lock uniqueness
+            self.ensure_hash_lock_unused(params.hash_lock)?;
+            self.take_hash_lock_commitment(order_hash, caller, params.hash_lock)?;
+
+            // Pull stablecoin deposit (requires prior PSP22 approval); only what arrived counts
+            let safety_deposit = match safety_deposit_token {
+                Some(token) => {
+                    let received = self.pull_psp22(token, self.env().caller(), safety_deposit)?;
+                    if received < self.min_token_safety_deposit {
+                        return Err(Error::InsufficientDepositV2 {
+                            required: self.min_token_safety_deposit,
+                            provided: received,
+                        });
+                    }
+                    received
+                }
+                None => safety_deposit,
+            };
+
+            // Update order with escrow info
+            order.resolver = Some(params.resolver);
+            self.track_resolver_order(params.resolver, &order, true);
+            order.hash_lock_info.hash_lock = HashLockState::Set(params.hash_lock);
+            order.safety_deposit = safety_deposit;
+            order.safety_deposit_token = safety_deposit_token;
+            order.resolver_fee = params.resolver_fee;
+            // The resolver takes the order at the current auction price
+            order.taken_dst_amount = Some(Self::current_dst_amount(&order, locked_at)?);
+            order.status = OrderStatus::Locked;
+            order.locked_at = Some(locked_at);
+            order.taker = Some(caller);
+            order.ethereum_escrow = Some(EthereumEscrowInfo {
+                escrow_address: params.ethereum_escrow_address,
+                tx_hash: None,
+                block_number: None,
+            });
+
+            // Escrow address from the immutables `get_escrow_immutables` reports
+            let immutables = Self::escrow_immutables(order_hash, &order).ok_or(Error::InvalidImmutables)?;
+            let escrow_address = self.compute_escrow_address(&immutables)?;
+
+            // Store updates
+            self.save_order(&order);
+            self.adjust_ledger(safety_deposit_token, LedgerEntry::Deposits, safety_deposit, true);
+            self.escrow_addresses.insert(order_hash, &escrow_address);
+            self.active_hash_locks.insert(params.hash_lock, &order_hash);
+
+            self.emit_escrow_deployed(EscrowDeployed {
+                order_hash,
+                resolver: params.resolver,
+                src_token: order.src_token,
+                hash_lock: params.hash_lock,
+                ethereum_escrow: params.ethereum_escrow_address,
+                safety_deposit,
+            }, order.hash_lock_info.hash_algo.clone());
+
+            Ok(())
+        }
+
+        /// Withdraw from escrow (resolver-compatible interface)
+        #[ink(message)]
+        pub fn withdraw(
+            &mut self,
+            order_hash: [u8; 32],
+            secret: [u8; 32],
+            _immutables: EscrowImmutables, // prefixed with underscore
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.withdraw_internal(order_hash, &secret)
+        }
+
+        /// `withdraw` for hash locks over a preimage that is not exactly 32 bytes
+        #[ink(message)]
+        pub fn withdraw_with_preimage(
+            &mut self,
+            order_hash: [u8; 32],
+            secret: Vec<u8>,
+            _immutables: EscrowImmutables,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            Self::ensure_secret_len(&secret)?;
+            self.withdraw_internal(order_hash, &secret)
+        }
+
+        fn withdraw_internal(&mut self, order_hash: [u8; 32], secret: &[u8]) -> Result<(), Error> {
+            let escrow_address = self.escrow_addresses.get(order_hash)
+                .ok_or(Error::EscrowNotFound)?;
+
+            // Get order
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+
+            // Verify secret against hash lock
+            self.verify_secret(&order, secret)?;
+
+            // Check timelock constraints
+            let current_time = self.env().block_timestamp();
+            self.check_withdrawal_timelock(&order, self.resolver_identity(self.env().caller()), current_time)?;
+
+            // Execute the swap logic
+            self.execute_swap_internal(order_hash, secret)?;
+
+            // The resolver-compatible event only carries 32-byte secrets; every preimage is in
+            // `SwapExecutedV2`
+            if let Ok(secret) = <[u8; 32]>::try_from(secret) {
+                self.env().emit_event(EscrowWithdrawal {
+                    order_hash,
+                    escrow_address,
+                    secret,
+                    amount: order.src_amount,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Execute swap with secret reveal (primary interface)
+        #[ink(message)]
+        pub fn execute_swap(
+            &mut self,
+            order_hash: [u8; 32],
+            secret: [u8; 32],
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.execute_swap_internal(order_hash, &secret)
+        }
+
+        /// `execute_swap` for hash locks over a preimage that is not exactly 32 bytes
+        #[ink(message)]
+        pub fn execute_swap_with_preimage(
+            &mut self,
+            order_hash: [u8; 32],
+            secret: Vec<u8>,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            Self::ensure_secret_len(&secret)?;
+            self.execute_swap_internal(order_hash, &secret)
+        }
+
+        /// Step 1 of commit-reveal execution: the order's resolver claims an exclusive window, ending
+        /// no later than `src_cancellation`, without exposing the secret
+        #[ink(message)]
+        pub fn commit_execution(&mut self, order_hash: [u8; 32], commitment: [u8; 32]) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+
+            let executor = self.resolver_identity(self.env().caller());
+            let state = self.order_states.get(order_hash).ok_or(Error::OrderNotFound)?;
+            if state.status != OrderStatus::Locked {
+                return Err(Error::InvalidOrderStatus);
+            }
+            // Only the order's resolver may claim the window; anyone else could use it to stall
+            // the resolver's own withdrawal
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            if order.resolver != Some(executor) {
+                return Err(Error::OnlyResolver);
+            }
+
+            // A live commitment cannot be replaced or extended, not even by its executor
+            let current_time = self.env().block_timestamp();
+            if matches!(
+                self.execution_commitments.get(order_hash),
+                Some(commitment) if current_time < commitment.exclusive_until
+            ) {
+                return Err(Error::ExecutionCommitted);
+            }
+            let src_cancellation = order.time_locks.src_cancellation;
+            if current_time >= src_cancellation {
+                return Err(Error::DeadlineExpired);
+            }
+
+            // Exclusivity never reaches into the cancellation stages
+            let exclusive_until = current_time
+                .checked_add(self.execution_exclusivity)
+                .ok_or(Error::ArithmeticOverflow)?
+                .min(src_cancellation);
+            self.execution_commitments.insert(order_hash, &ExecutionCommitment {
+                executor,
+                commitment,
+                committed_at_block: self.env().block_number(),
+                exclusive_until,
+            });
+
+            self.env().emit_event(ExecutionCommitted {
+                order_hash,
+                executor,
+                exclusive_until,
+            });
+
+            Ok(())
+        }
+
+        /// Step 2: reveal the secret in a later block and execute as the committed executor
+        #[ink(message)]
+        pub fn reveal_execution(&mut self, order_hash: [u8; 32], secret: [u8; 32]) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let executor = self.resolver_identity(self.env().caller());
+            let commitment = self.execution_commitments.get(order_hash).ok_or(Error::ExecutionNotCommitted)?;
+            if commitment.executor != executor {
+                return Err(Error::ExecutionCommitted);
+            }
+            if self.env().block_number() <= commitment.committed_at_block {
+                return Err(Error::RevealTooEarly);
+            }
+            if self.execution_commitment_hash(&secret, &executor) != commitment.commitment {
+                return Err(Error::InvalidSecret);
+            }
+
+            self.execute_swap_internal(order_hash, &secret)
+        }
+
+        /// Internal swap execution logic
+        fn execute_swap_internal(
+            &mut self,
+            order_hash: [u8; 32],
+            secret: &[u8],
+        ) -> Result<(), Error> {
+            let caller = self.resolver_identity(self.env().caller());
+            let current_time = self.env().block_timestamp();
+
+            // The whole order settles as a single fill (fees, late-fee decay, underwriter share);
+            // a valid secret is still accepted during the grace window
+            let (mut order, taker, split) = self.check_fill(order_hash, None, caller, current_time, |order| {
+                self.verify_secret(order, secret)
+            })?;
+            let in_grace_period = current_time > order.time_locks.fill_deadline;
+            let total_amount = order.src_amount;
+
+            // Commit the settled order and its accounting before any payout
+            order.status = OrderStatus::Executed;
+            order.filled_amount = total_amount;
+            order.hash_lock_info.secret = Some(secret.to_vec());
+            self.adjust_ledger(None, LedgerEntry::Escrowed, total_amount, false);
+            self.total_volume = self.total_volume.checked_add(total_amount).ok_or(Error::ArithmeticOverflow)?;
+            self.accrue_fill_fees(&split)?;
+
+            let receipt = FillReceipt {
+                order_hash,
+                resolver: taker,
+                fill_amount: total_amount,
+                total_filled: total_amount,
+                resolver_fee: split.resolver_fee,
+                maker_fee: split.maker_fee,
+                taker_fee: split.taker_fee,
+                deposit_released: 0,
+                filled_at: current_time,
+                block_number: self.env().block_number(),
+            };
+            self.finalize_order(&mut order, false, split.underwriter_fee, Some(receipt))?;
+
+            // Resolver gets the funds to provide liquidity on Ethereum, with the basket legs
+            self.pay_fill(&order, &split)?;
+            if order.direction == SwapDirection::PolkadotToEthereum {
+                let resolver_address = order.resolver.ok_or(Error::OnlyResolver)?;
+                self.transfer_basket(&order, resolver_address)?;
+            }
+
+            // Anyone but the assigned resolver executing with the secret earns the maker's tip
+            if order.resolver != Some(caller) {
+                self.pay_executor_tip(order_hash, self.env().caller(), OrderAction::Execute)?;
+            }
+
+            self.emit_fill_fees(order_hash, taker, &split);
+            self.emit_swap_executed(SwapExecutedV2 {
+                order_hash,
+                resolver: caller,
+                src_token: order.src_token,
+                secret: secret.to_vec(),
+                amount_filled: total_amount,
+                late_fee_rebate: split.late_fee_rebate,
+                in_grace_period,
+                metadata: order.metadata,
+                recipient: order.recipient,
+                chain_id: self.ethereum_chain_id,
+                hash_algo: order.hash_lock_info.hash_algo,
+            });
+
+            self.debug_assert_reconciled();
+            Ok(())
+        }
+
+        /// Cancel escrow (resolver-compatible interface)
+        #[ink(message)]
+        pub fn cancel(
+            &mut self,
+            order_hash: [u8; 32],
+            _immutables: EscrowImmutables, // prefixed with underscore
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            let escrow_address = self.escrow_addresses.get(order_hash)
+                .ok_or(Error::EscrowNotFound)?;
+
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            if !matches!(order.status, OrderStatus::Locked | OrderStatus::PartialFill) {
+                return Err(Error::InvalidOrderStatus);
+            }
+
+            // Check cancellation timelock
+            let caller = self.env().caller();
+            let current_time = self.env().block_timestamp();
+            self.check_cancellation_timelock(&order, self.resolver_identity(caller), current_time)?;
+
+            let refund_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
+            let reason = self.locked_cancel_reason(&order, caller, current_time);
+            self.cancel_unchecked(order, caller, current_time, reason)?;
+
+            self.env().emit_event(EscrowCancellation {
+                order_hash,
+                escrow_address,
+                refund_amount,
+            });
+            Ok(())
+        }
+
+        /// Cancel order (primary interface)
+        #[ink(message)]
+        pub fn cancel_order(&mut self, order_hash: [u8; 32]) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            
+            let caller = self.env().caller();
+            let current_time = self.env().block_timestamp();
+
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+
+            let (can_cancel, reason) = match order.status {
+                OrderStatus::Pending => {
+                    // Maker can cancel during private cancellation period
+                    if caller == order.maker && current_time <= order.time_locks.private_cancellation {
+                        (true, CancelReason::MakerCancellation)
+                    } else {
+                        (false, CancelReason::MakerCancellation)
+                    }
+                },
+                OrderStatus::Locked | OrderStatus::PartialFill => {
+                    // Anyone once public cancellation opens, only the resolver before that; a
+                    // locked order is never the maker's to cancel
+                    let allowed = match order.time_locks.src_stage(current_time) {
+                        SrcStage::PublicCancellation => true,
+                        SrcStage::PrivateCancellation => Some(self.resolver_identity(caller)) == order.resolver,
+                        _ => false,
+                    };
+                    (allowed, self.locked_cancel_reason(&order, caller, current_time))
+                }
+                _ => (false, CancelReason::EmergencyStop),
+            };
+
+            if !can_cancel {
+                return Err(Error::Unauthorized);
+            }
+            self.cancel_unchecked(order, caller, current_time, reason)
+        }
+
+        /// Permissionless sweep: Pending orders past their fill deadline and Locked ones in
+        /// public cancellation are cancelled, refunded and archived on the spot. The caller is
+        /// paid once per order: the keeper bounty out of a pending order's storage deposit, the
+        /// cancellation tip out of a locked order's safety deposit. Hashes that are unknown or
+        /// not expired are skipped. Returns how many orders were pruned.
+        #[ink(message)]
+        pub fn prune_expired_orders(&mut self, hashes: Vec<[u8; 32]>) -> Result<u32, Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+            if hashes.len() > MAX_PAGE_LEN as usize {
+                return Err(Error::InvalidLength);
+            }
+
+            let caller = self.env().caller();
+            let current_time = self.env().block_timestamp();
+            let mut pruned = 0u32;
+            for order_hash in hashes {
+                let Some(order) = self.load_order(order_hash) else {
+                    continue;
+                };
+                let pending = order.status == OrderStatus::Pending;
+                let reason = match order.status {
+                    OrderStatus::Pending if current_time > order.time_locks.fill_deadline => {
+                        CancelReason::TimelockExpired
+                    }
+                    OrderStatus::Locked | OrderStatus::PartialFill
+                        if order.time_locks.src_stage(current_time) == SrcStage::PublicCancellation =>
+                    {
+                        self.locked_cancel_reason(&order, caller, current_time)
+                    }
+                    _ => continue,
+                };
+                self.cancel_unchecked(order, caller, current_time, reason)?;
+                let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+                self.archive_unchecked(order, current_time, pending)?;
+                pruned += 1;
+            }
+            Ok(pruned)
+        }
+
+        /// Refund and cancel an order the caller has already been authorized to cancel
+        fn cancel_unchecked(
+            &mut self,
+            mut order: FusionOrder,
+            caller: AccountId,
+            current_time: Timestamp,
+            reason: CancelReason,
+        ) -> Result<(), Error> {
+            let order_hash = order.order_hash;
+
+            // Calculate refund
+            let refund_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
+            self.adjust_ledger(None, LedgerEntry::Escrowed, refund_amount, false);
+
+            // Split the safety deposit and commit the cancelled order before any payout: a third
+            // party cancelling a timed-out order takes the tip, the rest of the deposit is slashed
+            let resolver_failed = self.resolver_failed(&order, current_time);
+            order.status = OrderStatus::Cancelled;
+            let timed_out_deposit = if reason == CancelReason::ResolverTimeout {
+                self.split_timed_out_deposit(&mut order, caller)?
+            } else {
+                None
+            };
+            self.finalize_order(&mut order, resolver_failed, 0, None)?;
+
+            // Execute refund
+            if let Some(deposit) = timed_out_deposit {
+                self.pay_timed_out_deposit(&order, caller, deposit)?;
+            }
+            self.queue_payout(None, order.maker, refund_amount)?;
+            self.transfer_basket(&order, order.maker)?;
+
+            self.env().emit_event(OrderCancelled {
+                order_hash,
+                refund_amount,
+                reason,
+                metadata: order.metadata,
+            });
+
+            self.debug_assert_reconciled();
+            Ok(())
+        }
+        
+        /// Trusted relayer reports that the order's Ethereum leg failed. Once `relayer_quorum`
+        /// currently trusted relayers agree the maker is refunded immediately, without waiting
+        /// for the timelock. Returns whether this report triggered the refund.
+        #[ink(message)]
+        pub fn relayer_initiated_refund(&mut self, order_hash: [u8; 32], evidence: Vec<u8>) -> Result<bool, Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+
+            let relayer = self.env().caller();
+            if self.relayer_quorum == 0 || !self.is_trusted_relayer(relayer) {
+                return Err(Error::Unauthorized);
+            }
+            if evidence.len() > MAX_REFUND_EVIDENCE_LEN {
+                return Err(Error::InvalidLength);
+            }
+
+            let mut order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            if !matches!(order.status, OrderStatus::Locked | OrderStatus::PartialFill) {
+                return Err(Error::InvalidOrderStatus);
+            }
+
+            let mut reports = self.refund_evidence.get(order_hash).unwrap_or_default();
+            if reports.iter().any(|report| report.relayer == relayer) {
+                return Err(Error::RefundAlreadyAttested);
+            }
+            let current_time = self.env().block_timestamp();
+            reports.push(RefundEvidence { relayer, evidence, submitted_at: current_time });
+            self.refund_evidence.insert(order_hash, &reports);
+
+            // Reports from relayers removed since they reported no longer count
+            let attestations = reports
+                .iter()
+                .filter(|report| self.is_trusted_relayer(report.relayer))
+                .count() as u32;
+            self.env().emit_event(RefundEvidenceSubmitted {
+                order_hash,
+                relayer,
+                attestations,
+                quorum: self.relayer_quorum,
+            });
+            if attestations < self.relayer_quorum {
+                return Ok(false);
+            }
+
+            let refund_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
+            self.adjust_ledger(None, LedgerEntry::Escrowed, refund_amount, false);
+
+            // The counterpart leg failed: underwriting collateral compensates the maker
+            order.status = OrderStatus::Cancelled;
+            self.finalize_order(&mut order, true, 0, None)?;
+            self.queue_payout(None, order.maker, refund_amount)?;
+            self.transfer_basket(&order, order.maker)?;
+
+            self.env().emit_event(OrderCancelled {
+                order_hash,
+                refund_amount,
+                reason: CancelReason::RelayerRefund,
+                metadata: order.metadata,
+            });
+
+            self.debug_assert_reconciled();
+            Ok(true)
+        }
+
+        /// Fill part of a locked order: the assigned resolver settles `fill_amount` of the source
+        /// funds like `execute_swap` settles the whole order, with this fill's pro-rata share of
+        /// the fees, and releases the matching share of its safety deposit
+        #[ink(message)]
+        pub fn execute_partial_fill(
+            &mut self,
+            order_hash: [u8; 32],
+            fill_amount: Balance,
+            secret: [u8; 32],
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.execute_partial_fill_internal(order_hash, fill_amount, &secret)
+        }
+
+        /// `execute_partial_fill` for hash locks over a preimage that is not exactly 32 bytes
+        #[ink(message)]
+        pub fn execute_partial_fill_with_preimage(
+            &mut self,
+            order_hash: [u8; 32],
+            fill_amount: Balance,
+            secret: Vec<u8>,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            Self::ensure_secret_len(&secret)?;
+            self.execute_partial_fill_internal(order_hash, fill_amount, &secret)
+        }
+
+        fn execute_partial_fill_internal(
+            &mut self,
+            order_hash: [u8; 32],
+            fill_amount: Balance,
+            secret: &[u8],
+        ) -> Result<(), Error> {
+            let caller = self.resolver_identity(self.env().caller());
+            let current_time = self.env().block_timestamp();
+
+            // Fees, late-fee decay and underwriter share apply to this fill's share
+            let (mut order, resolver, split) = self.check_fill(order_hash, Some(fill_amount), caller, current_time, |order| {
+                self.verify_secret(order, secret)
+            })?;
+
+            // Execute partial fill; the filled source amount leaves the escrowed ledger with it
+            order.filled_amount = order.filled_amount.checked_add(fill_amount).ok_or(Error::ArithmeticOverflow)?;
+            self.adjust_ledger(None, LedgerEntry::Escrowed, fill_amount, false);
+            self.total_volume = self.total_volume.checked_add(fill_amount).ok_or(Error::ArithmeticOverflow)?;
+            self.accrue_fill_fees(&split)?;
+
+            // Release the deposit pro rata to cumulative volume (the last fill takes the remainder)
+            let deposit_due = if order.filled_amount >= order.src_amount {
+                order.safety_deposit
+            } else {
+                math::mul_div(order.safety_deposit, order.filled_amount, order.src_amount)
+                    .ok_or(Error::ArithmeticOverflow)?
+            };
+            let deposit_released = deposit_due.saturating_sub(order.deposit_released);
+            order.deposit_released = order.deposit_released
+                .checked_add(deposit_released)
+                .ok_or(Error::ArithmeticOverflow)?;
+            
+            let receipt = FillReceipt {
+                order_hash,
+                resolver,
+                fill_amount,
+                total_filled: order.filled_amount,
+                resolver_fee: split.resolver_fee,
+                maker_fee: split.maker_fee,
+                taker_fee: split.taker_fee,
+                deposit_released,
+                filled_at: current_time,
+                block_number: self.env().block_number(),
+            };
+            if order.filled_amount >= order.src_amount {
+                order.status = OrderStatus::Executed;
+                order.hash_lock_info.secret = Some(secret.to_vec());
+                self.finalize_order(&mut order, false, split.underwriter_fee, Some(receipt))?;
+            } else {
+                order.status = OrderStatus::PartialFill;
+                self.record_fill(receipt);
+                self.save_order(&order);
+                // The underwriting itself settles with the last fill; its fee share is paid per fill
+                if let Some(underwriting) = self.underwritings.get(order_hash) {
+                    self.queue_payout(None, underwriting.underwriter, split.underwriter_fee)?;
+                }
+            }
+
+            // Resolver provides the liquidity on Ethereum for PolkadotToEthereum; the maker is paid here otherwise
+            self.pay_fill(&order, &split)?;
+            self.pay_safety_deposit(&order, resolver, deposit_released)?;
+            self.emit_fill_fees(order_hash, resolver, &split);
+
+            let remaining_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
+
+            self.env().emit_event(PartialFillExecuted {
+                order_hash,
+                filled_amount: order.filled_amount,
+                remaining_amount,
+                deposit_released,
+            });
+
+            self.debug_assert_reconciled();
+            Ok(())
+        }
+
+        /// Replace a long-settled order with its compact archival record (anyone may call;
+        /// the freed storage deposit goes back to its payer, less any keeper bounty)
+        #[ink(message)]
+        pub fn archive_order(&mut self, order_hash: [u8; 32]) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            let state = self.order_states.get(order_hash).ok_or(Error::OrderNotFound)?;
+
+            let settled_at = match state.settled_at {
+                Some(settled_at) if order.status.is_terminal() => settled_at,
+                _ => return Err(Error::InvalidOrderStatus),
+            };
+            let archivable_at = settled_at
+                .checked_add(self.archive_retention)
+                .ok_or(Error::ArithmeticOverflow)?;
+            let current_time = self.env().block_timestamp();
+            if current_time < archivable_at {
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at: archivable_at, current_time });
+            }
+            self.archive_unchecked(order, settled_at, true)
+        }
+
+        /// Replace a settled order with its archival record and hand back what it held in storage;
+        /// the caller is paid for archiving only when `incentivized`
+        fn archive_unchecked(
+            &mut self,
+            order: FusionOrder,
+            settled_at: Timestamp,
+            incentivized: bool,
+        ) -> Result<(), Error> {
+            let order_hash = order.order_hash;
+            let record_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&order.encode());
+            self.archived_orders.insert(order_hash, &ArchivedOrder {
+                record_hash,
+                status: order.status.clone(),
+                src_amount: order.src_amount,
+                settled_at,
+            });
+            self.orders.remove(order_hash);
+            self.order_states.remove(order_hash);
+            self.unindex_order(&order.maker, &order.status, order_hash);
+            self.escrow_addresses.remove(order_hash);
+            for index in 0..self.fill_receipt_counts.take(order_hash).unwrap_or(0) {
+                self.fill_receipts.remove((order_hash, index));
+            }
+            self.risk_flags.remove(order_hash);
+
+            // Return the storage deposit to whoever paid it, less the keeper's bounty
+            if let Some(deposit) = self.storage_deposits.take(order_hash) {
+                let keeper = self.env().caller();
+                let bounty = if !incentivized || keeper == deposit.payer {
+                    0
+                } else {
+                    self.keeper_bounty(keeper, deposit.amount)
+                };
+                if bounty > 0 {
+                    self.queue_payout(None, keeper, bounty)?;
+                    self.env().emit_event(KeeperPaid {
+                        keeper,
+                        order_hash,
+                        action: OrderAction::Archive,
+                        amount: bounty,
+                    });
+                }
+                let refund = deposit.amount.checked_sub(bounty).ok_or(Error::ArithmeticOverflow)?;
+                self.queue_payout(None, deposit.payer, refund)?;
+                self.adjust_ledger(None, LedgerEntry::Deposits, deposit.amount, false);
+            }
+
+            // Archival is the order's last action: tip the caller, refund the reserve if unclaimed
+            if incentivized && self.env().caller() != order.maker {
+                self.pay_executor_tip(order_hash, self.env().caller(), OrderAction::Archive)?;
+            }
+            if let Some(tip) = self.executor_tips.take(order_hash) {
+                if tip.remaining > 0 {
+                    self.queue_payout(None, order.maker, tip.remaining)?;
+                    self.adjust_ledger(None, LedgerEntry::Tips, tip.remaining, false);
+                }
+            }
+
+            self.env().emit_event(OrderArchived {
+                order_hash,
+                record_hash,
+                status: order.status,
+                src_amount: order.src_amount,
+                filled_amount: order.filled_amount,
+            });
+
+            self.debug_assert_reconciled();
+            Ok(())
+        }
+
+        /// Split a pending order into `parts` independently fillable child orders with
+        /// proportional amounts (remainder goes to the last child)
+        #[ink(message)]
+        pub fn split_order(&mut self, order_hash: [u8; 32], parts: u32) -> Result<Vec<[u8; 32]>, Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+
+            let mut parent = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            if self.env().caller() != parent.maker {
+                return Err(Error::OnlyMaker);
+            }
+            if parent.status != OrderStatus::Pending {
+                return Err(Error::InvalidOrderStatus);
+            }
+            if !parent.basket.is_empty() {
+                return Err(Error::IndivisibleBasket);
+            }
+            if !(2..=MAX_SPLIT_PARTS).contains(&parts) {
+                return Err(Error::InvalidAmount);
+            }
+
+            let n = parts as u128;
+            let src_part = parent.src_amount / n;
+            let fee_part = parent.resolver_fee / n;
+            let dst_part = parent.dst_amount.div_small(parts);
+            if src_part == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            let current_time = self.env().block_timestamp();
+            let mut children = Vec::new();
+            for index in 0..parts {
+                let last = index == parts - 1;
+                let rest = (parts - 1) as u128;
+                let (src_amount, resolver_fee, dst_amount) = if last {
+                    (
+                        parent.src_amount.checked_sub(src_part * rest).ok_or(Error::ArithmeticOverflow)?,
+                        parent.resolver_fee.checked_sub(fee_part * rest).ok_or(Error::ArithmeticOverflow)?,
+                        parent.dst_amount
+                            .checked_sub(dst_part.checked_mul_small(parts - 1).ok_or(Error::ArithmeticOverflow)?)
+                            .ok_or(Error::ArithmeticOverflow)?,
+                    )
+                } else {
+                    (src_part, fee_part, dst_part)
+                };
+
+                let child_hash = self.hash_preimage(&HashPreimage::Split {
+                    parent_order: order_hash,
+                    index,
+                });
+                if self.orders.contains(child_hash) {
+                    return Err(Error::OrderAlreadyExists);
+                }
+
+                let auction = match &parent.auction {
+                    Some(auction) => Some(auction.share(parts, last).ok_or(Error::ArithmeticOverflow)?),
+                    None => None,
+                };
+                let child = FusionOrder {
+                    order_hash: child_hash,
+                    src_amount,
+                    dst_amount,
+                    auction,
+                    resolver_fee,
+                    created_at: current_time,
+                    parent_order: Some(order_hash),
+                    nonce: index as u64,
+                    ..parent.clone()
+                };
+                self.save_order(&child);
+                children.push(child_hash);
+
+                self.emit_order_created(OrderCreatedV2 {
+                    order_hash: child_hash,
+                    maker: child.maker,
+                    src_token: child.src_token,
+                    direction: child.direction,
+                    src_amount,
+                    dst_amount,
+                    fill_deadline: child.time_locks.fill_deadline,
+                    metadata: child.metadata,
+                    recipient: child.recipient,
+                    encrypted_memo: child.encrypted_memo,
+                    preferred_resolver: child.preferred_resolver,
+                    chain_id: self.ethereum_chain_id,
+                    hash_algo: child.hash_lock_info.hash_algo,
+                });
+            }
+
+            // Any underwriting was for the whole order; hand the collateral back
+            self.settle_underwriting(order_hash, parent.maker, false, 0)?;
+
+            parent.status = OrderStatus::Split;
+            self.save_order_state(&parent);
+
+            self.env().emit_event(OrderSplit {
+                parent_order: order_hash,
+                maker: parent.maker,
+                children: children.clone(),
+            });
+
+            Ok(children)
+        }
+
+        /// Approved resolver reserves a pending order for its hash lock for `commitment_ttl`; after
+        /// its own commitment lapses it waits another `commitment_ttl` before committing again
+        #[ink(message)]
+        pub fn commit_hash_lock(&mut self, order_hash: [u8; 32], hash_lock: [u8; 32]) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+
+            let resolver = self.resolver_identity(self.env().caller());
+            if !self.is_resolver_approved(resolver) {
+                return Err(Error::OnlyResolver);
+            }
+            let state = self.order_states.get(order_hash).ok_or(Error::OrderNotFound)?;
+            if state.status != OrderStatus::Pending {
+                return Err(Error::InvalidOrderStatus);
+            }
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            self.ensure_preferred_resolver(&order, resolver)?;
+            self.ensure_hash_lock_unused(hash_lock)?;
+
+            // A live commitment cannot be replaced or extended, not even by its owner
+            let current_time = self.env().block_timestamp();
+            if self.live_commitment(order_hash, current_time).is_some() {
+                return Err(Error::HashLockCommitted);
+            }
+            if let Some(lapsed) = self.hash_lock_commitments.get(order_hash) {
+                let cooldown_ends = lapsed.expires_at.saturating_add(self.commitment_ttl);
+                if lapsed.resolver == resolver && current_time < cooldown_ends {
+                    return Err(Error::CommitmentCooldown);
+                }
+            }
+
+            let expires_at = current_time.checked_add(self.commitment_ttl).ok_or(Error::ArithmeticOverflow)?;
+            self.hash_lock_commitments.insert(order_hash, &HashLockCommitment {
+                resolver,
+                hash_lock,
+                expires_at,
+            });
+
+            self.env().emit_event(HashLockCommitted {
+                order_hash,
+                resolver,
+                hash_lock,
+                expires_at,
+            });
+
+            Ok(())
+        }
+
+        /// Resolver of a locked order accepts `underwriter` for at most `max_fee_share_bps` of its
+        /// fee; replaces any earlier approval
+        #[ink(message)]
+        pub fn approve_underwriter(
+            &mut self,
+            order_hash: [u8; 32],
+            underwriter: AccountId,
+            max_fee_share_bps: u32,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            let resolver = self.env().caller();
+            if order.resolver != Some(resolver) {
+                return Err(Error::Unauthorized);
+            }
+            if !matches!(order.status, OrderStatus::Locked | OrderStatus::PartialFill) {
+                return Err(Error::InvalidOrderStatus);
+            }
+            if self.underwritings.contains(order_hash) {
+                return Err(Error::AlreadyUnderwritten);
+            }
+            if max_fee_share_bps > 10_000 {
+                return Err(Error::InvalidAmount);
+            }
+
+            self.underwriter_approvals.insert(order_hash, &UnderwriterApproval { underwriter, max_fee_share_bps });
+            self.env().emit_event(UnderwriterApproved {
+                order_hash,
+                resolver,
+                underwriter,
+                max_fee_share_bps,
+            });
+            Ok(())
+        }
+
+        /// Post collateral backing the resolver of a locked order, for a share of its fee; the
+        /// resolver must have approved the caller at a share no lower than the current one
+        #[ink(message, payable)]
+        pub fn underwrite(&mut self, order_hash: [u8; 32]) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_not_paused()?;
+
+            let underwriter = self.env().caller();
+            let collateral = self.env().transferred_value();
+            if collateral == 0 {
+                return Err(Error::InvalidAmount);
+            }
+
+            let state = self.order_states.get(order_hash).ok_or(Error::OrderNotFound)?;
+            if !matches!(state.status, OrderStatus::Locked | OrderStatus::PartialFill) {
+                return Err(Error::InvalidOrderStatus);
+            }
+            if self.underwritings.contains(order_hash) {
+                return Err(Error::AlreadyUnderwritten);
+            }
+
+            let fee_share_bps = self.underwriter_fee_share_bps;
+            match self.underwriter_approvals.get(order_hash) {
+                Some(approval) if approval.underwriter == underwriter && fee_share_bps <= approval.max_fee_share_bps => {}
+                _ => return Err(Error::UnderwritingNotApproved),
+            }
+            self.underwriter_approvals.remove(order_hash);
+            self.underwritings.insert(order_hash, &Underwriting {
+                underwriter,
+                collateral,
+                fee_share_bps,
+            });
+            self.adjust_ledger(None, LedgerEntry::Deposits, collateral, true);
+
+            self.env().emit_event(OrderUnderwritten {
+                order_hash,
+                underwriter,
+                collateral,
+                fee_share_bps,
+            });
+
+            Ok(())
+        }
+
+        /// Arbitrary calls (resolver compatibility)
+        #[ink(message)]
+        pub fn arbitrary_calls(
+            &mut self,
+            targets: Vec<AccountId>,
+            arguments: Vec<Vec<u8>>,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            
+            if targets.len() != arguments.len() {
+                return Err(Error::LengthMismatch);
+            }
+
+            for (target, args) in targets.iter().zip(arguments.iter()) {
+                // Execute cross-contract call (simplified)
+                let _result = self.execute_arbitrary_call(*target, args);
+                // Continue even if one call fails
+            }
+
+            Ok(())
+        }
+
+        // --- View Functions ---
+
+        /// Dry run of `execute_swap`/`withdraw` (`amount` = remaining) or `execute_partial_fill`
+        /// (`amount` < remaining, or an already partially filled order): `Ok` or the error the
+        /// call would fail with; a full execution is `Ok` only if both messages would pass.
+        /// `secret_hash` is the hash of the secret under the order's `hash_algo`, so the secret
+        /// stays private.
+        #[ink(message)]
+        pub fn check_executable(
+            &self,
+            order_hash: [u8; 32],
+            secret_hash: [u8; 32],
+            amount: Balance,
+        ) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            let remaining = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
+
+            let fill = if amount < remaining || order.status == OrderStatus::PartialFill {
+                Some(amount)
+            } else {
+                // withdraw needs the escrow record from deploy_src on top of what execute_swap checks
+                if !self.escrow_addresses.contains(order_hash) {
+                    return Err(Error::EscrowNotFound);
+                }
+                if amount > remaining {
+                    return Err(Error::InvalidAmount);
+                }
+                None
+            };
+            let caller = self.resolver_identity(self.env().caller());
+            self.check_fill(order_hash, fill, caller, self.env().block_timestamp(), |order| {
+                self.check_hash_lock(order, &secret_hash)
+            })?;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_order(&self, order_hash: [u8; 32]) -> Option<FusionOrder> {
+            self.load_order(order_hash)
+        }
+
+        /// Destination amount a pending order asks for right now (its auction price, or the
+        /// fixed `dst_amount`); locked and settled orders report the price they were taken at
+        #[ink(message)]
+        pub fn get_current_dst_amount(&self, order_hash: [u8; 32]) -> Option<U256> {
+            let order = self.load_order(order_hash)?;
+            if order.status != OrderStatus::Pending {
+                return Some(order.taken_dst_amount.unwrap_or(order.dst_amount));
+            }
+            Self::current_dst_amount(&order, self.env().block_timestamp()).ok()
+        }
+
+        /// Countdown data and currently allowed actions, mirroring the message guards
+        #[ink(message)]
+        pub fn get_order_timeline(&self, order_hash: [u8; 32]) -> Option<OrderTimeline> {
+            let order = self.load_order(order_hash)?;
+            let state = self.order_states.get(order_hash)?;
+            let current_time = self.env().block_timestamp();
+            let locked = order.hash_lock_info.hash_lock != HashLockState::Unset;
+            let (execution_cutoff, public_cancellation_start) = if locked {
+                (order.time_locks.src_cancellation.saturating_sub(1), order.time_locks.src_public_cancellation)
+            } else {
+                let cutoff = self.execution_cutoff(&order);
+                (cutoff, cutoff.saturating_add(1))
+            };
+            let src_stage = matches!(order.status, OrderStatus::Locked | OrderStatus::PartialFill)
+                .then(|| order.time_locks.src_stage(current_time));
+            let archivable_at = state.settled_at.map(|at| at.saturating_add(self.archive_retention));
+
+            let mut allowed_actions = Vec::new();
+            match order.status {
+                _ if order.status.is_terminal() => {
+                    if archivable_at.is_some_and(|at| current_time >= at) {
+                        allowed_actions.push((OrderAction::Archive, ActionActor::Anyone));
+                    }
+                }
+                _ if self.paused => {}
+                OrderStatus::Pending => {
+                    let deployer = match (self.live_commitment(order_hash, current_time), order.preferred_resolver) {
+                        (Some(commitment), _) => ActionActor::Resolver(commitment.resolver),
+                        (None, Some(preferred)) if current_time < order.preferred_until => ActionActor::Resolver(preferred),
+                        (None, _) => ActionActor::AnyResolver,
+                    };
+                    // Locking now must still leave a withdrawal stage before the execution cutoff
+                    if self.lock_time_locks(&order, current_time).is_ok() {
+                        allowed_actions.push((OrderAction::DeployEscrow, deployer));
+                    }
+                    if current_time <= order.time_locks.private_cancellation {
+                        allowed_actions.push((OrderAction::CancelOrder, ActionActor::Maker));
+                    }
+                }
+                _ => {
+                    let resolver = ActionActor::Resolver(order.resolver.unwrap_or(order.maker));
+                    match order.time_locks.src_stage(current_time) {
+                        SrcStage::FinalityLock => {}
+                        SrcStage::PrivateWithdrawal => allowed_actions.push((OrderAction::Execute, resolver)),
+                        SrcStage::PublicWithdrawal => {
+                            let executor = match self.execution_commitments.get(order_hash) {
+                                Some(commitment) if current_time < commitment.exclusive_until => {
+                                    ActionActor::Resolver(commitment.executor)
+                                }
+                                _ => ActionActor::SecretHolder,
+                            };
+                            allowed_actions.push((OrderAction::Execute, executor));
+                        }
+                        SrcStage::PrivateCancellation => allowed_actions.push((OrderAction::CancelOrder, resolver)),
+                        SrcStage::PublicCancellation => allowed_actions.push((OrderAction::CancelOrder, ActionActor::Anyone)),
+                    }
+                }
+            }
+
+            Some(OrderTimeline {
+                status: order.status,
+                current_time,
+                created_at: order.created_at,
+                private_cancellation_end: order.time_locks.private_cancellation,
+                fill_deadline: order.time_locks.fill_deadline,
+                execution_cutoff,
+                public_cancellation_start,
+                src_stage,
+                settled_at: state.settled_at,
+                archivable_at,
+                allowed_actions,
+            })
+        }
+
+        /// Coarse risk score of a live order from its resolver's reputation and stake, deposit
+        /// ratio and remaining execution time; `None` once the order is settled
+        #[ink(message)]
+        pub fn get_order_risk(&self, order_hash: [u8; 32]) -> Option<OrderRisk> {
+            let order = self.load_order(order_hash)?;
+            if order.status.is_terminal() {
+                return None;
+            }
+            let denominator = math::BPS_DENOMINATOR;
+            let shortfall_risk = |value: u64, reference: u64| {
+                math::lerp(denominator, 0, value, reference).unwrap_or(denominator) as u32
+            };
+
+            let resolver_reputation = order.resolver.and_then(|resolver| {
+                let value = self.peek_remote(&RemoteKey::Reputation(resolver))?;
+                Some(u32::try_from(value).unwrap_or(u32::MAX))
+            });
+            let reputation_risk_bps = shortfall_risk(
+                resolver_reputation.unwrap_or(0) as u64,
+                RISK_REFERENCE_REPUTATION as u64,
+            );
+
+            let deposit_ratio_bps = math::mul_div(order.safety_deposit, denominator, order.src_amount)
+                .unwrap_or(0)
+                .min(u32::MAX as u128) as u32;
+            let deposit_risk_bps = shortfall_risk(deposit_ratio_bps as u64, RISK_REFERENCE_DEPOSIT_BPS as u64);
+
+            // Locked orders run to their stamped cutoff, pending ones to the configured one
+            let execution_cutoff = match order.hash_lock_info.hash_lock {
+                HashLockState::Unset => self.execution_cutoff(&order),
+                _ => order.time_locks.src_cancellation.saturating_sub(1),
+            };
+            let time_remaining = execution_cutoff.saturating_sub(self.env().block_timestamp());
+            let time_risk_bps = shortfall_risk(time_remaining, execution_cutoff.saturating_sub(order.created_at));
+
+            let stake = order.resolver.map_or(0, |resolver| self.resolver_stakes.get(resolver).unwrap_or(0));
+            let covered_bps = math::mul_div(stake.min(order.src_amount), denominator, order.src_amount).unwrap_or(0);
+            let size_risk_bps = denominator.saturating_sub(covered_bps) as u32;
+
+            let score_bps = (reputation_risk_bps + deposit_risk_bps + time_risk_bps + size_risk_bps) / 4;
+            let level = match score_bps {
+                score if score < 3_334 => RiskLevel::Low,
+                score if score < 6_667 => RiskLevel::Medium,
+                _ => RiskLevel::High,
+            };
+            Some(OrderRisk {
+                level,
+                score_bps,
+                resolver_reputation,
+                reputation_risk_bps,
+                deposit_ratio_bps,
+                deposit_risk_bps,
+                time_remaining,
+                time_risk_bps,
+                size_risk_bps,
+            })
+        }
+
+        /// Everything the contract owes in `asset` (`None` = native): escrow, deposits, fees, tips
+        #[ink(message)]
+        pub fn tvl(&self, asset: Option<AccountId>) -> Balance {
+            self.get_asset_ledger(asset).total()
+        }
+
+        #[ink(message)]
+        pub fn get_asset_ledger(&self, asset: Option<AccountId>) -> AssetLedger {
+            self.asset_ledgers.get(asset).unwrap_or_default()
+        }
+
+        /// Compare an asset's ledger with the contract's actual balance of it
+        #[ink(message)]
+        pub fn reconcile(&self, asset: Option<AccountId>) -> Result<AssetReconciliation, Error> {
+            let held = match asset {
+                Some(token) => psp22::balance_of(token, self.env().account_id(), self.gas_budgets.token)
+                    .map_err(|_| Error::TransferFailed)?,
+                None => self.env().balance(),
+            };
+            let ledger = self.get_asset_ledger(asset);
+            let owed = ledger.total();
+            Ok(AssetReconciliation {
+                asset,
+                ledger,
+                held,
+                surplus: held.saturating_sub(owed),
+                shortfall: owed.saturating_sub(held),
+            })
+        }
+
+        /// Protocol fees in `token` (None = native) awaiting `withdraw_fees`
+        #[ink(message)]
+        pub fn get_accrued_fees(&self, token: Option<AccountId>) -> Balance {
+            self.fee_vault.get(token).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_fee_recipients(&self) -> Vec<FeeRecipient> {
+            self.fee_recipients.clone()
+        }
+
+        /// Fees in `token` awaiting `claim_fee_share` by `recipient`
+        #[ink(message)]
+        pub fn get_accrued_fee_share(&self, recipient: AccountId, token: Option<AccountId>) -> Balance {
+            self.accrued_fee_shares.get((recipient, token)).unwrap_or(0)
+        }
+
+        /// Number of non-terminal orders in the open-order index
+        #[ink(message)]
+        pub fn get_open_order_count(&self) -> u32 {
+            self.open_order_count.get().unwrap_or(0)
+        }
+
+        /// Number of unarchived orders created by `maker`
+        #[ink(message)]
+        pub fn get_maker_order_count(&self, maker: AccountId) -> u32 {
+            self.maker_order_counts.get(maker).unwrap_or(0)
+        }
+
+        /// Page of `maker`'s unarchived orders; order within the list is not stable across removals
+        #[ink(message)]
+        pub fn get_orders_by_maker(&self, maker: AccountId, offset: u32, limit: u32) -> Vec<FusionOrder> {
+            self.index_page(&self.maker_orders, &self.maker_order_counts, &maker, offset, limit)
+        }
+
+        /// Number of unarchived orders currently in `status`
+        #[ink(message)]
+        pub fn get_order_count_by_status(&self, status: OrderStatus) -> u32 {
+            self.status_order_counts.get(&status).unwrap_or(0)
+        }
+
+        /// Page of unarchived orders currently in `status`; order within the list is not stable
+        #[ink(message)]
+        pub fn get_orders_by_status(&self, status: OrderStatus, offset: u32, limit: u32) -> Vec<FusionOrder> {
+            self.index_page(&self.status_orders, &self.status_order_counts, &status, offset, limit)
+        }
+
+        /// Page of live orders in open-index order, with their storage deposits, executor tips
+        /// and underwriting, for migration snapshots. `limit` is capped at `MAX_PAGE_LEN`; page
+        /// through `get_open_order_count` within one block.
+        #[ink(message)]
+        pub fn export_orders(&self, offset: u32, limit: u32) -> Vec<ExportedOrder> {
+            self.open_order_page(offset, limit)
+                .filter_map(|order_hash| {
+                    Some(ExportedOrder {
+                        order: self.load_order(order_hash)?,
+                        storage_deposit: self.storage_deposits.get(order_hash),
+                        executor_tip: self.executor_tips.get(order_hash),
+                        underwriting: self.underwritings.get(order_hash),
+                    })
+                })
+                .collect()
+        }
+
+        /// Pending, unexpired orders selling `src_token` for `dst_token` among one page of the
+        /// open-order index (`limit` capped at `MAX_PAGE_LEN`), aggregated by implied price
+        /// (cheapest ask first). Prices are floored to a multiple of `tick` (1e18-scaled, 0 keeps
+        /// exact prices). At most `levels` levels, capped at `MAX_DEPTH_LEVELS`; levels of equal
+        /// price merge across pages.
+        #[ink(message)]
+        pub fn get_depth(
+            &self,
+            src_token: AccountId,
+            dst_token: [u8; 20],
+            levels: u32,
+            tick: u128,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<DepthLevel> {
+            let current_time = self.env().block_timestamp();
+            let mut depth: Vec<DepthLevel> = Vec::new();
+            for order_hash in self.open_order_page(offset, limit) {
+                let Some(order) = self.load_order(order_hash) else {
+                    continue;
+                };
+                if order.status != OrderStatus::Pending
+                    || order.src_token != src_token
+                    || order.dst_token != dst_token
+                    || current_time > order.time_locks.fill_deadline
+                {
+                    continue;
+                }
+                // Orders whose amounts do not fit the price arithmetic are left out
+                let Ok(dst_amount) = Self::current_dst_amount(&order, current_time) else {
+                    continue;
+                };
+                let price = self
+                    .src_to_dst_units(src_token, order.src_amount, dst_token)
+                    .ok()
+                    .and_then(|normalized| normalized.checked_to_u128())
+                    .zip(dst_amount.checked_to_u128())
+                    .and_then(|(normalized, dst)| math::Fixed::from_ratio(dst, normalized));
+                let Some(math::Fixed(price)) = price else {
+                    continue;
+                };
+                let price = price - price % tick.max(1);
+
+                match depth.binary_search_by_key(&price, |level| level.price) {
+                    Ok(index) => {
+                        let level = &mut depth[index];
+                        level.src_amount = level.src_amount.saturating_add(order.src_amount);
+                        level.dst_amount = level.dst_amount.checked_add(dst_amount).unwrap_or(U256::MAX);
+                        level.order_count += 1;
+                    }
+                    Err(index) => depth.insert(index, DepthLevel {
+                        price,
+                        src_amount: order.src_amount,
+                        dst_amount,
+                        order_count: 1,
+                    }),
+                }
+            }
+            depth.truncate(levels.min(MAX_DEPTH_LEVELS) as usize);
+            depth
+        }
+
+        /// Blake2x256 of the SCALE-encoded list of `blake2x256(exported.encode())`, one per entry
+        /// of the same `export_orders` page; recompute from the exported page to verify it
+        #[ink(message)]
+        pub fn state_root(&self, offset: u32, limit: u32) -> [u8; 32] {
+            let leaves: Vec<[u8; 32]> = self
+                .export_orders(offset, limit)
+                .into_iter()
+                .map(|exported| self.env().hash_bytes::<ink::env::hash::Blake2x256>(&exported.encode()))
+                .collect();
+            self.env().hash_bytes::<ink::env::hash::Blake2x256>(&leaves.encode())
+        }
+
+        /// Migrate live orders exported from a previous deployment (owner only, until
+        /// `finalize_import` or the first `create_order`). The call carries exactly the batch's
+        /// native obligations, including storage deposits, unspent tip budgets and underwriting
+        /// collateral; PSP22 legs and deposits must already be held. Resolver-compatible escrow
+        /// addresses are not carried over.
+        #[ink(message, payable)]
+        pub fn import_orders(&mut self, orders: Vec<ExportedOrder>) -> Result<u32, Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.import_finalized {
+                return Err(Error::ImportClosed);
+            }
+            if orders.len() > MAX_PAGE_LEN as usize {
+                return Err(Error::InvalidLength);
+            }
+
+            let mut required: Balance = 0;
+            for exported in orders.iter() {
+                let order = &exported.order;
+                let native_deposit = match order.safety_deposit_token {
+                    None => order.safety_deposit.saturating_sub(order.deposit_released),
+                    Some(_) => 0,
+                };
+                for amount in [
+                    order.src_amount,
+                    native_deposit,
+                    exported.storage_deposit.as_ref().map_or(0, |deposit| deposit.amount),
+                    exported.executor_tip.as_ref().map_or(0, |tip| tip.remaining),
+                    exported.underwriting.as_ref().map_or(0, |underwriting| underwriting.collateral),
+                ] {
+                    required = required.checked_add(amount).ok_or(Error::ArithmeticOverflow)?;
+                }
+            }
+            let provided = self.env().transferred_value();
+            if provided < required {
+                return Err(Error::InsufficientFundsV2 { required, provided });
+            }
+            // Anything above the obligations would sit untracked in the contract
+            if provided > required {
+                return Err(Error::InvalidAmount);
+            }
+
+            let first = self.get_open_order_count();
+            let mut tokens: Vec<AccountId> = Vec::new();
+            for exported in orders.iter() {
+                self.import_order(exported, &mut tokens)?;
+            }
+            for token in tokens {
+                if self.reconcile(Some(token))?.shortfall > 0 {
+                    return Err(Error::InsufficientFunds);
+                }
+            }
+
+            let count = orders.len() as u32;
+            self.imported_orders = self.imported_orders.checked_add(count).ok_or(Error::ArithmeticOverflow)?;
+            self.env().emit_event(OrdersImported {
+                count,
+                total_imported: self.imported_orders,
+                state_root: self.state_root(first, count),
+            });
+
+            self.debug_assert_reconciled();
+            Ok(count)
+        }
+
+        /// Close `import_orders` permanently (owner only)
+        #[ink(message)]
+        pub fn finalize_import(&mut self) -> Result<(), Error> {
+            self.ensure_migrated()?;
+            self.ensure_owner()?;
+            if self.import_finalized {
+                return Err(Error::ImportClosed);
+            }
+            self.import_finalized = true;
+            self.env().emit_event(ImportFinalized { total_imported: self.imported_orders });
+            Ok(())
+        }
+
+        /// Whether `import_orders` is still accepted
+        #[ink(message)]
+        pub fn is_import_open(&self) -> bool {
+            !self.import_finalized
+        }
+
+        /// Storage deposit held for an order, if any
+        #[ink(message)]
+        pub fn get_storage_cost(&self, order_hash: [u8; 32]) -> Option<StorageDeposit> {
+            self.storage_deposits.get(order_hash)
+        }
+
+        /// Executor tip budget left on an order
+        #[ink(message)]
+        pub fn get_executor_tip(&self, order_hash: [u8; 32]) -> Option<ExecutorTip> {
+            self.executor_tips.get(order_hash)
+        }
+
+        /// Storage deposit `create_order` will require on top of `src_amount`
+        #[ink(message)]
+        pub fn quote_storage_cost(&self, metadata_len: u32) -> Balance {
+            self.storage_deposit_per_byte
+                .saturating_mul(ORDER_STORAGE_BASE_BYTES.saturating_add(metadata_len) as Balance)
+        }
+
+        /// Live pre-commitment on an order, if any
+        #[ink(message)]
+        pub fn get_hash_lock_commitment(&self, order_hash: [u8; 32]) -> Option<HashLockCommitment> {
+            self.live_commitment(order_hash, self.env().block_timestamp())
+        }
+
+        #[ink(message)]
+        pub fn get_execution_commitment(&self, order_hash: [u8; 32]) -> Option<ExecutionCommitment> {
+            self.execution_commitments.get(order_hash)
+        }
+
+        #[ink(message)]
+        pub fn get_underwriting(&self, order_hash: [u8; 32]) -> Option<Underwriting> {
+            self.underwritings.get(order_hash)
+        }
+
+        #[ink(message)]
+        pub fn get_archived_order(&self, order_hash: [u8; 32]) -> Option<ArchivedOrder> {
+            self.archived_orders.get(order_hash)
+        }
+
+        /// Receipts for every fill of an order, oldest first
+        #[ink(message)]
+        pub fn get_fill_receipts(&self, order_hash: [u8; 32]) -> Vec<FillReceipt> {
+            (0..self.fill_receipt_counts.get(order_hash).unwrap_or(0))
+                .filter_map(|index| self.fill_receipts.get((order_hash, index)))
+                .collect()
+        }
+
+        /// Exact preimage fields of an order hash, for cross-chain re-derivation
+        #[ink(message)]
+        pub fn get_hash_preimage(&self, order_hash: [u8; 32]) -> Option<HashPreimage> {
+            let order = self.load_order(order_hash)?;
+            Some(match order.parent_order {
+                Some(parent_order) => HashPreimage::Split {
+                    parent_order,
+                    index: order.nonce as u32,
+                },
+                None => HashPreimage::Root(OrderHashPreimage {
+                    maker: order.maker,
+                    src_token: order.src_token,
+                    dst_token: order.dst_token,
+                    src_amount: order.src_amount,
+                    min_dst_amount: order.dst_amount,
+                    fill_deadline: order.time_locks.fill_deadline,
+                    nonce: order.nonce,
+                    created_at: order.created_at,
+                    basket: order.basket,
+                }),
+            })
+        }
+
+        #[ink(message)]
+        pub fn get_order_by_hash_lock(&self, hash_lock: [u8; 32]) -> Option<[u8; 32]> {
+            self.active_hash_locks.get(hash_lock)
+        }
+
+        /// Order whose settlement retired `hash_lock`, if it has been used
+        #[ink(message)]
+        pub fn get_hash_lock_consumer(&self, hash_lock: [u8; 32]) -> Option<[u8; 32]> {
+            self.consumed_hash_locks.get(hash_lock)
+        }
+
+        #[ink(message)]
+        pub fn get_escrow_address(&self, order_hash: [u8; 32]) -> Option<AccountId> {
+            self.escrow_addresses.get(order_hash)
+        }
+
+        /// CREATE2 salt for `immutables`, identical to `ImmutablesLib.hash` on Ethereum
+        #[ink(message)]
+        pub fn get_escrow_salt(&self, immutables: EscrowImmutables) -> [u8; 32] {
+            create2::escrow_salt(&immutables.create2())
+        }
+
+        /// Address the Ethereum factory deploys the escrow for `immutables` at
+        #[ink(message)]
+        pub fn compute_ethereum_escrow_address(
+            &self,
+            immutables: EscrowImmutables,
+            factory: [u8; 20],
+            bytecode_hash: [u8; 32],
+        ) -> [u8; 20] {
+            create2::ethereum_escrow_address(&factory, &bytecode_hash, &create2::escrow_salt(&immutables.create2()))
+        }
+
+        #[ink(message)]
+        pub fn get_escrow_immutables(&self, order_hash: [u8; 32]) -> Option<EscrowImmutables> {
+            let order = self.load_order(order_hash)?;
+            Self::escrow_immutables(order_hash, &order)
+        }
+
+        /// Owner-approved, or self-registered with at least `min_resolver_stake` bonded,
+        /// unless revoked
+        #[ink(message)]
+        pub fn is_resolver_approved(&self, resolver: AccountId) -> bool {
+            match self.approved_resolvers.get(resolver) {
+                Some(approved) => approved,
+                None => {
+                    self.min_resolver_stake > 0
+                        && self.resolver_stakes.get(resolver).unwrap_or(0) >= self.min_resolver_stake
+                }
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_keeper_config(&self) -> KeeperConfig {
+            self.keeper_config.clone()
+        }
+
+        #[ink(message)]
+        pub fn is_keeper(&self, account: AccountId) -> bool {
+            self.keepers.get(account).unwrap_or(false)
+        }
+
+        /// Hot key currently operating for `resolver`
+        #[ink(message)]
+        pub fn get_operator(&self, resolver: AccountId) -> Option<AccountId> {
+            self.resolver_operators.get(resolver)
+        }
+
+        /// Hot key `resolver` proposed that has not accepted yet
+        #[ink(message)]
+        pub fn get_proposed_operator(&self, resolver: AccountId) -> Option<AccountId> {
+            self.proposed_operators.get(resolver)
+        }
+
+        #[ink(message)]
+        pub fn is_resolver_blocked(&self, maker: AccountId, resolver: AccountId) -> bool {
+            self.maker_blocked_resolvers.contains((maker, resolver))
+        }
+
+        /// Relayer reports filed against an order through `relayer_initiated_refund`
+        #[ink(message)]
+        pub fn get_refund_evidence(&self, order_hash: [u8; 32]) -> Vec<RefundEvidence> {
+            self.refund_evidence.get(order_hash).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn is_trusted_relayer(&self, relayer: AccountId) -> bool {
+            self.trusted_relayers.get(relayer).unwrap_or(false)
+        }
+
+        #[ink(message)]
+        pub fn is_fee_exempt(&self, account: AccountId) -> bool {
+            self.fee_exempt.contains(account)
+        }
+
+        #[ink(message)]
+        pub fn get_resolver_stake(&self, resolver: AccountId) -> Balance {
+            self.resolver_stakes.get(resolver).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_resolver_unbonding(&self, resolver: AccountId) -> Option<ResolverUnbonding> {
+            self.resolver_unbondings.get(resolver)
+        }
+
+        /// All runtime parameters in one call
+        #[ink(message)]
+        pub fn get_config(&self) -> EscrowConfig {
+            let (access_token, min_access_token_balance) = self.get_access_token();
+            EscrowConfig {
+                owner: self.owner,
+                paused: self.paused,
+                guardian_count: self.guardian_count,
+                recovery_threshold: self.recovery_threshold,
+                recovery_delay: self.recovery_delay,
+                rescue_delay: self.get_rescue_delay(),
+                maker_fee_bps: self.maker_fee_bps,
+                taker_fee_bps: self.taker_fee_bps,
+                execution_grace_period: self.execution_grace_period,
+                stage_delays: self.stage_delays.clone(),
+                min_order_duration: self.min_order_duration,
+                max_order_duration: self.max_order_duration,
+                commitment_ttl: self.commitment_ttl,
+                min_resolver_stake: self.min_resolver_stake,
+                permissionless_resolvers: self.permissionless_resolvers,
+                resolver_unbonding_period: self.resolver_unbonding_period,
+                execution_exclusivity: self.execution_exclusivity,
+                referral_exclusivity: self.referral_exclusivity,
+                min_safety_deposit: self.min_safety_deposit,
+                deposit_token: self.deposit_token,
+                min_token_safety_deposit: self.min_token_safety_deposit,
+                access_token,
+                min_access_token_balance,
+                coordinator: self.coordinator,
+                remote_cache_ttl: self.remote_cache_ttl,
+                underwriter_fee_share_bps: self.underwriter_fee_share_bps,
+                slash_maker_share_bps: self.slash_maker_share_bps,
+                cancellation_tip_bps: self.cancellation_tip_bps,
+                watchtower_fee: self.watchtower_fee,
+                collateral_ratio_bps: self.collateral_ratio_bps,
+                ethereum_resolver: self.ethereum_resolver,
+                ethereum_chain_id: self.ethereum_chain_id,
+                src_token_list_mode: self.src_token_list_mode.clone(),
+                event_version: self.event_version.clone(),
+                allow_all_dst_tokens: self.allow_all_dst_tokens,
+                archive_retention: self.archive_retention,
+                storage_deposit_per_byte: self.storage_deposit_per_byte,
+                relayer_quorum: self.relayer_quorum,
+                transfer_backend: self.transfer_backend(),
+            }
+        }
+
+        /// Value in open orders per token, for tokens `offset..offset + limit` in first-locked
+        /// order; `limit` is capped at `MAX_PAGE_LEN`
+        #[ink(message)]
+        pub fn get_locked_values(&self, offset: u32, limit: u32) -> Vec<(AccountId, Balance)> {
+            let end = offset.saturating_add(limit.min(MAX_PAGE_LEN)).min(self.locked_token_count.get().unwrap_or(0));
+            (offset..end)
+                .filter_map(|index| self.locked_tokens.get(index))
+                .map(|token| (token, self.locked_value.get(token).unwrap_or(0)))
+                .collect()
+        }
+
+        /// All headline protocol figures in one call; `locked_value` covers the first
+        /// `MAX_PAGE_LEN` tokens, page the rest with `get_locked_values`
+        #[ink(message)]
+        pub fn get_protocol_stats(&self) -> ProtocolStats {
+            ProtocolStats {
+                open_orders: self.get_open_order_count(),
+                locked_value: self.get_locked_values(0, MAX_PAGE_LEN),
+                total_volume: self.total_volume,
+                total_protocol_fees: self.total_protocol_fees,
+                active_resolvers: self.active_resolvers,
+                total_escrows_created: self.total_escrows_created,
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_total_volume(&self) -> Balance {
+            self.total_volume
+        }
+
+        #[ink(message)]
+        pub fn get_total_escrows_created(&self) -> u64 {
+            self.total_escrows_created
+        }
+
+        #[ink(message)]
+        pub fn is_guardian(&self, account: AccountId) -> bool {
+            self.guardians.contains(account)
+        }
+
+        /// (members, threshold, delay)
+        #[ink(message)]
+        pub fn get_recovery_council(&self) -> (Vec<AccountId>, u32, Timestamp) {
+            (self.recovery_council.clone(), self.recovery_threshold, self.recovery_delay)
+        }
+
+        #[ink(message)]
+        pub fn get_pending_recovery(&self) -> Option<OwnerRecovery> {
+            self.pending_recovery.clone()
+        }
+
+        #[ink(message)]
+        pub fn get_rescue_delay(&self) -> Timestamp {
+            self.rescue_delay.get().unwrap_or(DEFAULT_RESCUE_DELAY)
+        }
+
+        #[ink(message)]
+        pub fn get_pending_rescue(&self) -> Option<FundsRescue> {
+            self.pending_rescue.get().flatten()
+        }
+
+        #[ink(message)]
+        pub fn get_pending_upgrade(&self) -> Option<CodeUpgrade> {
+            self.pending_upgrade.get().flatten()
+        }
+
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        #[ink(message)]
+        pub fn get_owner(&self) -> AccountId {
+            self.owner
+        }
+
+        #[ink(message)]
+        pub fn get_execution_grace_period(&self) -> Timestamp {
+            self.execution_grace_period
+        }
+
+        /// Parameters in effect for `direction`: its overrides, the global settings elsewhere
+        #[ink(message)]
+        pub fn get_direction_params(&self, direction: SwapDirection) -> DirectionParams {
+            self.params_for(&direction)
+        }
+
+        #[ink(message)]
+        pub fn get_size_tiers(&self) -> Vec<SizeTier> {
+            self.size_tiers.clone()
+        }
+
+        #[ink(message)]
+        pub fn get_reputation_discounts(&self) -> Vec<ReputationDiscount> {
+            self.reputation_discounts.get().unwrap_or_default()
+        }
+
+        /// Express-lane tier a new order of `src_amount` of `src_token` plus `basket` would get, if
+        /// any
+        #[ink(message)]
+        pub fn get_size_tier(&self, src_token: AccountId, src_amount: Balance, basket: Vec<BasketLeg>) -> Option<SizeTier> {
+            self.size_tier_for(src_token, src_amount, &basket)
+        }
+
+        /// `(min, max)` allowed `fill_deadline - now` for new orders, in ms
+        #[ink(message)]
+        pub fn get_order_duration_bounds(&self) -> (Timestamp, Timestamp) {
+            (self.min_order_duration, self.max_order_duration)
+        }
+
+        /// Source and basket value of the resolver's open (assigned, unsettled) orders, in
+        /// `DEFAULT_SRC_DECIMALS` units
+        #[ink(message)]
+        pub fn get_resolver_exposure(&self, resolver: AccountId) -> Balance {
+            self.resolver_exposure.get(resolver).unwrap_or(0)
+        }
+
+        /// Weight bound for `execute_swap` on this order as currently stored
+        #[ink(message)]
+        pub fn estimate_execute_weight(&self, order_hash: [u8; 32]) -> Option<ExecutionEstimate> {
+            let order = self.load_order(order_hash)?;
+            let mut payout_credits = match order.direction {
+                SwapDirection::PolkadotToEthereum => 1,     // Resolver payout
+                SwapDirection::EthereumToPolkadot => 2,     // Maker payout + resolver fee
+            };
+            if order.late_fee_schedule.is_some() {
+                payout_credits += 1;
+            }
+            if self.underwritings.contains(order_hash) {
+                payout_credits += 2;
+            }
+            // Executor tip, and the unspent rest back to the maker
+            if self.executor_tips.contains(order_hash) {
+                payout_credits += 2;
+            }
+            // Basket legs, and the safety deposit back to the resolver (protocol fees only accrue)
+            payout_credits += order.basket.len() as u32 + 1;
+            Some(self.estimate_weight(payout_credits))
+        }
+
+        /// Weight bound for one `execute_partial_fill` on this order (deposit release included)
+        #[ink(message)]
+        pub fn estimate_partial_fill_weight(&self, order_hash: [u8; 32]) -> Option<ExecutionEstimate> {
+            let order = self.load_order(order_hash)?;
+            // This fill's payouts, then the deposit release
+            let mut payout_credits = match order.direction {
+                SwapDirection::PolkadotToEthereum => 2,
+                SwapDirection::EthereumToPolkadot => 3,
+            };
+            if order.late_fee_schedule.is_some() {
+                payout_credits += 1;
+            }
+            if self.underwritings.contains(order_hash) {
+                payout_credits += 2;
+            }
+            // The last fill hands the unspent executor tip back to the maker
+            if self.executor_tips.contains(order_hash) {
+                payout_credits += 1;
+            }
+            Some(self.estimate_weight(payout_credits))
+        }
+
+        #[ink(message)]
+        pub fn get_gas_budgets(&self) -> GasBudgets {
+            self.gas_budgets.clone()
+        }
+
+        #[ink(message)]
+        pub fn get_coordinator(&self) -> Option<AccountId> {
+            self.coordinator
+        }
+
+        /// Cached coordinator value for `key`, if one is held and still fresh
+        #[ink(message)]
+        pub fn get_cached_remote(&self, key: RemoteKey) -> Option<CachedValue> {
+            self.cached_remote(&key)
+        }
+
+        #[ink(message)]
+        pub fn get_ethereum_resolver(&self) -> [u8; 20] {
+            self.ethereum_resolver
+        }
+
+        /// Interface version, for SDK feature detection
+        #[ink(message)]
+        pub fn get_version(&self) -> (u16, u16, u16) {
+            CONTRACT_VERSION
+        }
+
+        /// Supported features as `capabilities` bitflags
+        #[ink(message)]
+        pub fn get_capabilities(&self) -> u64 {
+            capabilities::PSP22_TOKENS
+                | capabilities::PARTIAL_FILLS
+                | capabilities::HASH_BLAKE2X256
+                | capabilities::HASH_KECCAK256
+                | capabilities::HASH_SHA256
+                | capabilities::CHAIN_ETHEREUM
+                | capabilities::RICH_ERRORS
+        }
+
+        #[ink(message)]
+        pub fn get_deposit_token(&self) -> (Option<AccountId>, Balance) {
+            (self.deposit_token, self.min_token_safety_deposit)
+        }
+
+        /// (token, minimum balance) a resolver must hold to lock orders
+        #[ink(message)]
+        pub fn get_access_token(&self) -> (Option<AccountId>, Balance) {
+            let config = self.access_token.get().unwrap_or_default();
+            (config.token, config.min_balance)
+        }
+
+        #[ink(message)]
+        pub fn get_src_token_list_mode(&self) -> TokenListMode {
+            self.src_token_list_mode.clone()
+        }
+
+        /// Whether `create_order` accepts this source token under the current list mode
+        #[ink(message)]
+        pub fn is_src_token_allowed(&self, token: AccountId) -> bool {
+            match self.src_token_list_mode {
+                TokenListMode::Open => true,
+                TokenListMode::DenyList => !self.denied_src_tokens.contains(token),
+                TokenListMode::AllowList => self.allowed_src_tokens.contains(token),
             }
         }
 
-        // --- Admin Functions ---
+        #[ink(message)]
+        pub fn get_src_token_decimals(&self, token: AccountId) -> u8 {
+            self.src_token_decimals.get(token).unwrap_or(DEFAULT_SRC_DECIMALS)
+        }
 
         #[ink(message)]
-        pub fn set_paused(&mut self, paused: bool) -> Result<(), Error> {
-            self.ensure_owner()?;
-            self.paused = paused;
-            Ok(())
+        pub fn get_dst_token_decimals(&self, token: [u8; 20]) -> u8 {
+            self.dst_tokens.get(token).map(|info| info.decimals).unwrap_or(DEFAULT_DST_DECIMALS)
         }
 
         #[ink(message)]
-        pub fn approve_resolver(&mut self, resolver: AccountId) -> Result<(), Error> {
-            self.ensure_owner()?;
-            self.approved_resolvers.insert(resolver, &true);
-            Ok(())
+        pub fn get_dst_token_info(&self, token: [u8; 20]) -> Option<DstTokenInfo> {
+            self.dst_tokens.get(token)
         }
 
+        /// Whether `create_order` accepts this destination token
         #[ink(message)]
-        pub fn add_trusted_relayer(&mut self, relayer: AccountId) -> Result<(), Error> {
-            self.ensure_owner()?;
-            self.trusted_relayers.insert(relayer, &true);
-            Ok(())
+        pub fn is_dst_token_allowed(&self, token: [u8; 20]) -> bool {
+            self.allow_all_dst_tokens
+                || self.dst_tokens.get(token).map(|info| info.enabled).unwrap_or(false)
         }
 
+        /// Express a source amount in the destination token's base units (decimal scaling only)
         #[ink(message)]
-        pub fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), Error> {
-            self.ensure_owner()?;
-            self.owner = new_owner;
-            Ok(())
+        pub fn normalize_amount(
+            &self,
+            src_token: AccountId,
+            src_amount: Balance,
+            dst_token: [u8; 20],
+        ) -> Option<U256> {
+            self.src_to_dst_units(src_token, src_amount, dst_token).ok()
         }
 
-        // --- Core Order Functions ---
+        // --- Helper Functions ---
 
-        /// Create a new cross-chain fusion order
-        #[ink(message, payable)]
-        pub fn create_order(&mut self, params: CreateOrderParams) -> Result<[u8; 32], Error> {
-            self.ensure_not_paused()?;
-            
+        fn src_to_dst_units(
+            &self,
+            src_token: AccountId,
+            src_amount: Balance,
+            dst_token: [u8; 20],
+        ) -> Result<U256, Error> {
+            math::rescale(src_amount, self.get_src_token_decimals(src_token), self.get_dst_token_decimals(dst_token))
+                .map(U256::from)
+                .ok_or(Error::ArithmeticOverflow)
+        }
+
+        /// Contract makers authorize `digest` via `SignatureValidator`, sr25519 and ECDSA makers
+        /// via the host functions
+        fn verify_maker_signature(
+            &self,
+            order: &FusionOrder,
+            digest: [u8; 32],
+            signature: MakerSignature,
+        ) -> Result<(), Error> {
+            match signature {
+                MakerSignature::Contract(signature) => {
+                    if !self.env().is_contract(&order.maker)
+                        || !signature_validator::is_valid_signature(order.maker, digest, signature, self.gas_budgets.signature_validator)
+                    {
+                        return Err(Error::InvalidSignature);
+                    }
+                    Ok(())
+                }
+                MakerSignature::Sr25519(signature) => {
+                    if !fusion_common::signatures::sr25519_signed(&order.maker, &digest, &signature) {
+                        return Err(Error::InvalidSignature);
+                    }
+                    Ok(())
+                }
+                MakerSignature::Ecdsa(signature) => {
+                    // A contract wallet cannot hold an ECDSA key
+                    if self.env().is_contract(&order.maker)
+                        || !fusion_common::signatures::ecdsa_signed(&order.maker, &digest, &signature)
+                    {
+                        return Err(Error::InvalidSignature);
+                    }
+                    Ok(())
+                }
+            }
+        }
+
+        fn ensure_recovery_council(&self) -> Result<AccountId, Error> {
             let caller = self.env().caller();
-            let current_time = self.env().block_timestamp();
-            let transferred = self.env().transferred_value();
+            if !self.recovery_council.contains(&caller) {
+                return Err(Error::OnlyRecoveryCouncil);
+            }
+            Ok(caller)
+        }
 
-            // Validate timing
-            if params.fill_deadline <= current_time {
-                return Err(Error::DeadlineExpired);
+        fn ensure_owner(&self) -> Result<(), Error> {
+            if self.env().caller() != self.owner {
+                return Err(Error::OnlyOwner);
             }
+            Ok(())
+        }
 
-            // Validate payment
-            if transferred < params.src_amount {
-                return Err(Error::InsufficientFunds);
+        /// Storage must be at `STORAGE_VERSION` before anything but `migrate` (and the upgrade and
+        /// pause controls) writes to it, so a migration never races new entries
+        fn ensure_migrated(&self) -> Result<(), Error> {
+            if self.storage_version < STORAGE_VERSION {
+                return Err(Error::MigrationPending);
             }
+            Ok(())
+        }
 
-            // Calculate private cancellation time (30 minutes grace period)
-            let private_cancellation = current_time
-                .checked_add(30u64.saturating_mul(60).saturating_mul(1000))
-                .ok_or(Error::ArithmeticOverflow)?;
+        fn ensure_not_paused(&self) -> Result<(), Error> {
+            if self.paused {
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
 
-            // Generate order hash (similar to 1inch)
-            let order_data = (
-                &caller,
-                params.src_token,
-                params.dst_token,
-                params.src_amount,
-                params.min_dst_amount,
-                params.fill_deadline,
-                self.order_nonce,
-                current_time,
-            );
-            let encoded = scale::Encode::encode(&order_data);
-            let order_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&encoded);
+        fn calculate_fee(&self, amount: Balance, fee_bps: u32) -> Result<Balance, Error> {
+            math::bps_of(amount, fee_bps).ok_or(Error::ArithmeticOverflow)
+        }
 
-            // Check for duplicate
-            if self.orders.contains(order_hash) {
-                return Err(Error::OrderAlreadyExists);
+        /// Every guard of executing `fill` of an order as `caller` (`None` = the whole order, as
+        /// `execute_swap`; otherwise `execute_partial_fill`), in the order those messages apply
+        /// them, with `secret_matches` standing in for the secret check so `check_executable`
+        /// runs the same guards. Returns the order, the taker paid and the fill's split.
+        fn check_fill(
+            &self,
+            order_hash: [u8; 32],
+            fill: Option<Balance>,
+            caller: AccountId,
+            current_time: Timestamp,
+            secret_matches: impl FnOnce(&FusionOrder) -> Result<(), Error>,
+        ) -> Result<(FusionOrder, AccountId, FillSplit), Error> {
+            self.ensure_not_paused()?;
+
+            // Hot record first: only locked orders execute whole, partially filled ones go on filling
+            let state = self.order_states.get(order_hash).ok_or(Error::OrderNotFound)?;
+            let fillable = match fill {
+                None => state.status == OrderStatus::Locked,
+                Some(_) => matches!(state.status, OrderStatus::Locked | OrderStatus::PartialFill),
+            };
+            if !fillable {
+                return Err(Error::InvalidOrderStatus);
             }
+            self.check_execution_commitment(order_hash, caller, current_time)?;
 
-            // Create order
-            let order = FusionOrder {
-                order_hash,
-                maker: caller,
-                taker: None,
-                src_token: params.src_token,
-                dst_token: params.dst_token,
-                src_amount: params.src_amount,
-                dst_amount: params.min_dst_amount,
-                direction: params.direction.clone(),
-                ethereum_escrow: None,
-                hash_lock_info: HashLockInfo {
-                    hash_lock: [0u8; 32],
-                    secret: None,
-                },
-                time_locks: TimeLocks {
-                    fill_deadline: params.fill_deadline,
-                    private_cancellation,
-                },
-                status: OrderStatus::Pending,
-                filled_amount: 0,
-                safety_deposit: 0,
-                resolver: None,
-                resolver_fee: params.max_resolver_fee,
-                created_at: current_time,
+            let order = self.load_order(order_hash).ok_or(Error::OrderNotFound)?;
+            let resolver = order.resolver.ok_or(Error::OnlyResolver)?;
+            if fill.is_some() && caller != resolver {
+                return Err(Error::OnlyResolver);
+            }
+            self.check_withdrawal_timelock(&order, caller, current_time)?;
+            if fill.is_some() && !order.basket.is_empty() {
+                return Err(Error::IndivisibleBasket);
+            }
+
+            secret_matches(&order)?;
+            if order.direction == SwapDirection::PolkadotToEthereum && order.ethereum_escrow.is_none() {
+                return Err(Error::EthereumEscrowNotSet);
+            }
+
+            let remaining = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
+            let fill_amount = fill.unwrap_or(remaining);
+            if fill_amount == 0 || fill_amount > remaining {
+                return Err(Error::InvalidAmount);
+            }
+            if fill_amount < remaining && self.fill_receipt_counts.get(order_hash).unwrap_or(0) >= MAX_PARTIAL_FILLS {
+                return Err(Error::TooManyFills);
+            }
+
+            let split = self.split_fill(&order, resolver, fill_amount, current_time)?;
+            Ok((order, resolver, split))
+        }
+
+        /// Split the next `fill_amount` of `order`'s source funds (after `order.filled_amount`)
+        /// between fees and payouts. Whole-order fees are taken pro rata to cumulative volume, so
+        /// any sequence of fills settles what a single execution would.
+        fn split_fill(
+            &self,
+            order: &FusionOrder,
+            taker: AccountId,
+            fill_amount: Balance,
+            current_time: Timestamp,
+        ) -> Result<FillSplit, Error> {
+            let filled_before = order.filled_amount;
+            let filled_after = filled_before.checked_add(fill_amount).ok_or(Error::ArithmeticOverflow)?;
+            let src_amount = order.src_amount;
+            // Share of a whole-order amount due once `filled` is executed; the last fill takes
+            // the remainder, and nothing is due before the first
+            let due = |total: Balance, filled: Balance| -> Result<Balance, Error> {
+                if filled >= src_amount {
+                    return Ok(total);
+                }
+                math::mul_div(total, filled, src_amount).ok_or(Error::ArithmeticOverflow)
+            };
+            let pro_rata = |total: Balance| -> Result<Balance, Error> {
+                if filled_before == 0 {
+                    return due(total, filled_after);
+                }
+                due(total, filled_after)?.checked_sub(due(total, filled_before)?).ok_or(Error::ArithmeticOverflow)
             };
 
-            self.orders.insert(order_hash, &order);
-            self.order_nonce = self.order_nonce.checked_add(1).ok_or(Error::ArithmeticOverflow)?;
+            let direction_params = self.params_for(&order.direction);
+            let maker_fee = pro_rata(self.calculate_protocol_fee(order.maker, src_amount, direction_params.maker_fee_bps)?)?;
+            let remaining_after_protocol = fill_amount.checked_sub(maker_fee).ok_or(Error::ArithmeticOverflow)?;
+            let full_resolver_fee = pro_rata(order.resolver_fee)?.min(remaining_after_protocol);
+            let resolver_fee = self.effective_resolver_fee(order, full_resolver_fee, current_time)?;
+            let late_fee_rebate = full_resolver_fee.checked_sub(resolver_fee).ok_or(Error::ArithmeticOverflow)?;
+            let net_amount = remaining_after_protocol.checked_sub(full_resolver_fee).ok_or(Error::ArithmeticOverflow)?;
+
+            // Underwriter's cut comes out of the resolver fee
+            let underwriter_fee = self.underwriter_fee_share(order.order_hash, resolver_fee)?;
+            let resolver_fee = resolver_fee.checked_sub(underwriter_fee).ok_or(Error::ArithmeticOverflow)?;
+
+            // Taker fee comes out of what the resolver is paid, never more than that (`TakerFeeCapped`)
+            let resolver_payout = match order.direction {
+                SwapDirection::PolkadotToEthereum => net_amount.checked_add(resolver_fee).ok_or(Error::ArithmeticOverflow)?,
+                SwapDirection::EthereumToPolkadot => resolver_fee,
+            };
+            let taker_fee = pro_rata(self.calculate_protocol_fee(taker, src_amount, direction_params.taker_fee_bps)?)?;
+            let taker_fee_discount = self.taker_fee_discount(taker, taker_fee)?;
+            let taker_fee_owed = taker_fee.checked_sub(taker_fee_discount).ok_or(Error::ArithmeticOverflow)?;
+            let taker_fee = taker_fee_owed.min(resolver_payout);
+            let resolver_payout = resolver_payout.checked_sub(taker_fee).ok_or(Error::ArithmeticOverflow)?;
+
+            let to_maker = net_amount.checked_add(late_fee_rebate).ok_or(Error::ArithmeticOverflow)?;
+
+            Ok(FillSplit {
+                maker_fee,
+                taker_fee,
+                taker_fee_owed,
+                taker_fee_discount,
+                underwriter_fee,
+                resolver_fee,
+                late_fee_rebate,
+                to_maker,
+                resolver_payout,
+            })
+        }
 
-            self.env().emit_event(OrderCreated {
-                order_hash,
-                maker: caller,
-                direction: params.direction,
-                src_amount: params.src_amount,
-                dst_amount: params.min_dst_amount,
-                fill_deadline: params.fill_deadline,
-            });
+        /// Book a fill's protocol fees; accrued, not sent, so a failing recipient transfer never
+        /// blocks settlement
+        fn accrue_fill_fees(&mut self, split: &FillSplit) -> Result<(), Error> {
+            let protocol_fee = split.maker_fee.checked_add(split.taker_fee).ok_or(Error::ArithmeticOverflow)?;
+            self.total_protocol_fees = self.total_protocol_fees.checked_add(protocol_fee).ok_or(Error::ArithmeticOverflow)?;
+            self.accrue_protocol_fee(None, protocol_fee)
+        }
 
-            Ok(order_hash)
+        /// Credit a fill's payouts: the resolver takes the source funds to provide liquidity on
+        /// Ethereum for PolkadotToEthereum and the maker takes them otherwise, plus any
+        /// late-execution rebate
+        fn pay_fill(&mut self, order: &FusionOrder, split: &FillSplit) -> Result<(), Error> {
+            let resolver = order.resolver.ok_or(Error::OnlyResolver)?;
+            match order.direction {
+                SwapDirection::PolkadotToEthereum => {
+                    self.queue_payout(None, resolver, split.resolver_payout)?;
+                    self.queue_payout(None, order.maker, split.late_fee_rebate)
+                }
+                SwapDirection::EthereumToPolkadot => {
+                    self.queue_payout(None, order.maker, split.to_maker)?;
+                    self.queue_payout(None, resolver, split.resolver_payout)
+                }
+            }
         }
 
-        /// Deploy source escrow (resolver-compatible interface)
-        #[ink(message, payable)]
-        pub fn deploy_src(
+        /// Report a fill's protocol fees, and the taker fee cap when it bit
+        fn emit_fill_fees(&self, order_hash: [u8; 32], taker: AccountId, split: &FillSplit) {
+            if split.maker_fee > 0 || split.taker_fee > 0 || split.taker_fee_discount > 0 {
+                self.emit_protocol_fee_charged(ProtocolFeeChargedV2 {
+                    order_hash,
+                    maker_fee: split.maker_fee,
+                    taker_fee: split.taker_fee,
+                    taker_fee_discount: split.taker_fee_discount,
+                });
+            }
+            if split.taker_fee < split.taker_fee_owed {
+                self.env().emit_event(TakerFeeCapped {
+                    order_hash,
+                    taker,
+                    owed: split.taker_fee_owed,
+                    charged: split.taker_fee,
+                });
+            }
+        }
+
+        /// Post-settlement reconciliation shared by the execution and refund paths: retires the
+        /// hash lock and any execution commitment, records the final fill (`receipt` is `None`
+        /// for refunds) and persists the order; only then returns the unreleased safety deposit
+        /// to the resolver, settles underwriting and notifies the coordinator.
+        /// `order.status` must already be terminal.
+        fn finalize_order(
             &mut self,
-            immutables: EscrowImmutables,
-            order_hash: [u8; 32],
-            _signature: [u8; 65], // r(32) + s(32) + v(1) - prefixed with underscore
-            _amount: Balance,     // prefixed with underscore
-            _args: Vec<u8>,       // prefixed with underscore
-        ) -> Result<AccountId, Error> {
-            self.ensure_not_paused()?;
-            
-            let safety_deposit = self.env().transferred_value();
-            if safety_deposit < self.min_safety_deposit {
-                return Err(Error::InsufficientDeposit);
+            order: &mut FusionOrder,
+            resolver_failed: bool,
+            underwriter_fee: Balance,
+            receipt: Option<FillReceipt>,
+        ) -> Result<(), Error> {
+            let order_hash = order.order_hash;
+            self.consume_hash_lock(order);
+            self.execution_commitments.remove(order_hash);
+
+            // Stake return: whatever partial fills have not already released
+            let mut returned = 0;
+            if order.resolver.is_some() {
+                returned = order.safety_deposit.saturating_sub(order.deposit_released);
+                order.deposit_released = order.safety_deposit;
             }
 
-            // Get and validate order
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
-            
-            if order.status != OrderStatus::Pending {
-                return Err(Error::InvalidOrderStatus);
+            if let Some(mut receipt) = receipt {
+                receipt.deposit_released = receipt.deposit_released
+                    .checked_add(returned)
+                    .ok_or(Error::ArithmeticOverflow)?;
+                self.record_fill(receipt);
             }
+            self.save_order(order);
 
-            // Update immutables with deployment timestamp
-            let mut immutables_mem = immutables.clone();
-            immutables_mem.deployed_at = Some(self.env().block_timestamp());
-            immutables_mem.safety_deposit = safety_deposit;
+            if let Some(resolver) = order.resolver {
+                self.pay_safety_deposit(order, resolver, returned)?;
+            }
+            self.settle_underwriting(order_hash, order.maker, resolver_failed, underwriter_fee)?;
+            self.refund_executor_tip(order)?;
+
+            // Best effort: a coordinator without the hook must not block settlement
+            if let (Some(coordinator), Some(resolver)) = (self.coordinator, order.resolver) {
+                coordinator::order_settled(
+                    coordinator,
+                    order_hash,
+                    resolver,
+                    order.status == OrderStatus::Executed,
+                    self.gas_budgets.coordinator,
+                );
+            }
 
-            // Compute escrow address deterministically 
-            let escrow_address = self.compute_escrow_address(&immutables_mem)?;
+            Ok(())
+        }
 
-            // Update order
-            order.status = OrderStatus::Locked;
-            order.safety_deposit = safety_deposit;
-            order.hash_lock_info.hash_lock = immutables.hash_lock;
-            order.resolver = Some(immutables.taker); // taker is resolver in this context
+        fn record_fill(&mut self, receipt: FillReceipt) {
+            let order_hash = receipt.order_hash;
+            let index = self.fill_receipt_counts.get(order_hash).unwrap_or(0);
+            self.fill_receipts.insert((order_hash, index), &receipt);
+            self.fill_receipt_counts.insert(order_hash, &index.saturating_add(1));
+        }
+
+        /// Part of `taker_fee` waived for the taker's coordinator reputation (none without a
+        /// coordinator)
+        fn taker_fee_discount(&self, taker: AccountId, taker_fee: Balance) -> Result<Balance, Error> {
+            if taker_fee == 0 || self.coordinator.is_none() {
+                return Ok(0);
+            }
+            let discounts = self.get_reputation_discounts();
+            if discounts.is_empty() {
+                return Ok(0);
+            }
+            let reputation = self.peek_remote(&RemoteKey::Reputation(taker)).unwrap_or(0);
+            let discount_bps = discounts
+                .iter()
+                .rev()
+                .find(|discount| Balance::from(discount.min_reputation) <= reputation)
+                .map_or(0, |discount| discount.discount_bps);
+            math::bps_of(taker_fee, discount_bps).ok_or(Error::ArithmeticOverflow)
+        }
+
+        fn size_tier_for(&self, src_token: AccountId, src_amount: Balance, basket: &[BasketLeg]) -> Option<SizeTier> {
+            let size = self.order_size(src_token, src_amount, basket)?;
+            self.size_tiers.iter().find(|tier| size <= tier.max_src_amount).cloned()
+        }
+
+        /// Source and basket legs rescaled to `DEFAULT_SRC_DECIMALS` and summed; None on overflow,
+        /// which no tier fits
+        fn order_size(&self, src_token: AccountId, src_amount: Balance, basket: &[BasketLeg]) -> Option<Balance> {
+            core::iter::once((src_token, src_amount))
+                .chain(basket.iter().map(|leg| (leg.token, leg.amount)))
+                .try_fold(0u128, |size, (token, amount)| {
+                    size.checked_add(math::rescale(amount, self.get_src_token_decimals(token), DEFAULT_SRC_DECIMALS)?)
+                })
+        }
+
+        /// An order's share of its resolver's exposure; saturates on overflow
+        fn order_exposure(&self, order: &FusionOrder) -> Balance {
+            self.order_size(order.src_token, order.src_amount, &order.basket).unwrap_or(Balance::MAX)
+        }
+
+        /// Native safety deposit required to lock `order`: its tier's, or the direction's
+        fn min_safety_deposit_for(&self, order: &FusionOrder) -> Balance {
+            match &order.size_tier {
+                Some(tier) => tier.min_safety_deposit,
+                None => self.params_for(&order.direction).min_safety_deposit,
+            }
+        }
+
+        fn params_for(&self, direction: &SwapDirection) -> DirectionParams {
+            let overrides = self.direction_overrides.get(direction).unwrap_or_default();
+            DirectionParams {
+                maker_fee_bps: overrides.maker_fee_bps.unwrap_or(self.maker_fee_bps),
+                taker_fee_bps: overrides.taker_fee_bps.unwrap_or(self.taker_fee_bps),
+                min_safety_deposit: overrides.min_safety_deposit.unwrap_or(self.min_safety_deposit),
+                private_cancellation_period: overrides
+                    .private_cancellation_period
+                    .unwrap_or(DEFAULT_PRIVATE_CANCELLATION_PERIOD),
+                stage_delays: overrides.stage_delays.unwrap_or_else(|| self.stage_delays.clone()),
+            }
+        }
+
+        /// Protocol fee owed by `payer`; fee-exempt accounts pay nothing
+        fn calculate_protocol_fee(&self, payer: AccountId, amount: Balance, fee_bps: u32) -> Result<Balance, Error> {
+            if self.fee_exempt.contains(payer) {
+                return Ok(0);
+            }
+            self.calculate_fee(amount, fee_bps)
+        }
+
+        /// Split `amount` of `token` between the fee recipients (rounding dust to the first), or
+        /// put it in the vault when none are configured
+        fn accrue_protocol_fee(&mut self, token: Option<AccountId>, amount: Balance) -> Result<(), Error> {
+            if amount == 0 {
+                return Ok(());
+            }
+            if self.fee_recipients.is_empty() {
+                let vault = self.fee_vault.get(token).unwrap_or(0);
+                self.fee_vault.insert(token, &vault.checked_add(amount).ok_or(Error::ArithmeticOverflow)?);
+            } else {
+                let mut shares = Vec::with_capacity(self.fee_recipients.len());
+                for recipient in self.fee_recipients.iter() {
+                    shares.push(math::bps_of(amount, recipient.share_bps).ok_or(Error::ArithmeticOverflow)?);
+                }
+                let dust = amount.saturating_sub(shares.iter().sum());
+                shares[0] = shares[0].saturating_add(dust);
+                for (recipient, share) in self.fee_recipients.iter().zip(shares) {
+                    let accrued = self.accrued_fee_shares.get((recipient.account, token)).unwrap_or(0);
+                    self.accrued_fee_shares.insert(
+                        (recipient.account, token),
+                        &accrued.checked_add(share).ok_or(Error::ArithmeticOverflow)?,
+                    );
+                }
+            }
+            self.adjust_ledger(token, LedgerEntry::Fees, amount, true);
+            Ok(())
+        }
+
+        /// Exit for payouts outside settlement, through the transfer backend chosen at
+        /// construction: credited to the payee's claimable balance under pull payments, otherwise
+        /// sent on the spot
+        fn payout(&mut self, token: Option<AccountId>, to: AccountId, amount: Balance) -> Result<(), Error> {
+            if amount == 0 {
+                return Ok(());
+            }
+            match self.transfer_backend() {
+                TransferBackend::PullPayment => self.credit_claimable(token, to, amount),
+                _ => self.transfer_out(token, to, amount),
+            }
+        }
+
+        /// Send `amount` now: PSP22 amounts via the token, native amounts directly or via the
+        /// chain extension
+        fn transfer_out(&mut self, token: Option<AccountId>, to: AccountId, amount: Balance) -> Result<(), Error> {
+            match (token, self.transfer_backend()) {
+                (Some(token), _) => psp22::transfer(token, to, amount, self.gas_budgets.token)
+                    .map_err(|_| Error::TransferFailed),
+                (None, TransferBackend::ChainExtension { func_id }) => {
+                    runtime_transfer::transfer(func_id, to, amount).map_err(|_| Error::TransferFailed)
+                }
+                (None, _) => self.env().transfer(to, amount).map_err(|_| Error::TransferFailed),
+            }
+        }
+
+        /// Contracts instantiated before the backend existed never set one and pay directly
+        fn transfer_backend(&self) -> TransferBackend {
+            self.transfer_backend.get().unwrap_or(TransferBackend::Direct)
+        }
+
+        /// Settlement legs are owed, never pushed, whatever the transfer backend: credited to the
+        /// payee's claimable balance and collected with `claim`, so no transfer can fail or
+        /// re-enter a settlement
+        fn queue_payout(&mut self, token: Option<AccountId>, to: AccountId, amount: Balance) -> Result<(), Error> {
+            if amount == 0 {
+                return Ok(());
+            }
+            self.credit_claimable(token, to, amount)
+        }
+
+        /// Owe `amount` of `token` to `payee` until claimed
+        fn credit_claimable(
+            &mut self,
+            token: Option<AccountId>,
+            payee: AccountId,
+            amount: Balance,
+        ) -> Result<(), Error> {
+            let owed = self.claimable.get((payee, token)).unwrap_or(0);
+            self.claimable.insert((payee, token), &owed.checked_add(amount).ok_or(Error::ArithmeticOverflow)?);
+            self.adjust_ledger(token, LedgerEntry::Payouts, amount, true);
+            self.env().emit_event(PayoutDeferred { payee, token, amount });
+            Ok(())
+        }
+
+        /// Transfer `payee`'s whole claimable balance in `token` to `to`
+        fn send_claimable(
+            &mut self,
+            payee: AccountId,
+            token: Option<AccountId>,
+            to: AccountId,
+        ) -> Result<Balance, Error> {
+            let amount = self.claimable.get((payee, token)).unwrap_or(0);
+            if amount == 0 {
+                return Ok(0);
+            }
+            self.claimable.remove((payee, token));
+            self.adjust_ledger(token, LedgerEntry::Payouts, amount, false);
+            self.transfer_out(token, to, amount)?;
+            self.env().emit_event(PayoutsWithdrawn { payee, token, amount });
+            self.debug_assert_reconciled();
+            Ok(amount)
+        }
+
+        /// Transfer accrued fees out of the contract in their own asset
+        fn pay_out_fees(&mut self, token: Option<AccountId>, to: AccountId, amount: Balance) -> Result<(), Error> {
+            self.payout(token, to, amount).map_err(|_| Error::ProtocolTransferFailed)?;
+            self.adjust_ledger(token, LedgerEntry::Fees, amount, false);
+            Ok(())
+        }
+
+        /// Order hash for a preimage; the enum wrapper is not part of the hashed bytes
+        fn hash_preimage(&self, preimage: &HashPreimage) -> [u8; 32] {
+            let encoded = match preimage {
+                HashPreimage::Root(fields) => fields.encode(),
+                HashPreimage::Split { parent_order, index } => (parent_order, index).encode(),
+            };
+            self.env().hash_bytes::<ink::env::hash::Blake2x256>(&encoded)
+        }
+
+        /// Store one migrated order and its side state with the bookkeeping its status implies,
+        /// collecting the PSP22 assets it owes
+        fn import_order(&mut self, exported: &ExportedOrder, tokens: &mut Vec<AccountId>) -> Result<(), Error> {
+            let order = &exported.order;
+            if order.status.is_terminal() {
+                return Err(Error::InvalidOrderStatus);
+            }
+            if exported.underwriting.is_some() && !matches!(order.status, OrderStatus::Locked | OrderStatus::PartialFill) {
+                return Err(Error::InvalidOrderStatus);
+            }
+            let order_hash = order.order_hash;
+            if self.orders.contains(order_hash) || self.archived_orders.contains(order_hash) {
+                return Err(Error::OrderAlreadyExists);
+            }
+            if let HashLockState::Set(hash_lock) = order.hash_lock_info.hash_lock {
+                if self.active_hash_locks.contains(hash_lock) || self.consumed_hash_locks.contains(hash_lock) {
+                    return Err(Error::HashLockAlreadyUsed);
+                }
+                self.active_hash_locks.insert(hash_lock, &order_hash);
+            }
+
+            self.save_order(order);
+            if let Some(resolver) = order.resolver {
+                self.track_resolver_order(resolver, order, true);
+            }
+            self.adjust_ledger(None, LedgerEntry::Escrowed, order.src_amount, true);
+            for leg in order.basket.iter() {
+                self.adjust_ledger(Some(leg.token), LedgerEntry::Escrowed, leg.amount, true);
+                if !tokens.contains(&leg.token) {
+                    tokens.push(leg.token);
+                }
+            }
+            let deposit_held = order.safety_deposit.saturating_sub(order.deposit_released);
+            self.adjust_ledger(order.safety_deposit_token, LedgerEntry::Deposits, deposit_held, true);
+            if let Some(token) = order.safety_deposit_token {
+                if !tokens.contains(&token) {
+                    tokens.push(token);
+                }
+            }
+            if let Some(deposit) = &exported.storage_deposit {
+                self.adjust_ledger(None, LedgerEntry::Deposits, deposit.amount, true);
+                self.storage_deposits.insert(order_hash, deposit);
+            }
+            if let Some(tip) = &exported.executor_tip {
+                self.adjust_ledger(None, LedgerEntry::Tips, tip.remaining, true);
+                self.executor_tips.insert(order_hash, tip);
+            }
+            if let Some(underwriting) = &exported.underwriting {
+                self.adjust_ledger(None, LedgerEntry::Deposits, underwriting.collateral, true);
+                self.underwritings.insert(order_hash, underwriting);
+            }
 
-            // Store escrow data
-            self.orders.insert(order_hash, &order);
-            self.escrow_addresses.insert(order_hash, &escrow_address);
-            self.active_hash_locks.insert(immutables.hash_lock, &order_hash);
-            
-            self.total_escrows_created = self.total_escrows_created.saturating_add(1);
+            // Keep locally created root orders from reusing a migrated nonce
+            if order.parent_order.is_none() && order.nonce >= self.order_nonce {
+                self.order_nonce = order.nonce.checked_add(1).ok_or(Error::ArithmeticOverflow)?;
+            }
+            Ok(())
+        }
 
-            self.env().emit_event(SrcEscrowDeployed {
-                order_hash: immutables_mem.order_hash,
-                escrow_address,
-                immutables: immutables_mem,
-                safety_deposit,
-            });
+        /// Load an order, overlaying the hot state onto the stored body
+        fn load_order(&self, order_hash: [u8; 32]) -> Option<FusionOrder> {
+            let mut order = self.orders.get(order_hash)?;
+            if let Some(state) = self.order_states.get(order_hash) {
+                order.status = state.status;
+                order.filled_amount = state.filled_amount;
+            }
+            Some(order)
+        }
 
-            Ok(escrow_address)
+        /// Persist both the order body and its hot state
+        fn save_order(&mut self, order: &FusionOrder) {
+            self.orders.insert(order.order_hash, order);
+            self.save_order_state(order);
         }
 
-        /// Deploy destination escrow (resolver-compatible interface)
-        #[ink(message, payable)]
-        pub fn deploy_dst(
-            &mut self,
-            dst_immutables: EscrowImmutables,
-            src_cancellation_timestamp: Timestamp,
-        ) -> Result<AccountId, Error> {
-            self.ensure_not_paused()?;
-            
-            let _deposit_amount = self.env().transferred_value(); // prefixed with underscore
-            let order_hash = dst_immutables.order_hash;
-            
-            // Get and validate order
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
-            
-            // Compute escrow address
-            let escrow_address = self.compute_escrow_address(&dst_immutables)?;
-            
-            // Update order with destination escrow info
-            order.ethereum_escrow = Some(EthereumEscrowInfo {
-                escrow_address: [0u8; 20], // Will be set by resolver
-                tx_hash: None,
-                block_number: None,
+        /// Persist only status/filled_amount (body untouched)
+        fn save_order_state(&mut self, order: &FusionOrder) {
+            let previous = self.order_states.get(order.order_hash);
+            match previous.as_ref().map(|state| &state.status) {
+                None => self.index_order(order),
+                Some(status) if *status != order.status => {
+                    Self::index_remove(
+                        &mut self.status_orders,
+                        &mut self.status_order_counts,
+                        &mut self.status_order_positions,
+                        status,
+                        order.order_hash,
+                    );
+                    Self::index_push(
+                        &mut self.status_orders,
+                        &mut self.status_order_counts,
+                        &mut self.status_order_positions,
+                        &order.status,
+                        order.order_hash,
+                    );
+                }
+                Some(_) => {}
+            }
+            let mut settled_at = previous.and_then(|s| s.settled_at);
+            if settled_at.is_none() && order.status.is_terminal() {
+                settled_at = Some(self.env().block_timestamp());
+            }
+            self.order_states.insert(order.order_hash, &OrderState {
+                status: order.status.clone(),
+                filled_amount: order.filled_amount,
+                settled_at,
             });
+            self.update_open_index(order);
+        }
 
-            // Store escrow data
-            self.orders.insert(order_hash, &order);
-            self.escrow_addresses.insert(order_hash, &escrow_address);
+        /// Add a new order to the maker and status indexes
+        fn index_order(&mut self, order: &FusionOrder) {
+            Self::index_push(
+                &mut self.maker_orders,
+                &mut self.maker_order_counts,
+                &mut self.maker_order_positions,
+                &order.maker,
+                order.order_hash,
+            );
+            Self::index_push(
+                &mut self.status_orders,
+                &mut self.status_order_counts,
+                &mut self.status_order_positions,
+                &order.status,
+                order.order_hash,
+            );
+        }
 
-            self.env().emit_event(DstEscrowDeployed {
+        /// Drop an archived order from the maker and status indexes
+        fn unindex_order(&mut self, maker: &AccountId, status: &OrderStatus, order_hash: [u8; 32]) {
+            Self::index_remove(
+                &mut self.maker_orders,
+                &mut self.maker_order_counts,
+                &mut self.maker_order_positions,
+                maker,
                 order_hash,
-                escrow_address,
-                immutables: dst_immutables,
-                src_cancellation_timestamp,
-            });
-
-            Ok(escrow_address)
+            );
+            Self::index_remove(
+                &mut self.status_orders,
+                &mut self.status_order_counts,
+                &mut self.status_order_positions,
+                status,
+                order_hash,
+            );
         }
 
-        /// Deploy escrow with resolver (primary interface)
-        #[ink(message, payable)]
-        pub fn deploy_escrow(
-            &mut self,
+        /// Append to a keyed order index, recording the position for O(1) removal. Entries are
+        /// stored one per `(key, index)` cell, so no single storage value grows with the index.
+        fn index_push<K: Encode + EncodeLike + Clone, E: StorageKey, C: StorageKey, P: StorageKey>(
+            entries: &mut Mapping<IndexKey<K>, [u8; 32], E>,
+            counts: &mut Mapping<K, u32, C>,
+            positions: &mut Mapping<[u8; 32], u32, P>,
+            key: &K,
             order_hash: [u8; 32],
-            params: ResolverParams,
-        ) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            
-            let caller = self.env().caller();
-            let safety_deposit = self.env().transferred_value();
+        ) {
+            let index = counts.get(key).unwrap_or(0);
+            entries.insert((key.clone(), index), &order_hash);
+            positions.insert(order_hash, &index);
+            counts.insert(key, &(index + 1));
+        }
 
-            // Validate resolver stake
-            if safety_deposit < self.min_safety_deposit {
-                return Err(Error::InsufficientDeposit);
+        /// Swap-remove from a keyed order index, moving the last entry into the freed cell
+        fn index_remove<K: Encode + EncodeLike + Clone, E: StorageKey, C: StorageKey, P: StorageKey>(
+            entries: &mut Mapping<IndexKey<K>, [u8; 32], E>,
+            counts: &mut Mapping<K, u32, C>,
+            positions: &mut Mapping<[u8; 32], u32, P>,
+            key: &K,
+            order_hash: [u8; 32],
+        ) {
+            let Some(index) = positions.take(order_hash) else {
+                return;
+            };
+            let count = counts.get(key).unwrap_or(0);
+            if index >= count {
+                return;
             }
-
-            // Get and validate order
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
-            
-            if order.status != OrderStatus::Pending {
-                return Err(Error::InvalidOrderStatus);
+            let last = count - 1;
+            if index != last {
+                if let Some(moved) = entries.get((key.clone(), last)) {
+                    entries.insert((key.clone(), index), &moved);
+                    positions.insert(moved, &index);
+                }
+            }
+            entries.remove((key.clone(), last));
+            if last == 0 {
+                counts.remove(key);
+            } else {
+                counts.insert(key, &last);
             }
+        }
 
-            // Validate hash lock uniqueness
-            if self.active_hash_locks.contains(params.hash_lock) {
-                return Err(Error::HashLockAlreadyUsed);
+        /// One page of the orders listed under `key` in a keyed index
+        fn index_page<K: Encode + EncodeLike + Clone, E: StorageKey, C: StorageKey>(
+            &self,
+            entries: &Mapping<IndexKey<K>, [u8; 32], E>,
+            counts: &Mapping<K, u32, C>,
+            key: &K,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<FusionOrder> {
+            let end = offset
+                .saturating_add(limit.min(MAX_PAGE_LEN))
+                .min(counts.get(key).unwrap_or(0));
+            (offset..end)
+                .filter_map(|index| entries.get((key.clone(), index)))
+                .filter_map(|order_hash| self.load_order(order_hash))
+                .collect()
+        }
+
+        /// Hashes in open-index cells `offset..offset + limit`, `limit` capped at `MAX_PAGE_LEN`
+        fn open_order_page(&self, offset: u32, limit: u32) -> impl Iterator<Item = [u8; 32]> + '_ {
+            let end = offset.saturating_add(limit.min(MAX_PAGE_LEN)).min(self.get_open_order_count());
+            (offset..end).filter_map(|index| self.open_orders.get(index))
+        }
+
+        /// Keep the open-order index in sync with an order's status
+        fn update_open_index(&mut self, order: &FusionOrder) {
+            let order_hash = order.order_hash;
+            let position = self.open_order_positions.get(order_hash);
+            match (order.status.is_terminal(), position) {
+                (false, None) => {
+                    let index = self.get_open_order_count();
+                    self.open_orders.insert(index, &order_hash);
+                    self.open_order_positions.insert(order_hash, &index);
+                    self.open_order_count.set(&(index + 1));
+                    self.adjust_locked_value(order.src_token, order.src_amount, true);
+                    for leg in order.basket.iter() {
+                        self.adjust_locked_value(leg.token, leg.amount, true);
+                    }
+                }
+                (true, Some(index)) => {
+                    self.adjust_locked_value(order.src_token, order.src_amount, false);
+                    for leg in order.basket.iter() {
+                        self.adjust_locked_value(leg.token, leg.amount, false);
+                    }
+                    if let Some(resolver) = order.resolver {
+                        self.track_resolver_order(resolver, order, false);
+                    }
+                    // Swap-remove: the last entry moves into the freed cell
+                    let last = self.get_open_order_count().saturating_sub(1);
+                    if index != last {
+                        if let Some(moved) = self.open_orders.get(last) {
+                            self.open_orders.insert(index, &moved);
+                            self.open_order_positions.insert(moved, &index);
+                        }
+                    }
+                    self.open_orders.remove(last);
+                    self.open_order_positions.remove(order_hash);
+                    self.open_order_count.set(&last);
+                }
+                _ => {}
             }
+        }
 
-            // Create immutables for escrow address computation
-            let immutables = EscrowImmutables {
-                order_hash,
-                hash_lock: params.hash_lock,
-                maker: order.maker,
-                taker: params.resolver,
-                token: order.src_token,
-                amount: order.src_amount,
-                safety_deposit,
-                timelocks: order.time_locks.clone(),
-                deployed_at: Some(self.env().block_timestamp()),
-            };
+        // V1/V2 emission per `event_version`; where V2 carries fields added after V1 was frozen,
+        // V1 is built from the V2 payload
+
+        fn emit_order_created(&self, event: OrderCreatedV2) {
+            if self.event_version.emits_v1() {
+                self.env().emit_event(OrderCreated {
+                    order_hash: event.order_hash,
+                    maker: event.maker,
+                    src_token: event.src_token,
+                    direction: event.direction.clone(),
+                    src_amount: event.src_amount,
+                    dst_amount: event.dst_amount,
+                    fill_deadline: event.fill_deadline,
+                    metadata: event.metadata.clone(),
+                });
+            }
+            if self.event_version.emits_v2() {
+                self.env().emit_event(event);
+            }
+        }
 
-            let escrow_address = self.compute_escrow_address(&immutables)?;
+        fn emit_escrow_deployed(&self, event: EscrowDeployed, hash_algo: HashAlgo) {
+            if self.event_version.emits_v2() {
+                self.env().emit_event(EscrowDeployedV2 {
+                    order_hash: event.order_hash,
+                    resolver: event.resolver,
+                    src_token: event.src_token,
+                    hash_lock: event.hash_lock,
+                    counterpart_escrow: ExternalAddress::Evm(event.ethereum_escrow),
+                    safety_deposit: event.safety_deposit,
+                    chain_id: self.ethereum_chain_id,
+                    hash_algo,
+                });
+            }
+            if self.event_version.emits_v1() {
+                self.env().emit_event(event);
+            }
+        }
 
-            // Update order with escrow info
-            order.resolver = Some(params.resolver);
-            order.hash_lock_info.hash_lock = params.hash_lock;
-            order.safety_deposit = safety_deposit;
-            order.resolver_fee = params.resolver_fee;
-            order.status = OrderStatus::Locked;
-            order.taker = Some(caller);
-            order.ethereum_escrow = Some(EthereumEscrowInfo {
-                escrow_address: params.ethereum_escrow_address,
-                tx_hash: None,
-                block_number: None,
-            });
+        /// A secret V1 cannot carry (not 32 bytes) is only reported in V2, whatever the version
+        fn emit_swap_executed(&self, event: SwapExecutedV2) {
+            let v1_secret = <[u8; 32]>::try_from(event.secret.as_slice()).ok();
+            if let (true, Some(secret)) = (self.event_version.emits_v1(), v1_secret) {
+                self.env().emit_event(SwapExecuted {
+                    order_hash: event.order_hash,
+                    resolver: event.resolver,
+                    src_token: event.src_token,
+                    secret,
+                    amount_filled: event.amount_filled,
+                    late_fee_rebate: event.late_fee_rebate,
+                    in_grace_period: event.in_grace_period,
+                    metadata: event.metadata.clone(),
+                });
+            }
+            if self.event_version.emits_v2() || v1_secret.is_none() {
+                self.env().emit_event(event);
+            }
+        }
 
-            // Store updates
-            self.orders.insert(order_hash, &order);
-            self.escrow_addresses.insert(order_hash, &escrow_address);
-            self.active_hash_locks.insert(params.hash_lock, &order_hash);
-            self.resolver_stakes.insert(params.resolver, &safety_deposit);
+        fn emit_protocol_fee_charged(&self, event: ProtocolFeeChargedV2) {
+            if self.event_version.emits_v1() {
+                self.env().emit_event(ProtocolFeeCharged {
+                    order_hash: event.order_hash,
+                    maker_fee: event.maker_fee,
+                    taker_fee: event.taker_fee,
+                });
+            }
+            if self.event_version.emits_v2() {
+                self.env().emit_event(event);
+            }
+        }
 
-            self.env().emit_event(EscrowDeployed {
-                order_hash,
-                resolver: params.resolver,
-                hash_lock: params.hash_lock,
-                ethereum_escrow: params.ethereum_escrow_address,
-                safety_deposit,
-            });
+        /// Recipient must be well-formed; Ethereum-bound orders need an EVM address
+        fn validate_recipient(&self, direction: &SwapDirection, recipient: &ExternalAddress) -> Result<(), Error> {
+            if !recipient.is_valid() {
+                return Err(Error::InvalidExternalAddress);
+            }
+            if *direction == SwapDirection::PolkadotToEthereum && !matches!(recipient, ExternalAddress::Evm(_)) {
+                return Err(Error::InvalidEthereumAddress);
+            }
+            Ok(())
+        }
 
+        fn validate_basket(&self, params: &CreateOrderParams) -> Result<(), Error> {
+            if params.basket.is_empty() {
+                return Ok(());
+            }
+            if params.direction != SwapDirection::PolkadotToEthereum {
+                return Err(Error::UnsupportedDirection);
+            }
+            if params.basket.len() > MAX_BASKET_LEGS {
+                return Err(Error::InvalidLength);
+            }
+            for (i, leg) in params.basket.iter().enumerate() {
+                if leg.amount == 0 {
+                    return Err(Error::InvalidAmount);
+                }
+                if !self.is_src_token_allowed(leg.token) {
+                    return Err(Error::UnsupportedToken);
+                }
+                if params.basket[..i].iter().any(|other| other.token == leg.token) {
+                    return Err(Error::InvalidAmount);
+                }
+            }
             Ok(())
         }
 
-        /// Withdraw from escrow (resolver-compatible interface)
-        #[ink(message)]
-        pub fn withdraw(
+        /// Bounty for `keeper` out of `base` (shared by every keeper-callable message); zero
+        /// for unregistered callers while the allow-list is enforced
+        fn keeper_bounty(&self, keeper: AccountId, base: Balance) -> Balance {
+            let config = &self.keeper_config;
+            if config.allow_list_only && !self.keepers.get(keeper).unwrap_or(false) {
+                return 0;
+            }
+            math::bps_of(base, config.bounty_bps)
+                .unwrap_or(0)
+                .min(config.max_bounty)
+        }
+
+        /// Pay `executor` the order's per-action tip out of what is left of its budget
+        fn pay_executor_tip(
             &mut self,
             order_hash: [u8; 32],
-            secret: [u8; 32],
-            _immutables: EscrowImmutables, // prefixed with underscore
+            executor: AccountId,
+            action: OrderAction,
         ) -> Result<(), Error> {
-            let escrow_address = self.escrow_addresses.get(order_hash)
-                .ok_or(Error::EscrowNotFound)?;
-
-            // Get order
-            let order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
-
-            // Verify secret against hash lock
-            let computed_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&secret);
-            if computed_hash != order.hash_lock_info.hash_lock {
-                return Err(Error::InvalidSecret);
+            let Some(mut tip) = self.executor_tips.get(order_hash) else {
+                return Ok(());
+            };
+            let amount = tip.per_action.min(tip.remaining);
+            if amount == 0 {
+                return Ok(());
             }
+            tip.remaining -= amount;
+            self.executor_tips.insert(order_hash, &tip);
+            self.adjust_ledger(None, LedgerEntry::Tips, amount, false);
 
-            // Check timelock constraints
-            let current_time = self.env().block_timestamp();
-            self.check_withdrawal_timelock(&order, current_time)?;
-
-            // Execute the swap logic
-            self.execute_swap_internal(order_hash, secret)?;
-
-            self.env().emit_event(EscrowWithdrawal {
+            self.queue_payout(None, executor, amount)?;
+            self.env().emit_event(ExecutorTipPaid {
+                executor,
                 order_hash,
-                escrow_address,
-                secret,
-                amount: order.src_amount,
+                action,
+                amount,
             });
+            Ok(())
+        }
 
+        /// Hand the maker back the unspent tip budget of a settled order, keeping one action's
+        /// worth for whoever archives it
+        fn refund_executor_tip(&mut self, order: &FusionOrder) -> Result<(), Error> {
+            let Some(mut tip) = self.executor_tips.get(order.order_hash) else {
+                return Ok(());
+            };
+            let refund = tip.remaining.saturating_sub(tip.per_action);
+            if refund == 0 {
+                return Ok(());
+            }
+            tip.remaining -= refund;
+            self.executor_tips.insert(order.order_hash, &tip);
+            self.adjust_ledger(None, LedgerEntry::Tips, refund, false);
+            self.queue_payout(None, order.maker, refund)
+        }
+
+        /// Resolver identity `account` acts as: the resolver it operates for, or itself
+        fn resolver_identity(&self, account: AccountId) -> AccountId {
+            self.operator_resolvers.get(account).unwrap_or(account)
+        }
+
+        /// An operator may not be a resolver itself (approved, staked or with its own
+        /// operator) nor act for a resolver other than `resolver`
+        fn ensure_operator_available(&self, operator: AccountId, resolver: AccountId) -> Result<(), Error> {
+            if operator == resolver
+                || self.approved_resolvers.contains(operator)
+                || self.resolver_stakes.contains(operator)
+                || self.resolver_operators.contains(operator)
+                || self.operator_resolvers.get(operator).is_some_and(|bound| bound != resolver)
+            {
+                return Err(Error::OperatorInUse);
+            }
             Ok(())
         }
 
-        /// Execute swap with secret reveal (primary interface)
-        #[ink(message)]
-        pub fn execute_swap(
-            &mut self,
+        /// Another executor's unexpired commit-reveal claim blocks everyone else
+        fn check_execution_commitment(
+            &self,
             order_hash: [u8; 32],
-            secret: [u8; 32],
+            executor: AccountId,
+            current_time: Timestamp,
         ) -> Result<(), Error> {
-            self.execute_swap_internal(order_hash, secret)
+            match self.execution_commitments.get(order_hash) {
+                Some(commitment) if commitment.executor != executor && current_time < commitment.exclusive_until => {
+                    Err(Error::ExecutionCommitted)
+                }
+                _ => Ok(()),
+            }
         }
 
-        /// Internal swap execution logic
-        fn execute_swap_internal(
+        fn execution_commitment_hash(&self, secret: &[u8; 32], executor: &AccountId) -> [u8; 32] {
+            let mut preimage = [0u8; 64];
+            preimage[..32].copy_from_slice(secret);
+            preimage[32..].copy_from_slice(executor.as_ref());
+            self.env().hash_bytes::<ink::env::hash::Blake2x256>(&preimage)
+        }
+
+        fn live_commitment(&self, order_hash: [u8; 32], current_time: Timestamp) -> Option<HashLockCommitment> {
+            self.hash_lock_commitments
+                .get(order_hash)
+                .filter(|commitment| current_time < commitment.expires_at)
+        }
+
+        /// While a commitment is live only its resolver and hash lock may deploy; consumes it
+        fn take_hash_lock_commitment(
             &mut self,
             order_hash: [u8; 32],
-            secret: [u8; 32],
+            resolver: AccountId,
+            hash_lock: [u8; 32],
         ) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            
-            let caller = self.env().caller();
-            let current_time = self.env().block_timestamp();
-
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
+            if let Some(commitment) = self.live_commitment(order_hash, self.env().block_timestamp()) {
+                if commitment.resolver != resolver {
+                    return Err(Error::HashLockCommitted);
+                }
+                if commitment.hash_lock != hash_lock {
+                    return Err(Error::InvalidHashLock);
+                }
+            }
+            self.hash_lock_commitments.remove(order_hash);
+            Ok(())
+        }
 
-            // Validate order state
-            if order.status != OrderStatus::Locked {
-                return Err(Error::InvalidOrderStatus);
+        /// Preimages of 1..=MAX_SECRET_LEN bytes; the order's hash over the raw bytes either way,
+        /// so a 32-byte preimage hashes the same through both entry points
+        fn ensure_secret_len(secret: &[u8]) -> Result<(), Error> {
+            if secret.is_empty() || secret.len() > MAX_SECRET_LEN {
+                return Err(Error::InvalidLength);
             }
+            Ok(())
+        }
 
-            // Check timelock
-            if current_time > order.time_locks.fill_deadline {
-                return Err(Error::DeadlineExpired);
+        /// Check `secret` against the order's active hash lock
+        fn verify_secret(&self, order: &FusionOrder, secret: &[u8]) -> Result<(), Error> {
+            let secret_hash = order.hash_lock_info.hash_algo.hash(secret);
+            self.check_hash_lock(order, &secret_hash)
+        }
+
+        fn check_hash_lock(&self, order: &FusionOrder, secret_hash: &[u8; 32]) -> Result<(), Error> {
+            match order.hash_lock_info.hash_lock {
+                HashLockState::Set(hash_lock) if hash_lock == *secret_hash => Ok(()),
+                HashLockState::Set(_) => Err(Error::InvalidSecret),
+                HashLockState::Unset => Err(Error::InvalidHashLock),
+                HashLockState::Consumed(_) => Err(Error::HashLockAlreadyUsed),
             }
+        }
 
-            // Verify secret against hash lock
-            let computed_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&secret);
-            if computed_hash != order.hash_lock_info.hash_lock {
-                return Err(Error::InvalidSecret);
+        /// Retire an active hash lock so it can never unlock this order again
+        fn consume_hash_lock(&mut self, order: &mut FusionOrder) {
+            if let HashLockState::Set(hash_lock) = order.hash_lock_info.hash_lock {
+                self.active_hash_locks.remove(hash_lock);
+                self.consumed_hash_locks.insert(hash_lock, &order.order_hash);
+                order.hash_lock_info.hash_lock = HashLockState::Consumed(hash_lock);
             }
+        }
 
-            // Verify Ethereum escrow is deployed (for PolkadotToEthereum)
-            if order.direction == SwapDirection::PolkadotToEthereum 
-                && order.ethereum_escrow.is_none() {
-                return Err(Error::EthereumEscrowNotSet);
+        /// A hash lock may back one order only, ever: a revealed secret must not unlock another
+        fn ensure_hash_lock_unused(&self, hash_lock: [u8; 32]) -> Result<(), Error> {
+            if self.active_hash_locks.contains(hash_lock) || self.consumed_hash_locks.contains(hash_lock) {
+                return Err(Error::HashLockAlreadyUsed);
             }
+            Ok(())
+        }
 
-            // Calculate amounts with overflow protection
-            let total_amount = order.src_amount;
-            let protocol_fee = self.calculate_protocol_fee(total_amount)?;
-            let remaining_after_protocol = total_amount.checked_sub(protocol_fee).ok_or(Error::ArithmeticOverflow)?;
-            let resolver_fee = order.resolver_fee.min(remaining_after_protocol);
-            let net_amount = remaining_after_protocol.checked_sub(resolver_fee).ok_or(Error::ArithmeticOverflow)?;
+        /// `transfer_from` into this contract, returning the balance actually received
+        fn pull_psp22(&self, token: AccountId, from: AccountId, amount: Balance) -> Result<Balance, Error> {
+            let contract = self.env().account_id();
+            let before = psp22::balance_of(token, contract, self.gas_budgets.token).map_err(|_| Error::TransferFailed)?;
+            psp22::transfer_from(token, from, contract, amount, self.gas_budgets.token).map_err(|_| Error::TransferFailed)?;
+            let after = psp22::balance_of(token, contract, self.gas_budgets.token).map_err(|_| Error::TransferFailed)?;
 
-            // Execute transfers based on direction
-            match order.direction {
-                SwapDirection::PolkadotToEthereum => {
-                    // Resolver gets the funds to provide liquidity on Ethereum
-                    let resolver_address = order.resolver.ok_or(Error::OnlyResolver)?;
-                    
-                    // Transfer net amount + resolver fee to resolver
-                    let total_to_resolver = net_amount.checked_add(resolver_fee).ok_or(Error::ArithmeticOverflow)?;
-                    self.env().transfer(resolver_address, total_to_resolver)
-                        .map_err(|_| Error::TransferFailed)?;
-                    
-                    // Pay protocol fee
-                    if protocol_fee > 0 {
-                        self.env().transfer(self.owner, protocol_fee)
-                            .map_err(|_| Error::TransferFailed)?;
-                    }
-                },
-                SwapDirection::EthereumToPolkadot => {
-                    // User receives funds from resolver's deposit
-                    self.env().transfer(order.maker, net_amount)
-                        .map_err(|_| Error::TransferFailed)?;
-                        
-                    // Pay resolver fee
-                    if resolver_fee > 0 {
-                        let resolver_address = order.resolver.ok_or(Error::OnlyResolver)?;
-                        self.env().transfer(resolver_address, resolver_fee)
-                            .map_err(|_| Error::TransferFailed)?;
-                    }
-                    
-                    // Pay protocol fee
-                    if protocol_fee > 0 {
-                        self.env().transfer(self.owner, protocol_fee)
-                            .map_err(|_| Error::TransferFailed)?;
-                    }
-                }
+            let received = after.saturating_sub(before).min(amount);
+            if received == 0 {
+                return Err(Error::InsufficientFundsV2 { required: amount, provided: 0 });
             }
+            Ok(received)
+        }
 
-            // Update order state
-            order.status = OrderStatus::Executed;
-            order.filled_amount = total_amount;
-            order.hash_lock_info.secret = Some(secret);
-            
-            self.orders.insert(order_hash, &order);
-            self.active_hash_locks.remove(order.hash_lock_info.hash_lock);
-            self.total_volume = self.total_volume.checked_add(total_amount).ok_or(Error::ArithmeticOverflow)?;
+        /// Pay out every basket leg of `order` to `to` (release or refund as a unit)
+        fn transfer_basket(&mut self, order: &FusionOrder, to: AccountId) -> Result<(), Error> {
+            for leg in order.basket.iter() {
+                self.queue_payout(Some(leg.token), to, leg.amount)?;
+                self.adjust_ledger(Some(leg.token), LedgerEntry::Escrowed, leg.amount, false);
+            }
+            Ok(())
+        }
 
-            self.env().emit_event(SwapExecuted {
-                order_hash,
-                resolver: caller,
-                secret,
-                amount_filled: total_amount,
-            });
+        /// Move one entry of an asset's ledger (`None` = native); called next to every
+        /// transfer into or out of the contract
+        fn adjust_ledger(&mut self, asset: Option<AccountId>, entry: LedgerEntry, amount: Balance, increase: bool) {
+            if amount == 0 {
+                return;
+            }
+            let mut ledger = self.asset_ledgers.get(asset).unwrap_or_default();
+            let current = match entry {
+                LedgerEntry::Escrowed => &mut ledger.escrowed,
+                LedgerEntry::Deposits => &mut ledger.deposits,
+                LedgerEntry::Fees => &mut ledger.fees,
+                LedgerEntry::Tips => &mut ledger.tips,
+                LedgerEntry::Payouts => &mut ledger.payouts,
+            };
+            debug_assert!(increase || *current >= amount, "{entry:?} ledger underflow");
+            *current = if increase {
+                current.saturating_add(amount)
+            } else {
+                current.saturating_sub(amount)
+            };
+            self.asset_ledgers.insert(asset, &ledger);
+        }
 
-            Ok(())
+        /// Test-build invariant: the native ledger is always covered by the contract balance
+        fn debug_assert_reconciled(&self) {
+            debug_assert!(
+                self.env().balance() >= self.asset_ledgers.get(None::<AccountId>).unwrap_or_default().total(),
+                "native ledger exceeds contract balance"
+            );
+        }
+
+        fn adjust_locked_value(&mut self, token: AccountId, amount: Balance, increase: bool) {
+            let current = self.locked_value.get(token);
+            if current.is_none() {
+                let index = self.locked_token_count.get().unwrap_or(0);
+                self.locked_tokens.insert(index, &token);
+                self.locked_token_count.set(&(index + 1));
+            }
+            let current = current.unwrap_or(0);
+            let updated = if increase {
+                current.saturating_add(amount)
+            } else {
+                current.saturating_sub(amount)
+            };
+            self.locked_value.insert(token, &updated);
         }
 
-        /// Cancel escrow (resolver-compatible interface)
-        #[ink(message)]
-        pub fn cancel(
-            &mut self,
-            order_hash: [u8; 32],
-            _immutables: EscrowImmutables, // prefixed with underscore
-        ) -> Result<(), Error> {
-            let escrow_address = self.escrow_addresses.get(order_hash)
-                .ok_or(Error::EscrowNotFound)?;
-
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
+        /// Count open assigned orders per resolver to derive the active resolver set
+        fn track_resolver_order(&mut self, resolver: AccountId, order: &FusionOrder, opened: bool) {
+            let count = self.resolver_open_orders.get(resolver).unwrap_or(0);
+            let updated = if opened { count.saturating_add(1) } else { count.saturating_sub(1) };
+
+            // Decimals may change while an order is open: exposure resets with the last order
+            let value = self.order_exposure(order);
+            let exposure = self.resolver_exposure.get(resolver).unwrap_or(0);
+            let exposure = if opened { exposure.saturating_add(value) } else { exposure.saturating_sub(value) };
+            if exposure == 0 || updated == 0 {
+                self.resolver_exposure.remove(resolver);
+            } else {
+                self.resolver_exposure.insert(resolver, &exposure);
+            }
 
-            // Check cancellation timelock
-            let current_time = self.env().block_timestamp();
-            self.check_cancellation_timelock(&order, current_time)?;
+            if count == 0 && updated > 0 {
+                self.active_resolvers = self.active_resolvers.saturating_add(1);
+            } else if count > 0 && updated == 0 {
+                self.active_resolvers = self.active_resolvers.saturating_sub(1);
+            }
+            if updated == 0 {
+                self.resolver_open_orders.remove(resolver);
+            } else {
+                self.resolver_open_orders.insert(resolver, &updated);
+            }
+        }
 
-            // Calculate refund
-            let refund_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
-            
-            // Execute refund
-            if refund_amount > 0 {
-                self.env().transfer(order.maker, refund_amount)
-                    .map_err(|_| Error::TransferFailed)?;
+        /// `payout_credits` claimable writes on top of the base, plus the coordinator's taker
+        /// reputation read and settlement notice when one is set
+        fn estimate_weight(&self, payout_credits: u32) -> ExecutionEstimate {
+            let coordinator_calls = if self.coordinator.is_some() { 2 } else { 0 };
+            let coordinator = self.gas_budgets.coordinator;
+            let ref_time = EXECUTE_BASE_WEIGHT.ref_time
+                .saturating_add(PAYOUT_CREDIT_WEIGHT.ref_time.saturating_mul(payout_credits as u64))
+                .saturating_add(coordinator.ref_time.saturating_mul(coordinator_calls as u64));
+            let proof_size = EXECUTE_BASE_WEIGHT.proof_size
+                .saturating_add(PAYOUT_CREDIT_WEIGHT.proof_size.saturating_mul(payout_credits as u64))
+                .saturating_add(coordinator.proof_size.saturating_mul(coordinator_calls as u64));
+            ExecutionEstimate {
+                ref_time,
+                proof_size,
+                payout_credits,
+                coordinator_calls,
             }
+        }
 
-            // Refund resolver stake
-            if order.safety_deposit > 0 && order.resolver.is_some() {
-                self.env().transfer(order.resolver.unwrap(), order.safety_deposit)
-                    .map_err(|_| Error::TransferFailed)?;
+        /// Slashing hook: take up to `amount` from bonded stake, then from unbonding stake,
+        /// and pay it to `beneficiary`. Returns the amount actually slashed.
+        fn slash_resolver_stake(
+            &mut self,
+            resolver: AccountId,
+            amount: Balance,
+            beneficiary: AccountId,
+        ) -> Result<Balance, Error> {
+            let mut remaining = amount;
+
+            let stake = self.resolver_stakes.get(resolver).unwrap_or(0);
+            let from_stake = stake.min(remaining);
+            if from_stake > 0 {
+                remaining -= from_stake;
+                if stake == from_stake {
+                    self.resolver_stakes.remove(resolver);
+                } else {
+                    self.resolver_stakes.insert(resolver, &(stake - from_stake));
+                }
             }
 
-            // Update state
-            order.status = OrderStatus::Cancelled;
-            self.orders.insert(order_hash, &order);
-            
-            if !order.hash_lock_info.hash_lock.is_empty() {
-                self.active_hash_locks.remove(order.hash_lock_info.hash_lock);
+            if remaining > 0 {
+                if let Some(mut unbonding) = self.resolver_unbondings.get(resolver) {
+                    let from_unbonding = unbonding.amount.min(remaining);
+                    remaining -= from_unbonding;
+                    unbonding.amount -= from_unbonding;
+                    if unbonding.amount == 0 {
+                        self.resolver_unbondings.remove(resolver);
+                    } else {
+                        self.resolver_unbondings.insert(resolver, &unbonding);
+                    }
+                }
             }
 
-            self.env().emit_event(EscrowCancellation {
-                order_hash,
-                escrow_address,
-                refund_amount,
-            });
+            let slashed = amount - remaining;
+            if slashed > 0 {
+                self.queue_payout(None, beneficiary, slashed)?;
+                self.adjust_ledger(None, LedgerEntry::Deposits, slashed, false);
+                self.env().emit_event(ResolverSlashed { resolver, amount: slashed, beneficiary });
+            }
+            Ok(slashed)
+        }
 
+        /// Stake stays slashable while the resolver holds any open order
+        fn ensure_no_open_orders(&self, resolver: AccountId) -> Result<(), Error> {
+            if self.resolver_open_orders.get(resolver).unwrap_or(0) > 0 {
+                return Err(Error::ResolverHasOpenOrders);
+            }
             Ok(())
         }
 
-        /// Cancel order (primary interface)
-        #[ink(message)]
-        pub fn cancel_order(&mut self, order_hash: [u8; 32]) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            
-            let caller = self.env().caller();
-            let current_time = self.env().block_timestamp();
+        /// Reject assignments that would take a resolver's open order value past the
+        /// configured multiple of its coordinator stake
+        fn ensure_collateralized(&mut self, resolver: AccountId, additional: Balance) -> Result<(), Error> {
+            if self.collateral_ratio_bps == 0 {
+                return Ok(());
+            }
+            let stake = self.read_remote(RemoteKey::Stake(resolver))?;
+            let limit = math::bps_of(stake, self.collateral_ratio_bps).unwrap_or(Balance::MAX);
+            let exposure = self.resolver_exposure.get(resolver).unwrap_or(0).saturating_add(additional);
+            if exposure > limit {
+                return Err(Error::UnderCollateralized { exposure, limit });
+            }
+            Ok(())
+        }
 
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
+        /// Only approved (or sufficiently staked) resolvers may lock orders, unless permissionless
+        fn ensure_resolver_vetted(&self, resolver: AccountId) -> Result<(), Error> {
+            if self.permissionless_resolvers || self.is_resolver_approved(resolver) {
+                return Ok(());
+            }
+            Err(Error::ResolverRejected)
+        }
 
-            let (can_cancel, reason) = match order.status {
-                OrderStatus::Pending => {
-                    // Maker can cancel during private cancellation period
-                    if caller == order.maker && current_time <= order.time_locks.private_cancellation {
-                        (true, CancelReason::MakerCancellation)
-                    } else {
-                        (false, CancelReason::MakerCancellation)
-                    }
-                },
-                OrderStatus::Locked | OrderStatus::PartialFill => {
-                    // Anyone can cancel after timelock expiry
-                    if current_time > order.time_locks.fill_deadline {
-                        (true, CancelReason::TimelockExpired)
-                    } else if caller == order.maker {
-                        (true, CancelReason::MakerCancellation)
-                    } else {
-                        (false, CancelReason::ResolverTimeout)
-                    }
-                },
-                _ => (false, CancelReason::EmergencyStop),
+        /// The locking caller (an operator's resolver) must hold the access token, if configured;
+        /// a failed balance read counts as holding none
+        fn ensure_access_token_held(&self, account: AccountId) -> Result<(), Error> {
+            let (Some(token), required) = self.get_access_token() else {
+                return Ok(());
             };
+            let held = psp22::balance_of(token, account, self.gas_budgets.token).unwrap_or(0);
+            if held < required {
+                return Err(Error::AccessTokenRequired { required, held });
+            }
+            Ok(())
+        }
 
-            if !can_cancel {
-                return Err(Error::Unauthorized);
+        /// During the referral head start only the maker's preferred resolver may claim the order
+        fn ensure_preferred_resolver(&self, order: &FusionOrder, resolver: AccountId) -> Result<(), Error> {
+            match order.preferred_resolver {
+                Some(preferred) if preferred != resolver && self.env().block_timestamp() < order.preferred_until => {
+                    Err(Error::PreferredResolverOnly)
+                }
+                _ => Ok(()),
             }
+        }
 
-            // Calculate refund
-            let refund_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
-            
-            // Execute refund
-            if refund_amount > 0 {
-                self.env().transfer(order.maker, refund_amount)
-                    .map_err(|_| Error::TransferFailed)?;
+        /// Reject resolvers the maker excluded on this order or persistently
+        fn ensure_resolver_not_blocked(&self, order: &FusionOrder, resolver: AccountId) -> Result<(), Error> {
+            if order.blocked_resolvers.contains(&resolver)
+                || self.maker_blocked_resolvers.contains((order.maker, resolver))
+            {
+                return Err(Error::ResolverBlocked);
             }
+            Ok(())
+        }
 
-            // Refund resolver stake
-            if order.safety_deposit > 0 && order.resolver.is_some() {
-                self.env().transfer(order.resolver.unwrap(), order.safety_deposit)
-                    .map_err(|_| Error::TransferFailed)?;
+        /// Coordinator value for `key`, from the cache while fresh; failed reads count as 0 and
+        /// are not cached
+        fn read_remote(&mut self, key: RemoteKey) -> Result<Balance, Error> {
+            if let Some(cached) = self.cached_remote(&key) {
+                return Ok(cached.value);
             }
+            let coordinator = self.coordinator.ok_or(Error::CoordinatorNotSet)?;
+            let Some(value) = self.fetch_remote(coordinator, &key) else {
+                return Ok(0);
+            };
+            if self.remote_cache_ttl > 0 {
+                self.cache_remote(&key, value);
+            }
+            Ok(value)
+        }
 
-            // Update state
-            order.status = OrderStatus::Cancelled;
-            self.orders.insert(order_hash, &order);
-            
-            if !order.hash_lock_info.hash_lock.is_empty() {
-                self.active_hash_locks.remove(order.hash_lock_info.hash_lock);
+        /// `read_remote` for read-only paths: a fresh reading is not cached, and `None` if the
+        /// coordinator is unset or does not answer
+        fn peek_remote(&self, key: &RemoteKey) -> Option<Balance> {
+            match self.cached_remote(key) {
+                Some(cached) => Some(cached.value),
+                None => self.fetch_remote(self.coordinator?, key),
             }
+        }
 
-            self.env().emit_event(OrderCancelled {
-                order_hash,
-                refund_amount,
-                reason,
+        fn cached_remote(&self, key: &RemoteKey) -> Option<CachedValue> {
+            let cached = self.remote_cache.get(key)?;
+            let expires_at = cached.fetched_at.saturating_add(self.remote_cache_ttl);
+            (cached.epoch == self.remote_cache_epoch && self.env().block_timestamp() < expires_at).then_some(cached)
+        }
+
+        fn cache_remote(&mut self, key: &RemoteKey, value: Balance) {
+            self.remote_cache.insert(key, &CachedValue {
+                value,
+                fetched_at: self.env().block_timestamp(),
+                epoch: self.remote_cache_epoch,
             });
+        }
 
-            Ok(())
+        fn fetch_remote(&self, coordinator: AccountId, key: &RemoteKey) -> Option<Balance> {
+            let gas = self.gas_budgets.coordinator;
+            match key {
+                RemoteKey::Reputation(account) => coordinator::get_reputation(coordinator, *account, gas).map(Balance::from),
+                RemoteKey::Stake(account) => coordinator::get_stake(coordinator, *account, gas),
+            }
         }
-        
-        /// Partial fill execution (stretch goal)
-        #[ink(message)]
-        pub fn execute_partial_fill(
-            &mut self,
-            order_hash: [u8; 32],
-            fill_amount: Balance,
-            secret: [u8; 32],
-        ) -> Result<(), Error> {
-            self.ensure_not_paused()?;
-            
-            let mut order = self.orders.get(order_hash).ok_or(Error::OrderNotFound)?;
 
-            if order.status != OrderStatus::Locked && order.status != OrderStatus::PartialFill {
-                return Err(Error::InvalidOrderStatus);
+        /// Enforce the maker's minimum resolver reputation, if any, via the coordinator
+        fn ensure_resolver_reputation(&mut self, order: &FusionOrder, resolver: AccountId) -> Result<(), Error> {
+            let Some(min_reputation) = order.min_resolver_reputation else {
+                return Ok(());
+            };
+            let reputation = self.read_remote(RemoteKey::Reputation(resolver))?;
+            if reputation < Balance::from(min_reputation) {
+                return Err(Error::InsufficientReputation);
             }
+            Ok(())
+        }
 
-            // Verify secret
-            let computed_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&secret);
-            if computed_hash != order.hash_lock_info.hash_lock {
-                return Err(Error::InvalidSecret);
+        /// Resolver fee after late-execution decay: linear from the full fee at
+        /// `fill_deadline - window` down to `min_fee_bps` at the deadline
+        fn effective_resolver_fee(
+            &self,
+            order: &FusionOrder,
+            resolver_fee: Balance,
+            current_time: Timestamp,
+        ) -> Result<Balance, Error> {
+            let Some(schedule) = &order.late_fee_schedule else {
+                return Ok(resolver_fee);
+            };
+            let deadline = order.time_locks.fill_deadline;
+            let decay_start = deadline.saturating_sub(schedule.window);
+            if schedule.window == 0 || current_time <= decay_start {
+                return Ok(resolver_fee);
             }
 
-            let remaining = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
-            if fill_amount > remaining {
-                return Err(Error::InvalidAmount);
+            // Linear from the full fee at `decay_start` down to `min_fee_bps` at the deadline
+            let remaining = deadline.saturating_sub(current_time);
+            let bps = math::lerp(schedule.min_fee_bps as u128, math::BPS_DENOMINATOR, remaining, schedule.window)
+                .ok_or(Error::ArithmeticOverflow)?;
+            math::mul_div(resolver_fee, bps, math::BPS_DENOMINATOR).ok_or(Error::ArithmeticOverflow)
+        }
+
+        /// Auction price at `current_time`, never below the order's `dst_amount` floor
+        fn current_dst_amount(order: &FusionOrder, current_time: Timestamp) -> Result<U256, Error> {
+            let Some(auction) = &order.auction else {
+                return Ok(order.dst_amount);
+            };
+            let start = auction.start_amount.checked_to_u128().ok_or(Error::ArithmeticOverflow)?;
+            let end = auction.end_amount.checked_to_u128().ok_or(Error::ArithmeticOverflow)?;
+            let elapsed = current_time.saturating_sub(auction.start_time);
+            let price = math::lerp(start, end, elapsed, auction.end_time - auction.start_time)
+                .ok_or(Error::ArithmeticOverflow)?;
+            Ok(U256::from(price).max(order.dst_amount))
+        }
+
+        /// Latest timelock boundary a live order has passed, with the time it was crossed
+        fn risk_boundary(&self, order: &FusionOrder, current_time: Timestamp) -> Option<(RiskBoundary, Timestamp)> {
+            let time_locks = &order.time_locks;
+            match order.status {
+                OrderStatus::Pending if current_time > time_locks.fill_deadline => {
+                    Some((RiskBoundary::FillDeadline, time_locks.fill_deadline.saturating_add(1)))
+                }
+                OrderStatus::Locked | OrderStatus::PartialFill => match time_locks.src_stage(current_time) {
+                    SrcStage::PublicCancellation => {
+                        Some((RiskBoundary::PublicCancellation, time_locks.src_public_cancellation))
+                    }
+                    SrcStage::PrivateCancellation => Some((RiskBoundary::Cancellation, time_locks.src_cancellation)),
+                    _ => None,
+                },
+                _ => None,
             }
+        }
 
-            // Execute partial fill
-            order.filled_amount = order.filled_amount.checked_add(fill_amount).ok_or(Error::ArithmeticOverflow)?;
-            
-            if order.filled_amount >= order.src_amount {
-                order.status = OrderStatus::Executed;
-                self.active_hash_locks.remove(order.hash_lock_info.hash_lock);
+        /// Why a locked order is being cancelled: a third party cancelling in public cancellation
+        /// means the resolver timed out and its deposit is slashed; the resolver's own exit (a
+        /// swap that failed, say the secret was never shared) keeps `TimelockExpired`
+        fn locked_cancel_reason(&self, order: &FusionOrder, caller: AccountId, current_time: Timestamp) -> CancelReason {
+            let third_party = caller != order.maker && Some(self.resolver_identity(caller)) != order.resolver;
+            if third_party && order.time_locks.src_stage(current_time) == SrcStage::PublicCancellation {
+                CancelReason::ResolverTimeout
             } else {
-                order.status = OrderStatus::PartialFill;
+                CancelReason::TimelockExpired
             }
+        }
 
-            self.orders.insert(order_hash, &order);
-
-            let remaining_amount = order.src_amount.checked_sub(order.filled_amount).ok_or(Error::ArithmeticOverflow)?;
-
-            self.env().emit_event(PartialFillExecuted {
-                order_hash,
-                filled_amount: order.filled_amount,
-                remaining_amount,
-            });
+        /// Whether a cancellation is due to the assigned resolver not completing in time
+        fn resolver_failed(&self, order: &FusionOrder, current_time: Timestamp) -> bool {
+            matches!(order.status, OrderStatus::Locked | OrderStatus::PartialFill)
+                && current_time >= order.time_locks.src_cancellation
+        }
 
-            Ok(())
+        fn underwriter_fee_share(&self, order_hash: [u8; 32], resolver_fee: Balance) -> Result<Balance, Error> {
+            match self.underwritings.get(order_hash) {
+                Some(underwriting) => math::bps_of(resolver_fee, underwriting.fee_share_bps)
+                    .ok_or(Error::ArithmeticOverflow),
+                None => Ok(0),
+            }
         }
 
-        /// Arbitrary calls (resolver compatibility)
-        #[ink(message)]
-        pub fn arbitrary_calls(
+        /// Release underwriting collateral: back to the underwriter (plus fee share), or to
+        /// the maker as compensation when the resolver failed
+        fn settle_underwriting(
             &mut self,
-            targets: Vec<AccountId>,
-            arguments: Vec<Vec<u8>>,
+            order_hash: [u8; 32],
+            maker: AccountId,
+            resolver_failed: bool,
+            fee_share: Balance,
         ) -> Result<(), Error> {
-            self.ensure_owner()?;
-            
-            if targets.len() != arguments.len() {
-                return Err(Error::LengthMismatch);
-            }
+            self.underwriter_approvals.remove(order_hash);
+            let Some(underwriting) = self.underwritings.take(order_hash) else {
+                return Ok(());
+            };
 
-            for (target, args) in targets.iter().zip(arguments.iter()) {
-                // Execute cross-contract call (simplified)
-                let _result = self.execute_arbitrary_call(*target, args);
-                // Continue even if one call fails
+            let (returned, paid_to_maker) = if resolver_failed {
+                (0, underwriting.collateral)
+            } else {
+                (underwriting.collateral, 0)
+            };
+            let to_underwriter = returned.checked_add(fee_share).ok_or(Error::ArithmeticOverflow)?;
+
+            if to_underwriter > 0 {
+                self.queue_payout(None, underwriting.underwriter, to_underwriter)?;
+            }
+            if paid_to_maker > 0 {
+                self.queue_payout(None, maker, paid_to_maker)?;
             }
+            self.adjust_ledger(None, LedgerEntry::Deposits, underwriting.collateral, false);
+
+            self.env().emit_event(UnderwritingSettled {
+                order_hash,
+                underwriter: underwriting.underwriter,
+                returned_to_underwriter: returned,
+                paid_to_maker,
+                fee_share,
+            });
 
             Ok(())
         }
 
-        // --- View Functions ---
+        /// Split the unreleased safety deposit of a resolver that let the order time out and mark
+        /// it released: the public canceller's `cancellation_tip_bps` (never to the order's own
+        /// parties), then the maker's share of the rest, the remainder to the fee vault
+        fn split_timed_out_deposit(
+            &self,
+            order: &mut FusionOrder,
+            caller: AccountId,
+        ) -> Result<Option<TimedOutDeposit>, Error> {
+            let Some(resolver) = order.resolver else {
+                return Ok(None);
+            };
+            let unreleased = order.safety_deposit.saturating_sub(order.deposit_released);
+            let tip = if caller == order.maker || Some(self.resolver_identity(caller)) == order.resolver {
+                0
+            } else {
+                math::bps_of(order.safety_deposit, self.cancellation_tip_bps)
+                    .ok_or(Error::ArithmeticOverflow)?
+                    .min(unreleased)
+            };
+            let slashed = unreleased - tip;
+            let to_maker = math::bps_of(slashed, self.slash_maker_share_bps).ok_or(Error::ArithmeticOverflow)?;
 
-        #[ink(message)]
-        pub fn get_order(&self, order_hash: [u8; 32]) -> Option<FusionOrder> {
-            self.orders.get(order_hash)
+            order.deposit_released = order.safety_deposit;
+            Ok(Some(TimedOutDeposit { resolver, tip, to_maker, to_treasury: slashed - to_maker }))
         }
 
-        #[ink(message)]
-        pub fn get_order_by_hash_lock(&self, hash_lock: [u8; 32]) -> Option<[u8; 32]> {
-            self.active_hash_locks.get(hash_lock)
+        /// Pay out a `split_timed_out_deposit` once the cancelled order is committed
+        fn pay_timed_out_deposit(
+            &mut self,
+            order: &FusionOrder,
+            caller: AccountId,
+            deposit: TimedOutDeposit,
+        ) -> Result<(), Error> {
+            if deposit.tip > 0 {
+                self.pay_safety_deposit(order, caller, deposit.tip)?;
+                self.env().emit_event(KeeperPaid {
+                    keeper: caller,
+                    order_hash: order.order_hash,
+                    action: OrderAction::CancelOrder,
+                    amount: deposit.tip,
+                });
+            }
+            if deposit.to_maker == 0 && deposit.to_treasury == 0 {
+                return Ok(());
+            }
+            self.adjust_ledger(order.safety_deposit_token, LedgerEntry::Deposits, deposit.to_treasury, false);
+            self.accrue_protocol_fee(order.safety_deposit_token, deposit.to_treasury)?;
+            self.pay_safety_deposit(order, order.maker, deposit.to_maker)?;
+
+            self.env().emit_event(SafetyDepositSlashed {
+                order_hash: order.order_hash,
+                resolver: deposit.resolver,
+                to_maker: deposit.to_maker,
+                to_treasury: deposit.to_treasury,
+            });
+            Ok(())
         }
 
-        #[ink(message)]
-        pub fn get_escrow_address(&self, order_hash: [u8; 32]) -> Option<AccountId> {
-            self.escrow_addresses.get(order_hash)
+        /// Pay out (part of) an order's safety deposit in the asset it was posted in
+        fn pay_safety_deposit(
+            &mut self,
+            order: &FusionOrder,
+            to: AccountId,
+            amount: Balance,
+        ) -> Result<(), Error> {
+            if amount == 0 {
+                return Ok(());
+            }
+            self.queue_payout(order.safety_deposit_token, to, amount)?;
+            self.adjust_ledger(order.safety_deposit_token, LedgerEntry::Deposits, amount, false);
+            Ok(())
         }
 
-        #[ink(message)]
-        pub fn get_escrow_immutables(&self, order_hash: [u8; 32]) -> Option<EscrowImmutables> {
-            let order = self.orders.get(order_hash)?;
-            
+        /// Source escrow immutables of `order` as recorded when it was locked; `None` before a
+        /// hash lock is set
+        fn escrow_immutables(order_hash: [u8; 32], order: &FusionOrder) -> Option<EscrowImmutables> {
             Some(EscrowImmutables {
                 order_hash,
-                hash_lock: order.hash_lock_info.hash_lock,
+                hash_lock: order.hash_lock_info.hash_lock.hash()?,
                 maker: order.maker,
                 taker: order.taker.unwrap_or(order.maker),
                 token: order.src_token,
                 amount: order.src_amount,
                 safety_deposit: order.safety_deposit,
-                timelocks: order.time_locks,
-                deployed_at: Some(order.created_at),
+                timelocks: order.time_locks.clone(),
+                deployed_at: order.locked_at,
             })
         }
 
-        #[ink(message)]
-        pub fn is_resolver_approved(&self, resolver: AccountId) -> bool {
-            self.approved_resolvers.get(resolver).unwrap_or(false)
-        }
-
-        #[ink(message)]
-        pub fn get_total_volume(&self) -> Balance {
-            self.total_volume
-        }
-
-        #[ink(message)]
-        pub fn get_total_escrows_created(&self) -> u64 {
-            self.total_escrows_created
-        }
-
-        #[ink(message)]
-        pub fn is_paused(&self) -> bool {
-            self.paused
-        }
-
-        #[ink(message)]
-        pub fn get_owner(&self) -> AccountId {
-            self.owner
+        fn compute_escrow_address(&self, immutables: &EscrowImmutables) -> Result<AccountId, Error> {
+            // Same salt as the Ethereum factory's CREATE2 (see `create2`)
+            let salt = create2::escrow_salt(&immutables.create2());
+            Ok(create2::polkadot_escrow_address(&self.env().account_id(), &salt))
         }
 
-        #[ink(message)]
-        pub fn get_ethereum_resolver(&self) -> [u8; 20] {
-            self.ethereum_resolver
+        /// Last instant a valid secret may still execute the order
+        fn execution_cutoff(&self, order: &FusionOrder) -> Timestamp {
+            order.time_locks.fill_deadline.saturating_add(self.execution_grace_period)
         }
 
-        // --- Helper Functions ---
-
-        fn ensure_owner(&self) -> Result<(), Error> {
-            if self.env().caller() != self.owner {
-                return Err(Error::OnlyOwner);
+        /// Stage start times for `order` locked at `locked_at`: cancellation opens right after
+        /// the execution cutoff and withdrawal must open before it
+        fn lock_time_locks(&self, order: &FusionOrder, locked_at: Timestamp) -> Result<TimeLocks, Error> {
+            let delays = match &order.size_tier {
+                Some(tier) => tier.stage_delays.clone(),
+                None => self.params_for(&order.direction).stage_delays,
+            };
+            let src_cancellation = self.execution_cutoff(order).saturating_add(1);
+            let src_withdrawal = locked_at.saturating_add(delays.src_withdrawal);
+            let dst_cancellation = locked_at.saturating_add(delays.dst_cancellation);
+            // The counterpart escrow must refund its resolver before the maker can be refunded here
+            if src_withdrawal >= src_cancellation || dst_cancellation > src_cancellation {
+                return Err(Error::InvalidTimelocks);
             }
-            Ok(())
+
+            Ok(TimeLocks {
+                src_withdrawal,
+                src_public_withdrawal: locked_at.saturating_add(delays.src_public_withdrawal).min(src_cancellation),
+                src_cancellation,
+                src_public_cancellation: src_cancellation.saturating_add(delays.src_public_cancellation),
+                dst_withdrawal: locked_at.saturating_add(delays.dst_withdrawal),
+                dst_public_withdrawal: locked_at.saturating_add(delays.dst_public_withdrawal),
+                dst_cancellation,
+                ..order.time_locks.clone()
+            })
         }
 
-        fn ensure_not_paused(&self) -> Result<(), Error> {
-            if self.paused {
-                return Err(Error::ContractPaused);
+        fn validate_stage_delays(delays: &StageDelays) -> Result<(), Error> {
+            if delays.src_withdrawal > delays.src_public_withdrawal
+                || delays.dst_withdrawal > delays.dst_public_withdrawal
+                || delays.dst_public_withdrawal > delays.dst_cancellation
+            {
+                return Err(Error::InvalidTimelocks);
             }
             Ok(())
         }
 
-        fn calculate_protocol_fee(&self, amount: Balance) -> Result<Balance, Error> {
-            amount.checked_mul(self.protocol_fee_bps as u128)
-                .and_then(|v| v.checked_div(10000))
-                .ok_or(Error::ArithmeticOverflow)
-        }
-
-        fn compute_escrow_address(&self, immutables: &EscrowImmutables) -> Result<AccountId, Error> {
-            // Deterministic address computation similar to 1inch CREATE2
-            let seed_data = (
-                &immutables.order_hash,
-                &immutables.hash_lock,
-                &immutables.maker,
-                &immutables.taker,
-                immutables.amount,
-                immutables.deployed_at.unwrap_or(0),
-            );
-            let encoded = scale::Encode::encode(&seed_data);
-            let hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&encoded);
-            
-            // Convert hash to AccountId (simplified)
-            let mut account_bytes = [0u8; 32];
-            account_bytes.copy_from_slice(&hash);
-            Ok(AccountId::from(account_bytes))
-        }
-
         fn check_withdrawal_timelock(
             &self,
             order: &FusionOrder,
+            executor: AccountId,
             current_time: Timestamp,
         ) -> Result<(), Error> {
-            // Allow withdrawal before deadline
-            if current_time <= order.time_locks.fill_deadline {
-                return Ok(());
+            // Resolver-only after the finality lock, then any secret holder until cancellation opens
+            let time_locks = &order.time_locks;
+            match time_locks.src_stage(current_time) {
+                SrcStage::FinalityLock => Err(Error::TimelockNotExpiredV2 {
+                    unlocks_at: time_locks.src_withdrawal,
+                    current_time,
+                }),
+                SrcStage::PrivateWithdrawal if Some(executor) != order.resolver => Err(Error::OnlyResolver),
+                SrcStage::PrivateWithdrawal | SrcStage::PublicWithdrawal => Ok(()),
+                SrcStage::PrivateCancellation | SrcStage::PublicCancellation => Err(Error::DeadlineExpiredV2 {
+                    deadline: time_locks.src_cancellation.saturating_sub(1),
+                    current_time,
+                }),
             }
-            
-            Err(Error::DeadlineExpired)
         }
 
         fn check_cancellation_timelock(
             &self,
             order: &FusionOrder,
+            caller: AccountId,
             current_time: Timestamp,
         ) -> Result<(), Error> {
-            // Allow cancellation after private cancellation period or after deadline
-            if current_time > order.time_locks.private_cancellation || 
-               current_time > order.time_locks.fill_deadline {
-                return Ok(());
+            // Resolver-only once the secret can no longer withdraw, then anyone
+            let time_locks = &order.time_locks;
+            match time_locks.src_stage(current_time) {
+                SrcStage::PublicCancellation => Ok(()),
+                SrcStage::PrivateCancellation if Some(caller) == order.resolver => Ok(()),
+                SrcStage::PrivateCancellation => Err(Error::OnlyResolver),
+                SrcStage::FinalityLock | SrcStage::PrivateWithdrawal | SrcStage::PublicWithdrawal => {
+                    // Report when cancellation opens for this caller, not for the resolver
+                    let unlocks_at = if Some(caller) == order.resolver {
+                        time_locks.src_cancellation
+                    } else {
+                        time_locks.src_public_cancellation
+                    };
+                    Err(Error::TimelockNotExpiredV2 { unlocks_at, current_time })
+                }
             }
-            
-            Err(Error::TimelockNotExpired)
         }
 
         fn execute_arbitrary_call(
@@ -0,0 +1,30 @@
+//! Runtime-native balance transfer through a chain extension, for chains where
+//! `seal_transfer` to some account types is restricted
+
+use ink::env::chain_extension::{ChainExtensionMethod, FromStatusCode};
+use ink::primitives::AccountId;
+use scale::{Decode, Encode};
+
+/// Status code the runtime returned for a failed transfer (0 is success)
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct TransferError(pub u32);
+
+impl FromStatusCode for TransferError {
+    fn from_status_code(status_code: u32) -> Result<(), Self> {
+        match status_code {
+            0 => Ok(()),
+            code => Err(Self(code)),
+        }
+    }
+}
+
+/// Move `value` of the native balance from the calling contract to `to` through the
+/// runtime's `transfer(dest, value)` chain-extension function `func_id`
+pub fn transfer(func_id: u32, to: AccountId, value: u128) -> Result<(), TransferError> {
+    ChainExtensionMethod::build(func_id)
+        .input::<(AccountId, u128)>()
+        .output::<(), false>()
+        .handle_error_code::<TransferError>()
+        .call(&(to, value))
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+//! ERC-1271-style signature checks for makers that are contracts (smart-contract wallets)
+
+use ink::env::call::{build_call, ExecutionInput, Selector};
+use ink::env::DefaultEnvironment;
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+use crate::fusion_polkadot_escrow::GasBudget;
+
+/// ERC-1271 `isValidSignature` selector, also the value returned on success
+pub const MAGIC_VALUE: [u8; 4] = [0x16, 0x26, 0xba, 0x7e];
+
+/// Implemented by contract wallets that can authorize orders on a maker's behalf
+#[ink::trait_definition]
+pub trait SignatureValidator {
+    /// Returns `MAGIC_VALUE` iff `signature` authorizes `hash` for this contract
+    #[ink(message, selector = 0x1626ba7e)]
+    fn is_valid_signature(&self, hash: [u8; 32], signature: Vec<u8>) -> [u8; 4];
+}
+
+/// Ask `wallet` whether `signature` authorizes `hash`; any failure counts as invalid
+pub fn is_valid_signature(wallet: AccountId, hash: [u8; 32], signature: Vec<u8>, gas: GasBudget) -> bool {
+    build_call::<DefaultEnvironment>()
+        .call(wallet)
+        .ref_time_limit(gas.ref_time)
+        .proof_size_limit(gas.proof_size)
+        .exec_input(
+            ExecutionInput::new(Selector::new(MAGIC_VALUE))
+                .push_arg(hash)
+                .push_arg(signature),
+        )
+        .returns::<[u8; 4]>()
+        .try_invoke()
+        .map(|res| res == Ok(MAGIC_VALUE))
+        .unwrap_or(false)
+}
\ No newline at end of file
@@ -0,0 +1,94 @@
+//! E2E: with an access token configured, only resolvers holding enough of it may lock orders.
+//!
+//! Run with `cargo test --features e2e-tests` against a running contracts node.
+#![cfg(feature = "e2e-tests")]
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, Error, OrderStatus, ResolverParams};
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{FusionPolkadotEscrow, FusionPolkadotEscrowRef};
+use ink_e2e::{AccountKeyring, ContractsBackend};
+use malicious_recipient::malicious_recipient::{Behavior, MaliciousRecipient, MaliciousRecipientRef};
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const SRC_AMOUNT: u128 = 1_000_000;
+const MIN_ACCESS_BALANCE: u128 = 100;
+
+/// `common::order_params()` sized for the node, `fill_deadline` needs the duration bounds widened
+fn e2e_order_params() -> CreateOrderParams {
+    CreateOrderParams { src_amount: SRC_AMOUNT, fill_deadline: u64::MAX / 2, ..order_params() }
+}
+
+fn bob_params() -> ResolverParams {
+    ResolverParams { resolver: ink_e2e::account_id(AccountKeyring::Bob), ..resolver_params([0xaa; 32]) }
+}
+
+#[ink_e2e::test]
+async fn resolver_needs_the_access_token_to_lock<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+    // Honest token fixture standing in for the access token
+    let mut token_ctor = MaliciousRecipientRef::new(Behavior::Accept);
+    let token = client
+        .instantiate("malicious_recipient", &ink_e2e::alice(), &mut token_ctor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+    let mut token_call = token.call_builder::<MaliciousRecipient>();
+
+    let mut escrow_ctor = FusionPolkadotEscrowRef::new(0, 1, 1, [0u8; 20]);
+    let escrow = client
+        .instantiate("fusion_polkadot_escrow", &ink_e2e::alice(), &mut escrow_ctor)
+        .submit()
+        .await
+        .expect("escrow instantiate failed");
+    let mut escrow_call = escrow.call_builder::<FusionPolkadotEscrow>();
+
+    client.call(&ink_e2e::alice(), &escrow_call.set_allow_all_dst_tokens(true)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.approve_resolver(ink_e2e::account_id(AccountKeyring::Bob))).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_order_duration_bounds(1, u64::MAX)).submit().await?;
+    client
+        .call(&ink_e2e::alice(), &escrow_call.set_access_token(Some(token.account_id), MIN_ACCESS_BALANCE))
+        .submit()
+        .await?;
+
+    let requirement = client.call(&ink_e2e::alice(), &escrow_call.get_access_token()).dry_run().await?.return_value();
+    assert_eq!(requirement, (Some(token.account_id), MIN_ACCESS_BALANCE));
+
+    let order = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params()))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create order failed");
+
+    // Bob holds just short of the requirement
+    client
+        .call(&ink_e2e::alice(), &token_call.transfer(ink_e2e::account_id(AccountKeyring::Bob), MIN_ACCESS_BALANCE - 1, Vec::new()))
+        .submit()
+        .await?;
+    let rejected = client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order, bob_params()))
+        .value(10)
+        .dry_run()
+        .await?;
+    assert_eq!(
+        rejected.return_value(),
+        Err(Error::AccessTokenRequired { required: MIN_ACCESS_BALANCE, held: MIN_ACCESS_BALANCE - 1 }),
+    );
+
+    client
+        .call(&ink_e2e::alice(), &token_call.transfer(ink_e2e::account_id(AccountKeyring::Bob), 1, Vec::new()))
+        .submit()
+        .await?;
+    client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order, bob_params()))
+        .value(10)
+        .submit()
+        .await?;
+    let locked = client.call(&ink_e2e::alice(), &escrow_call.get_order(order)).dry_run().await?.return_value();
+    assert_eq!(locked.expect("order").status, OrderStatus::Locked);
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+//! Fixture vector pinning the Ethereum log attestation layout shared with the Solidity side.
+//!
+//! Expected digest is `keccak256(abi.encode(uint256(1), address(0x11..11), bytes32(0x22..22),
+//! uint256(7), bytes32(0x33..33)))`, computed outside this crate.
+
+use fusion_polkadot_escrow::attestation::{EthereumLogAttestation, ATTESTATION_SIZE};
+
+fn unhex<const N: usize>(s: &str) -> [u8; N] {
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).expect("hex");
+    }
+    out
+}
+
+fn fixture() -> EthereumLogAttestation {
+    EthereumLogAttestation {
+        chain_id: 1,
+        emitter: [0x11; 20],
+        tx_hash: [0x22; 32],
+        log_index: 7,
+        data_hash: [0x33; 32],
+    }
+}
+
+#[test]
+fn preimage_is_abi_encoded_words() {
+    let preimage = fixture().preimage();
+    assert_eq!(preimage.len(), ATTESTATION_SIZE);
+    assert_eq!(preimage[31], 1);
+    assert_eq!(preimage[32..44], [0u8; 12]);
+    assert_eq!(preimage[44..64], [0x11; 20]);
+    assert_eq!(preimage[64..96], [0x22; 32]);
+    assert_eq!(preimage[127], 7);
+    assert_eq!(preimage[128..], [0x33; 32]);
+}
+
+#[test]
+fn digest_matches_solidity_abi_encode() {
+    assert_eq!(
+        fixture().digest(),
+        unhex::<32>("e89d65c85dc43c7a42e4cfe39c12c0007b5b5a924b6c60153fc5c76bf27a3d46"),
+    );
+}
\ No newline at end of file
@@ -0,0 +1,98 @@
+//! E2E: a relayer-initiated refund returns every basket leg to the maker alongside the native
+//! source amount.
+//!
+//! Run with `cargo test --features e2e-tests` against a running contracts node.
+#![cfg(feature = "e2e-tests")]
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{BasketLeg, CreateOrderParams, OrderStatus, ResolverParams};
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{FusionPolkadotEscrow, FusionPolkadotEscrowRef};
+use ink_e2e::{AccountKeyring, ContractsBackend};
+use malicious_recipient::malicious_recipient::{Behavior, MaliciousRecipient, MaliciousRecipientRef};
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const SRC_AMOUNT: u128 = 1_000_000;
+const BASKET_AMOUNT: u128 = 250_000;
+
+/// `common::order_params()` sized for the node with `basket`; `fill_deadline` needs the duration
+/// bounds widened
+fn e2e_order_params(basket: Vec<BasketLeg>) -> CreateOrderParams {
+    CreateOrderParams { src_amount: SRC_AMOUNT, fill_deadline: u64::MAX / 2, basket, ..order_params() }
+}
+
+fn bob_params() -> ResolverParams {
+    ResolverParams { resolver: ink_e2e::account_id(AccountKeyring::Bob), ..resolver_params([0xaa; 32]) }
+}
+
+#[ink_e2e::test]
+async fn relayer_refund_returns_the_basket_to_the_maker<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+    // Honest token fixture standing in for the basket leg
+    let mut token_ctor = MaliciousRecipientRef::new(Behavior::Accept);
+    let token = client
+        .instantiate("malicious_recipient", &ink_e2e::alice(), &mut token_ctor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+    let token_call = token.call_builder::<MaliciousRecipient>();
+
+    let mut escrow_ctor = FusionPolkadotEscrowRef::new(0, 1, 1, [0u8; 20]);
+    let escrow = client
+        .instantiate("fusion_polkadot_escrow", &ink_e2e::alice(), &mut escrow_ctor)
+        .submit()
+        .await
+        .expect("escrow instantiate failed");
+    let mut escrow_call = escrow.call_builder::<FusionPolkadotEscrow>();
+
+    client.call(&ink_e2e::alice(), &escrow_call.set_allow_all_dst_tokens(true)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.approve_resolver(ink_e2e::account_id(AccountKeyring::Bob))).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_order_duration_bounds(1, u64::MAX)).submit().await?;
+    for relayer in [AccountKeyring::Charlie, AccountKeyring::Dave] {
+        client.call(&ink_e2e::alice(), &escrow_call.add_trusted_relayer(ink_e2e::account_id(relayer))).submit().await?;
+    }
+    client.call(&ink_e2e::alice(), &escrow_call.set_relayer_quorum(2)).submit().await?;
+
+    let basket = vec![BasketLeg { token: token.account_id, amount: BASKET_AMOUNT }];
+    let order = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params(basket)))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create order failed");
+    client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order, bob_params()))
+        .value(10)
+        .submit()
+        .await?;
+
+    // The second trusted report reaches the quorum and refunds both legs
+    let first = client
+        .call(&ink_e2e::charlie(), &escrow_call.relayer_initiated_refund(order, b"tx reverted".to_vec()))
+        .submit()
+        .await?
+        .return_value();
+    assert_eq!(first, Ok(false));
+    let second = client
+        .call(&ink_e2e::dave(), &escrow_call.relayer_initiated_refund(order, Vec::new()))
+        .submit()
+        .await?
+        .return_value();
+    assert_eq!(second, Ok(true));
+    let refunded = client.call(&ink_e2e::alice(), &escrow_call.get_order(order)).dry_run().await?.return_value();
+    assert_eq!(refunded.expect("order").status, OrderStatus::Cancelled);
+
+    let alice = ink_e2e::account_id(AccountKeyring::Alice);
+    let owed = client.call(&ink_e2e::alice(), &escrow_call.get_claimable(alice, None)).dry_run().await?;
+    assert_eq!(owed.return_value(), SRC_AMOUNT);
+    let owed = client.call(&ink_e2e::alice(), &escrow_call.get_claimable(alice, Some(token.account_id))).dry_run().await?;
+    assert_eq!(owed.return_value(), BASKET_AMOUNT);
+
+    let claimed = client.call(&ink_e2e::alice(), &escrow_call.claim(Some(token.account_id))).submit().await?.return_value();
+    assert_eq!(claimed, Ok(BASKET_AMOUNT));
+    assert_eq!(client.call(&ink_e2e::alice(), &token_call.balance_of(alice)).dry_run().await?.return_value(), BASKET_AMOUNT);
+
+    Ok(())
+}
\ No newline at end of file
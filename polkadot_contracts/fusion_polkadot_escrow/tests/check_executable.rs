@@ -0,0 +1,105 @@
+//! `check_executable` runs the guards of the execution it dry-runs: for every row it reports
+//! exactly what `execute_swap` or `execute_partial_fill` then returns for the same caller.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    EscrowImmutables, Error, FusionPolkadotEscrow, MakerSignature, TimeLocks, MAX_PARTIAL_FILLS,
+};
+use fusion_polkadot_escrow::nonces;
+use ink::env::test;
+use ink::primitives::AccountId;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+/// Order locked by `resolver()` through `deploy_escrow`, which records the Ethereum escrow
+fn locked_order(escrow: &mut FusionPolkadotEscrow) -> [u8; 32] {
+    let order_hash = create_order(escrow, order_params());
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params(blake2x256(&SECRET))).expect("lock");
+    order_hash
+}
+
+/// Order of an ECDSA maker locked by `resolver()` through `deploy_src`, which leaves the
+/// Ethereum escrow unset
+fn order_locked_by_deploy_src(escrow: &mut FusionPolkadotEscrow) -> [u8; 32] {
+    let key = SecretKey::from_slice(&[0x4b; 32]).expect("secret key");
+    let maker = AccountId::from(blake2x256(&PublicKey::from_secret_key(&Secp256k1::new(), &key).serialize()));
+    test::set_account_balance::<Env>(maker, STARTING_BALANCE);
+    call_as(maker, SRC_AMOUNT, CREATED_AT);
+    let order_hash = escrow.create_order(order_params()).expect("create order");
+
+    let digest = nonces::digest(contract(), order_hash, 0);
+    let message = Message::from_slice(&digest).expect("digest");
+    let (recovery_id, rs) = Secp256k1::new().sign_ecdsa_recoverable(&message, &key).serialize_compact();
+    let mut signature = [0u8; 65];
+    signature[..64].copy_from_slice(&rs);
+    signature[64] = recovery_id.to_i32() as u8;
+
+    let immutables = EscrowImmutables {
+        order_hash,
+        hash_lock: blake2x256(&SECRET),
+        maker,
+        taker: resolver(),
+        token: native(),
+        amount: SRC_AMOUNT,
+        safety_deposit: SAFETY_DEPOSIT,
+        timelocks: TimeLocks::default(),
+        deployed_at: None,
+    };
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_src(immutables, order_hash, MakerSignature::Ecdsa(signature), 0, Vec::new()).expect("deploy src");
+    order_hash
+}
+
+/// Order filled `MAX_PARTIAL_FILLS` times, one unit at a time
+fn order_at_the_fill_cap(escrow: &mut FusionPolkadotEscrow) -> [u8; 32] {
+    let order_hash = locked_order(escrow);
+    let src_withdrawal = escrow.get_order(order_hash).expect("order").time_locks.src_withdrawal;
+    for _ in 0..MAX_PARTIAL_FILLS {
+        call_as(resolver(), 0, src_withdrawal);
+        escrow.execute_partial_fill(order_hash, 1, SECRET).expect("partial fill");
+    }
+    order_hash
+}
+
+/// Sets up an order and returns its hash
+type Setup = fn(&mut FusionPolkadotEscrow) -> [u8; 32];
+
+/// (name, setup, caller, amount, what both the dry run and the execution return)
+type Case = (&'static str, Setup, AccountId, u128, Result<(), Error>);
+
+#[test]
+fn dry_runs_report_what_the_execution_returns() {
+    let whole = SRC_AMOUNT;
+    let cases: [Case; 7] = [
+        ("whole order", locked_order, resolver(), whole, Ok(())),
+        ("partial fill", locked_order, resolver(), whole / 2, Ok(())),
+        ("partial fill by a stranger", locked_order, stranger(), whole / 2, Err(Error::OnlyResolver)),
+        ("empty partial fill", locked_order, resolver(), 0, Err(Error::InvalidAmount)),
+        ("fill past the cap", order_at_the_fill_cap, resolver(), 1, Err(Error::TooManyFills)),
+        ("whole order without the Ethereum escrow", order_locked_by_deploy_src, resolver(), whole, Err(Error::EthereumEscrowNotSet)),
+        ("partial fill without the Ethereum escrow", order_locked_by_deploy_src, resolver(), whole / 2, Err(Error::EthereumEscrowNotSet)),
+    ];
+    for (name, setup, caller, amount, expected) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow(SAFETY_DEPOSIT);
+            let order_hash = setup(&mut escrow);
+            let order = escrow.get_order(order_hash).expect("order");
+            let remaining = order.src_amount - order.filled_amount;
+
+            call_as(caller, 0, order.time_locks.src_withdrawal);
+            assert_eq!(escrow.check_executable(order_hash, blake2x256(&SECRET), amount), expected, "{name}: dry run");
+            let executed = if amount < remaining || order.filled_amount > 0 {
+                escrow.execute_partial_fill(order_hash, amount, SECRET)
+            } else {
+                escrow.execute_swap(order_hash, SECRET)
+            };
+            assert_eq!(executed, expected, "{name}: execution");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
@@ -0,0 +1,71 @@
+//! Under pull payment a settlement only moves state: the executed order is committed, every
+//! participant's share becomes claimable and leaves the contract through `claim`, or `claim_to`
+//! another destination.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, FusionPolkadotEscrow, HashAlgo, OrderStatus, TransferBackend};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+#[test]
+fn executed_swap_is_claimed_not_pushed() {
+    test::run_test::<Env, _>(|_| {
+        fund_accounts();
+        call_as(owner(), 0, CREATED_AT);
+        let mut escrow =
+            FusionPolkadotEscrow::new_with_transfer_backend(0, SAFETY_DEPOSIT, 1, [0u8; 20], TransferBackend::PullPayment);
+        escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+        escrow.approve_resolver(resolver()).expect("approve");
+        let order_hash = create_order(&mut escrow, order_params());
+
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+        escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+
+        call_as(resolver(), 0, CREATED_AT);
+        let before = balance(resolver());
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        assert_eq!(balance(resolver()), before);
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Executed);
+        assert_eq!(escrow.get_claimable(resolver(), None), SRC_AMOUNT + SAFETY_DEPOSIT);
+        assert_eq!(escrow.get_claimable(maker(), None), 0);
+
+        let ledger = escrow.get_asset_ledger(None);
+        assert_eq!((ledger.escrowed, ledger.deposits, ledger.payouts), (0, 0, SRC_AMOUNT + SAFETY_DEPOSIT));
+
+        assert_eq!(escrow.claim(None), Ok(SRC_AMOUNT + SAFETY_DEPOSIT));
+        assert_eq!(balance(resolver()) - before, SRC_AMOUNT + SAFETY_DEPOSIT);
+        assert_eq!(escrow.claim(None), Ok(0));
+        assert_eq!(escrow.reconcile(None).expect("reconcile").ledger.total(), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn claimable_balance_can_be_sent_elsewhere() {
+    test::run_test::<Env, _>(|_| {
+        fund_accounts();
+        call_as(owner(), 0, CREATED_AT);
+        let mut escrow =
+            FusionPolkadotEscrow::new_with_transfer_backend(0, SAFETY_DEPOSIT, 1, [0u8; 20], TransferBackend::PullPayment);
+        escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+        let order_hash = create_order(&mut escrow, order_params());
+        call_as(maker(), 0, CREATED_AT);
+        escrow.cancel_order(order_hash).expect("cancel");
+
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.retry_payout(maker(), None), Err(Error::Unauthorized));
+
+        let before = balance(stranger());
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(escrow.claim_to(None, stranger()), Ok(SRC_AMOUNT));
+        assert_eq!(balance(stranger()) - before, SRC_AMOUNT);
+        assert_eq!(escrow.get_claimable(maker(), None), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
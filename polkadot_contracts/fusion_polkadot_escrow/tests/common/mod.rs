@@ -0,0 +1,124 @@
+//! Accounts, clock and order defaults shared by the off-chain tests. Each test crate uses its
+//! own subset.
+#![allow(dead_code)]
+
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, ExternalAddress, FusionPolkadotEscrow, HashAlgo, ResolverParams, SwapDirection,
+    NATIVE_SRC_TOKEN,
+};
+use fusion_polkadot_escrow::U256;
+use ink::env::{test, DefaultEnvironment};
+use ink::primitives::AccountId;
+
+pub type Env = DefaultEnvironment;
+
+pub const STARTING_BALANCE: u128 = 1_000_000_000;
+pub const SRC_AMOUNT: u128 = 100_000;
+pub const SECRET: [u8; 32] = [0x5e; 32];
+
+pub const MINUTE: u64 = 60 * 1000;
+pub const CREATED_AT: u64 = 1_700_000_000_000;
+pub const FILL_DEADLINE: u64 = CREATED_AT + 60 * MINUTE;
+
+pub fn owner() -> AccountId {
+    AccountId::from([0x01; 32])
+}
+
+pub fn maker() -> AccountId {
+    AccountId::from([0x02; 32])
+}
+
+pub fn resolver() -> AccountId {
+    AccountId::from([0x03; 32])
+}
+
+pub fn stranger() -> AccountId {
+    AccountId::from([0x04; 32])
+}
+
+pub fn contract() -> AccountId {
+    AccountId::from([0xc0; 32])
+}
+
+/// Source token id of native orders
+pub fn native() -> AccountId {
+    AccountId::from(NATIVE_SRC_TOKEN)
+}
+
+/// Next call comes from `caller` at `at`, transferring `value` into the contract
+pub fn call_as(caller: AccountId, value: u128, at: u64) {
+    test::set_caller::<Env>(caller);
+    test::set_callee::<Env>(contract());
+    test::set_block_timestamp::<Env>(at);
+    test::transfer_in::<Env>(value);
+}
+
+pub fn balance(account: AccountId) -> u128 {
+    test::get_account_balance::<Env>(account).unwrap_or(0)
+}
+
+pub fn blake2x256(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(input, &mut output);
+    output
+}
+
+/// Funds the test accounts and leaves the contract empty
+pub fn fund_accounts() {
+    for account in [owner(), maker(), resolver(), stranger()] {
+        test::set_account_balance::<Env>(account, STARTING_BALANCE);
+    }
+    test::set_account_balance::<Env>(contract(), 0);
+}
+
+/// Fresh fee-free contract accepting any destination token, with `resolver()` approved
+pub fn escrow(min_safety_deposit: u128) -> FusionPolkadotEscrow {
+    fund_accounts();
+    call_as(owner(), 0, CREATED_AT);
+    let mut escrow = FusionPolkadotEscrow::new(0, min_safety_deposit, 1, [0u8; 20]);
+    escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+    escrow.approve_resolver(resolver()).expect("approve");
+    escrow
+}
+
+/// Plain native Polkadot -> Ethereum order of `SRC_AMOUNT`; override fields with
+/// `CreateOrderParams { .., ..order_params() }`
+pub fn order_params() -> CreateOrderParams {
+    CreateOrderParams {
+        direction: SwapDirection::PolkadotToEthereum,
+        src_token: native(),
+        dst_token: [0xdd; 20],
+        src_amount: SRC_AMOUNT,
+        min_dst_amount: U256::from(1u128),
+        fill_deadline: FILL_DEADLINE,
+        recipient: ExternalAddress::Evm([0xee; 20]),
+        max_resolver_fee: 0,
+        metadata: Vec::new(),
+        min_resolver_reputation: None,
+        late_fee_schedule: None,
+        basket: Vec::new(),
+        encrypted_memo: None,
+        blocked_resolvers: Vec::new(),
+        executor_tip: None,
+        hash_algo: HashAlgo::Blake2x256,
+        preferred_resolver: None,
+        auction: None,
+    }
+}
+
+/// `resolver()` locking for itself with no fee and no token deposit
+pub fn resolver_params(hash_lock: [u8; 32]) -> ResolverParams {
+    ResolverParams {
+        resolver: resolver(),
+        hash_lock,
+        ethereum_escrow_address: [0xab; 20],
+        resolver_fee: 0,
+        token_safety_deposit: 0,
+    }
+}
+
+/// Creates `params` as `maker()` at `CREATED_AT`, attaching its native `src_amount`
+pub fn create_order(escrow: &mut FusionPolkadotEscrow, params: CreateOrderParams) -> [u8; 32] {
+    call_as(maker(), params.src_amount, CREATED_AT);
+    escrow.create_order(params).expect("create order")
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+//! Fixture vectors pinning `create2` to the Ethereum escrow factory's CREATE2 derivation.
+//!
+//! Expected values were produced with `ImmutablesLib.hashMem` / `Create2.computeAddress`
+//! semantics (abi-encoded immutables, keccak256) outside this crate. Escrows the contract
+//! deploys sit at the address derived from the immutables it reports for them.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::create2;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    EscrowImmutables, FusionPolkadotEscrow, MakerSignature, TimeLocks,
+};
+use fusion_polkadot_escrow::nonces;
+use ink::env::test;
+use ink::primitives::AccountId;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+const DEPLOYED_AT_MS: u64 = 1_700_000_000_000;
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+fn unhex<const N: usize>(s: &str) -> [u8; N] {
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).expect("hex");
+    }
+    out
+}
+
+/// Ethereum address left-padded into an account word
+fn eth_account(byte: u8) -> AccountId {
+    let mut account = [0u8; 32];
+    account[12..].copy_from_slice(&[byte; 20]);
+    AccountId::from(account)
+}
+
+fn fixture() -> EscrowImmutables {
+    EscrowImmutables {
+        order_hash: [0x11; 32],
+        hash_lock: [0x22; 32],
+        maker: eth_account(0xaa),
+        taker: eth_account(0xbb),
+        token: eth_account(0xcc),
+        amount: 1_000_000_000_000_000_000,
+        safety_deposit: 10_000_000_000_000_000,
+        timelocks: TimeLocks {
+            fill_deadline: DEPLOYED_AT_MS + 3_600_000,
+            private_cancellation: DEPLOYED_AT_MS + 1_800_000,
+            src_cancellation: DEPLOYED_AT_MS + 1_800_000,
+            src_public_cancellation: DEPLOYED_AT_MS + 3_600_000,
+            ..Default::default()
+        },
+        deployed_at: Some(DEPLOYED_AT_MS),
+    }
+}
+
+#[test]
+fn timelocks_pack_like_timelocks_lib() {
+    let immutables = fixture();
+    assert_eq!(
+        create2::pack_timelocks(&immutables.timelocks.stage_starts(), DEPLOYED_AT_MS),
+        unhex::<32>("6553f10000000000000000000000000000000e10000007080000000000000000"),
+    );
+}
+
+#[test]
+fn preimage_follows_immutables_field_order() {
+    let preimage = create2::immutables_preimage(&fixture().create2());
+    assert_eq!(preimage.len(), create2::ESCROW_IMMUTABLES_SIZE);
+    assert_eq!(preimage[..32], [0x11; 32]);
+    assert_eq!(preimage[32..64], [0x22; 32]);
+    assert_eq!(preimage[64..76], [0u8; 12]);
+    assert_eq!(preimage[76..96], [0xaa; 20]);
+    assert_eq!(preimage[108..128], [0xbb; 20]);
+    assert_eq!(preimage[140..160], [0xcc; 20]);
+}
+
+#[test]
+fn salt_matches_immutables_lib_hash() {
+    assert_eq!(
+        create2::escrow_salt(&fixture().create2()),
+        unhex::<32>("0d732735a2bd2df25793de3137faa9dfcc78728ef0b0dc0a3873ea1fdf475269"),
+    );
+}
+
+#[test]
+fn ethereum_address_matches_create2_compute_address() {
+    let salt = create2::escrow_salt(&fixture().create2());
+    assert_eq!(
+        create2::ethereum_escrow_address(&[0x33; 20], &[0x44; 32], &salt),
+        unhex::<20>("13a1f60cc83f86ca7007748b4aa81d4c25190d15"),
+    );
+}
+
+#[test]
+fn any_immutable_changes_the_salt() {
+    let base = create2::escrow_salt(&fixture().create2());
+    let mut changed = fixture();
+    changed.safety_deposit += 1;
+    assert_ne!(create2::escrow_salt(&changed.create2()), base);
+}
+/// Locks the order as `resolver()`, the maker's key at hand
+type Deploy = fn(&mut FusionPolkadotEscrow, [u8; 32], &SecretKey);
+
+/// `deploy_src` and `deploy_escrow` both put the escrow at the address its reported immutables
+/// derive, locked at the current block by the calling resolver
+#[test]
+fn escrow_addresses_derive_from_the_reported_immutables() {
+    let lock_at = CREATED_AT + MINUTE;
+    let key = SecretKey::from_slice(&[0x4b; 32]).expect("secret key");
+    let ecdsa_maker = AccountId::from(blake2x256(&PublicKey::from_secret_key(&Secp256k1::new(), &key).serialize()));
+
+    let deploys: [(&str, AccountId, Deploy); 2] = [
+        ("deploy_escrow", maker(), |escrow, order_hash, _| {
+            escrow.deploy_escrow(order_hash, resolver_params(blake2x256(&SECRET))).expect("deploy escrow");
+        }),
+        ("deploy_src", ecdsa_maker, |escrow, order_hash, key| {
+            let digest = nonces::digest(contract(), order_hash, 0);
+            let message = Message::from_slice(&digest).expect("digest");
+            let (recovery_id, rs) = Secp256k1::new().sign_ecdsa_recoverable(&message, key).serialize_compact();
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&rs);
+            signature[64] = recovery_id.to_i32() as u8;
+            let immutables = EscrowImmutables {
+                order_hash,
+                hash_lock: blake2x256(&SECRET),
+                maker: escrow.get_order(order_hash).expect("order").maker,
+                taker: resolver(),
+                token: native(),
+                amount: SRC_AMOUNT,
+                safety_deposit: SAFETY_DEPOSIT,
+                timelocks: TimeLocks::default(),
+                deployed_at: None,
+            };
+            escrow.deploy_src(immutables, order_hash, MakerSignature::Ecdsa(signature), 0, Vec::new()).expect("deploy src");
+        }),
+    ];
+    for (name, order_maker, deploy) in deploys {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow(SAFETY_DEPOSIT);
+            test::set_account_balance::<Env>(order_maker, STARTING_BALANCE);
+            call_as(order_maker, SRC_AMOUNT, CREATED_AT);
+            let order_hash = escrow.create_order(order_params()).expect("create order");
+            call_as(resolver(), SAFETY_DEPOSIT, lock_at);
+            deploy(&mut escrow, order_hash, &key);
+
+            let immutables = escrow.get_escrow_immutables(order_hash).expect("immutables");
+            assert_eq!((immutables.taker, immutables.deployed_at), (resolver(), Some(lock_at)), "{name}");
+            assert_eq!(
+                escrow.get_escrow_address(order_hash),
+                Some(create2::polkadot_escrow_address(&contract(), &escrow.get_escrow_salt(immutables))),
+                "{name}",
+            );
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
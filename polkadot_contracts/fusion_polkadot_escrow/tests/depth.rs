@@ -0,0 +1,131 @@
+//! `get_depth` aggregates pending orders of one pair by implied price, bucketed to a tick and
+//! cheapest ask first, one page of the open-order index at a time.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, DepthLevel, FusionPolkadotEscrow, MAX_PAGE_LEN};
+use fusion_polkadot_escrow::U256;
+use ink::env::test;
+
+const UNIT_SCALE: u128 = 100_000_000; // 10 src decimals to 18 dst decimals
+const PRICE_ONE: u128 = 1_000_000_000_000_000_000;
+const DST_TOKEN: [u8; 20] = [0xdd; 20];
+
+/// Order selling `src_amount` at `price` whole dst tokens per whole src token
+fn create_priced_order(
+    escrow: &mut FusionPolkadotEscrow,
+    dst_token: [u8; 20],
+    src_amount: u128,
+    price: u128,
+) -> [u8; 32] {
+    create_order(
+        escrow,
+        CreateOrderParams {
+            dst_token,
+            src_amount,
+            min_dst_amount: U256::from(src_amount * UNIT_SCALE * price),
+            ..order_params()
+        },
+    )
+}
+
+#[test]
+fn levels_aggregate_by_price_cheapest_first() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        create_priced_order(&mut escrow, DST_TOKEN, 1_000, 3);
+        create_priced_order(&mut escrow, DST_TOKEN, 2_000, 2);
+        create_priced_order(&mut escrow, DST_TOKEN, 500, 2);
+        create_priced_order(&mut escrow, [0xaa; 20], 4_000, 1); // Other pair
+
+        assert_eq!(
+            escrow.get_depth(native(), DST_TOKEN, 10, 0, 0, MAX_PAGE_LEN),
+            vec![
+                DepthLevel {
+                    price: 2 * PRICE_ONE,
+                    src_amount: 2_500,
+                    dst_amount: U256::from(2_500 * UNIT_SCALE * 2),
+                    order_count: 2,
+                },
+                DepthLevel {
+                    price: 3 * PRICE_ONE,
+                    src_amount: 1_000,
+                    dst_amount: U256::from(1_000 * UNIT_SCALE * 3),
+                    order_count: 1,
+                },
+            ],
+        );
+
+        let best = escrow.get_depth(native(), DST_TOKEN, 1, 0, 0, MAX_PAGE_LEN);
+        assert_eq!(best.len(), 1);
+        assert_eq!(best[0].price, 2 * PRICE_ONE);
+        assert!(escrow.get_depth(native(), DST_TOKEN, 0, 0, 0, MAX_PAGE_LEN).is_empty());
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn nearby_prices_share_a_tick() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        create_order(
+            &mut escrow,
+            CreateOrderParams {
+                dst_token: DST_TOKEN,
+                src_amount: 1_000,
+                min_dst_amount: U256::from(1_000 * UNIT_SCALE * 2 + 1_000 * UNIT_SCALE / 10), // 2.1
+                ..order_params()
+            },
+        );
+        create_priced_order(&mut escrow, DST_TOKEN, 500, 2);
+        create_priced_order(&mut escrow, DST_TOKEN, 2_000, 3);
+
+        assert_eq!(escrow.get_depth(native(), DST_TOKEN, 10, 0, 0, MAX_PAGE_LEN).len(), 3);
+        let depth = escrow.get_depth(native(), DST_TOKEN, 10, PRICE_ONE, 0, MAX_PAGE_LEN);
+        let levels: Vec<(u128, u128, u32)> =
+            depth.iter().map(|level| (level.price, level.src_amount, level.order_count)).collect();
+        assert_eq!(levels, vec![(2 * PRICE_ONE, 1_500, 2), (3 * PRICE_ONE, 2_000, 1)]);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn cancelled_and_expired_orders_leave_the_book() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        let cancelled = create_priced_order(&mut escrow, DST_TOKEN, 1_000, 2);
+        create_priced_order(&mut escrow, DST_TOKEN, 1_000, 3);
+
+        call_as(maker(), 0, CREATED_AT);
+        escrow.cancel_order(cancelled).expect("cancel");
+        let depth = escrow.get_depth(native(), DST_TOKEN, 10, 0, 0, MAX_PAGE_LEN);
+        assert_eq!(depth.len(), 1);
+        assert_eq!(depth[0].price, 3 * PRICE_ONE);
+
+        call_as(maker(), 0, FILL_DEADLINE + 1);
+        assert!(escrow.get_depth(native(), DST_TOKEN, 10, 0, 0, MAX_PAGE_LEN).is_empty());
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn each_page_of_the_open_index_is_scanned_on_its_own() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        create_priced_order(&mut escrow, DST_TOKEN, 1_000, 2);
+        create_priced_order(&mut escrow, DST_TOKEN, 2_000, 2);
+        create_priced_order(&mut escrow, DST_TOKEN, 500, 3);
+
+        let first = escrow.get_depth(native(), DST_TOKEN, 10, 0, 0, 1);
+        assert_eq!((first.len(), first[0].src_amount), (1, 1_000));
+        let rest = escrow.get_depth(native(), DST_TOKEN, 10, 0, 1, 2);
+        assert_eq!(rest.iter().map(|level| level.src_amount).collect::<Vec<_>>(), vec![2_000, 500]);
+        assert!(escrow.get_depth(native(), DST_TOKEN, 10, 0, 3, MAX_PAGE_LEN).is_empty());
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
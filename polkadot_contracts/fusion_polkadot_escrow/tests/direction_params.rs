@@ -0,0 +1,54 @@
+//! Per-direction overrides replace only the fields they set; everything else follows the global
+//! settings, including later changes to them.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{DirectionOverrides, Error, SwapDirection};
+use ink::env::test;
+
+#[test]
+fn unset_fields_follow_the_global_settings() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1_000);
+        call_as(owner(), 0, CREATED_AT);
+        escrow
+            .set_direction_params(
+                SwapDirection::EthereumToPolkadot,
+                DirectionOverrides { taker_fee_bps: Some(25), ..Default::default() },
+            )
+            .expect("override");
+        escrow.set_maker_fee_bps(40).expect("maker fee");
+
+        let params = escrow.get_direction_params(SwapDirection::EthereumToPolkadot);
+        assert_eq!((params.maker_fee_bps, params.taker_fee_bps, params.min_safety_deposit), (40, 25, 1_000));
+        let other = escrow.get_direction_params(SwapDirection::PolkadotToEthereum);
+        assert_eq!((other.maker_fee_bps, other.taker_fee_bps), (40, 0));
+
+        // An empty override clears it
+        escrow
+            .set_direction_params(SwapDirection::EthereumToPolkadot, DirectionOverrides::default())
+            .expect("clear");
+        assert_eq!(escrow.get_direction_params(SwapDirection::EthereumToPolkadot), other);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn overrides_are_validated() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1_000);
+        let overrides = DirectionOverrides { maker_fee_bps: Some(10_001), ..Default::default() };
+
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(
+            escrow.set_direction_params(SwapDirection::PolkadotToEthereum, DirectionOverrides::default()),
+            Err(Error::OnlyOwner)
+        );
+        call_as(owner(), 0, CREATED_AT);
+        assert_eq!(escrow.set_direction_params(SwapDirection::PolkadotToEthereum, overrides), Err(Error::InvalidAmount));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
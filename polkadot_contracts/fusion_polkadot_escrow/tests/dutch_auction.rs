@@ -0,0 +1,190 @@
+//! Dutch-auction orders: the destination amount decays from the start to the end amount until a
+//! resolver takes the order at the current price. That price is kept through settlement; it
+//! prices the destination leg, which this contract cannot check, so execution never depends on
+//! it.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, DutchAuction, Error, FusionPolkadotEscrow, HashAlgo, HashPreimage, SwapDirection,
+};
+use fusion_polkadot_escrow::U256;
+use ink::env::test;
+use scale::Encode;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const START_AMOUNT: u128 = 2_000;
+const END_AMOUNT: u128 = 1_000;
+
+const AUCTION_START: u64 = CREATED_AT + 10 * MINUTE;
+const AUCTION_END: u64 = CREATED_AT + 50 * MINUTE;
+
+fn auction() -> DutchAuction {
+    DutchAuction {
+        start_amount: U256::from(START_AMOUNT),
+        end_amount: U256::from(END_AMOUNT),
+        start_time: AUCTION_START,
+        end_time: AUCTION_END,
+    }
+}
+
+fn create_auction_order(escrow: &mut FusionPolkadotEscrow, auction: Option<DutchAuction>) -> Result<[u8; 32], Error> {
+    create_directed_order(escrow, SwapDirection::PolkadotToEthereum, auction)
+}
+
+fn create_directed_order(
+    escrow: &mut FusionPolkadotEscrow,
+    direction: SwapDirection,
+    auction: Option<DutchAuction>,
+) -> Result<[u8; 32], Error> {
+    call_as(maker(), SRC_AMOUNT, CREATED_AT);
+    escrow.create_order(CreateOrderParams {
+        direction,
+        min_dst_amount: U256::from(END_AMOUNT),
+        auction,
+        ..order_params()
+    })
+}
+
+fn lock(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32], at: u64) {
+    call_as(resolver(), SAFETY_DEPOSIT, at);
+    let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+    escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+}
+
+#[test]
+fn price_decays_linearly_between_the_auction_bounds() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        let order_hash = create_auction_order(&mut escrow, Some(auction())).expect("create order");
+        let fixed_hash = create_auction_order(&mut escrow, None).expect("create fixed order");
+
+        let cases = [
+            ("before the start", CREATED_AT, START_AMOUNT),
+            ("at the start", AUCTION_START, START_AMOUNT),
+            ("quarter way", AUCTION_START + 10 * MINUTE, 1_750),
+            ("half way", AUCTION_START + 20 * MINUTE, 1_500),
+            ("at the end", AUCTION_END, END_AMOUNT),
+            ("past the end", FILL_DEADLINE, END_AMOUNT),
+        ];
+        for (name, at, expected) in cases {
+            call_as(maker(), 0, at);
+            assert_eq!(escrow.get_current_dst_amount(order_hash), Some(U256::from(expected)), "{name}");
+            assert_eq!(escrow.get_current_dst_amount(fixed_hash), Some(U256::from(END_AMOUNT)), "{name}");
+        }
+        assert_eq!(escrow.get_current_dst_amount([0x99; 32]), None);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn the_price_is_fixed_when_the_order_is_taken() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        let order_hash = create_auction_order(&mut escrow, Some(auction())).expect("create order");
+        lock(&mut escrow, order_hash, AUCTION_START + 20 * MINUTE);
+
+        // Neither a locked nor a settled order's price moves any more
+        call_as(maker(), 0, AUCTION_END);
+        assert_eq!(escrow.get_current_dst_amount(order_hash), Some(U256::from(1_500u128)));
+        call_as(resolver(), 0, AUCTION_END);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        let order = escrow.get_order(order_hash).expect("order");
+        assert_eq!(order.taken_dst_amount, Some(U256::from(1_500u128)));
+        assert_eq!(order.dst_amount, U256::from(END_AMOUNT));
+        assert_eq!(escrow.get_current_dst_amount(order_hash), Some(U256::from(1_500u128)));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn a_taken_order_still_hashes_from_its_preimage() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        let order_hash = create_auction_order(&mut escrow, Some(auction())).expect("create order");
+        lock(&mut escrow, order_hash, AUCTION_START);
+
+        let Some(HashPreimage::Root(preimage)) = escrow.get_hash_preimage(order_hash) else {
+            panic!("root order preimage");
+        };
+        assert_eq!(preimage.min_dst_amount, U256::from(END_AMOUNT));
+        assert_eq!(blake2x256(&preimage.encode()), order_hash);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn ethereum_to_polkadot_orders_execute_whatever_price_they_were_taken_at() {
+    let auction = DutchAuction {
+        start_amount: U256::from(SRC_AMOUNT * 2),
+        end_amount: U256::from(SRC_AMOUNT / 2),
+        ..auction()
+    };
+    let cases = [
+        ("taken above the source amount", AUCTION_START),
+        ("taken once the price decayed", AUCTION_END),
+    ];
+    for (name, locked_at) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow(SAFETY_DEPOSIT);
+            let order_hash = create_directed_order(&mut escrow, SwapDirection::EthereumToPolkadot, Some(auction.clone()))
+                .expect("create order");
+            lock(&mut escrow, order_hash, locked_at);
+
+            call_as(resolver(), 0, locked_at + MINUTE);
+            assert_eq!(escrow.execute_swap(order_hash, SECRET), Ok(()), "{name}");
+            assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT, "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn split_children_share_the_auction() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        let order_hash = create_auction_order(&mut escrow, Some(auction())).expect("create order");
+
+        call_as(maker(), 0, CREATED_AT);
+        let children = escrow.split_order(order_hash, 3).expect("split");
+        let shares: Vec<_> = children
+            .iter()
+            .map(|child| escrow.get_order(*child).expect("child").auction.expect("auction"))
+            .map(|auction| (auction.start_amount, auction.end_amount))
+            .collect();
+        assert_eq!(
+            shares,
+            vec![
+                (U256::from(666u128), U256::from(333u128)),
+                (U256::from(666u128), U256::from(333u128)),
+                (U256::from(668u128), U256::from(334u128)),
+            ],
+        );
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn auction_bounds_are_validated() {
+    let cases = [
+        ("rising price", DutchAuction { start_amount: U256::from(500u128), ..auction() }, Error::InvalidAmount),
+        ("below the minimum", DutchAuction { end_amount: U256::from(999u128), ..auction() }, Error::InvalidAmount),
+        ("beyond u128", DutchAuction { start_amount: U256::MAX, ..auction() }, Error::InvalidAmount),
+        ("empty window", DutchAuction { end_time: AUCTION_START, ..auction() }, Error::InvalidTimelocks),
+        ("past the deadline", DutchAuction { end_time: FILL_DEADLINE + 1, ..auction() }, Error::InvalidTimelocks),
+    ];
+    for (name, auction, expected) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow(SAFETY_DEPOSIT);
+            assert_eq!(create_auction_order(&mut escrow, Some(auction)), Err(expected), "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+//! Commit-reveal execution: only the order's resolver may claim the exclusive window, a live
+//! claim is never replaced or extended, and no claim reaches into the cancellation stages.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, Error, FusionPolkadotEscrow};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+/// Fresh contract with one order locked by the approved resolver
+fn escrow_with_locked_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    escrow.approve_resolver(stranger()).expect("approve");
+    let order_hash = create_order(&mut escrow, CreateOrderParams { src_amount: 10_000, ..order_params() });
+
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params([0x42; 32])).expect("lock");
+    (escrow, order_hash)
+}
+
+#[test]
+fn only_the_orders_resolver_commits_and_never_twice_while_live() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.commit_execution(order_hash, [0x11; 32]), Err(Error::OnlyResolver));
+
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.commit_execution(order_hash, [0x11; 32]).expect("commit");
+        let first = escrow.get_execution_commitment(order_hash).expect("commitment");
+        call_as(resolver(), 0, CREATED_AT + 1);
+        assert_eq!(escrow.commit_execution(order_hash, [0x22; 32]), Err(Error::ExecutionCommitted));
+        assert_eq!(escrow.get_execution_commitment(order_hash), Some(first));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn exclusivity_ends_at_src_cancellation() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+        let src_cancellation = escrow.get_order(order_hash).expect("order").time_locks.src_cancellation;
+
+        call_as(owner(), 0, CREATED_AT);
+        escrow.set_execution_exclusivity(u64::MAX / 2).expect("exclusivity");
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.commit_execution(order_hash, [0x11; 32]).expect("commit");
+        assert_eq!(
+            escrow.get_execution_commitment(order_hash).map(|commitment| commitment.exclusive_until),
+            Some(src_cancellation)
+        );
+
+        call_as(resolver(), 0, src_cancellation);
+        assert_eq!(escrow.commit_execution(order_hash, [0x22; 32]), Err(Error::DeadlineExpired));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
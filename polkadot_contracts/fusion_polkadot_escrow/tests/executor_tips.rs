@@ -0,0 +1,56 @@
+//! Executor tips: the maker gets the unspent budget back when the order settles, less one tip
+//! kept for whoever archives it.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, ExecutorTip, DEFAULT_ARCHIVE_RETENTION};
+use ink::env::test;
+
+const PER_ACTION: u128 = 100;
+const BUDGET: u128 = 350;
+
+fn tipped_params() -> CreateOrderParams {
+    CreateOrderParams {
+        executor_tip: Some(ExecutorTip { per_action: PER_ACTION, remaining: BUDGET }),
+        ..order_params()
+    }
+}
+
+#[test]
+fn cancellation_refunds_all_but_the_archival_tip() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        call_as(maker(), SRC_AMOUNT + BUDGET, CREATED_AT);
+        let order_hash = escrow.create_order(tipped_params()).expect("create order");
+
+        escrow.cancel_order(order_hash).expect("cancel");
+        assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT + BUDGET - PER_ACTION);
+        assert_eq!(escrow.get_executor_tip(order_hash).map(|tip| tip.remaining), Some(PER_ACTION));
+
+        call_as(stranger(), 0, CREATED_AT + DEFAULT_ARCHIVE_RETENTION);
+        escrow.archive_order(order_hash).expect("archive");
+        assert_eq!(escrow.get_claimable(stranger(), None), PER_ACTION);
+        assert_eq!(escrow.get_asset_ledger(None).tips, 0);
+        assert_eq!(escrow.reconcile(None).expect("reconcile").shortfall, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn the_maker_archiving_gets_the_reserve_back() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        call_as(maker(), SRC_AMOUNT + BUDGET, CREATED_AT);
+        let order_hash = escrow.create_order(tipped_params()).expect("create order");
+
+        escrow.cancel_order(order_hash).expect("cancel");
+        call_as(maker(), 0, CREATED_AT + DEFAULT_ARCHIVE_RETENTION);
+        escrow.archive_order(order_hash).expect("archive");
+        assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT + BUDGET);
+        assert_eq!(escrow.get_asset_ledger(None).tips, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
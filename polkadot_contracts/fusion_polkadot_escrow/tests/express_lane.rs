@@ -0,0 +1,147 @@
+//! Size-tiered express lane: small orders get the tier's deposit and timelocks at creation,
+//! orders above every tier keep the direction's parameters.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    BasketLeg, CreateOrderParams, Error, FusionPolkadotEscrow, SizeTier, StageDelays,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const STRICT_DEPOSIT: u128 = 10_000;
+const EXPRESS_DEPOSIT: u128 = 100;
+const EXPRESS_MAX_AMOUNT: u128 = 50_000;
+
+const STRICT_PRIVATE_CANCELLATION: u64 = CREATED_AT + 30 * MINUTE; // Default per-direction period
+const EXPRESS_PRIVATE_CANCELLATION: u64 = CREATED_AT + 5 * MINUTE;
+
+fn express_tier() -> SizeTier {
+    SizeTier {
+        max_src_amount: EXPRESS_MAX_AMOUNT,
+        min_safety_deposit: EXPRESS_DEPOSIT,
+        private_cancellation_period: EXPRESS_PRIVATE_CANCELLATION - CREATED_AT,
+        stage_delays: StageDelays::default(),
+    }
+}
+
+/// Fresh contract with one express tier and the strict deposit as the global minimum
+fn escrow() -> FusionPolkadotEscrow {
+    let mut escrow = common::escrow(STRICT_DEPOSIT);
+    escrow.set_size_tiers(vec![express_tier()]).expect("size tiers");
+    escrow
+}
+
+fn create_sized_order(escrow: &mut FusionPolkadotEscrow, src_amount: u128) -> [u8; 32] {
+    create_order(escrow, CreateOrderParams { src_amount, ..order_params() })
+}
+
+fn deploy_escrow(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32], deposit: u128) -> Result<(), Error> {
+    call_as(resolver(), deposit, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params([0x42; 32]))
+}
+
+#[test]
+fn orders_take_the_tier_they_fit_at_creation() {
+    let cases = [
+        ("at the tier bound", EXPRESS_MAX_AMOUNT, Some(express_tier()), EXPRESS_DEPOSIT, EXPRESS_PRIVATE_CANCELLATION),
+        ("above every tier", EXPRESS_MAX_AMOUNT + 1, None, STRICT_DEPOSIT, STRICT_PRIVATE_CANCELLATION),
+    ];
+    for (name, src_amount, tier, required, private_cancellation) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow();
+            let order_hash = create_sized_order(&mut escrow, src_amount);
+            let order = escrow.get_order(order_hash).expect("order");
+            assert_eq!(order.size_tier, tier, "{name}");
+            assert_eq!(order.time_locks.private_cancellation, private_cancellation, "{name}");
+
+            assert_eq!(
+                deploy_escrow(&mut escrow, order_hash, required - 1),
+                Err(Error::InsufficientDepositV2 { required, provided: required - 1 }),
+                "{name}",
+            );
+            assert_eq!(deploy_escrow(&mut escrow, order_hash, required), Ok(()), "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn retiering_leaves_existing_orders_alone() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        let order_hash = create_sized_order(&mut escrow, EXPRESS_MAX_AMOUNT);
+
+        call_as(owner(), 0, CREATED_AT);
+        escrow.set_size_tiers(Vec::new()).expect("clear tiers");
+        assert_eq!(escrow.get_size_tier(native(), EXPRESS_MAX_AMOUNT, Vec::new()), None);
+        assert_eq!(deploy_escrow(&mut escrow, order_hash, EXPRESS_DEPOSIT), Ok(()));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn tiers_must_ascend() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        call_as(owner(), 0, CREATED_AT);
+        let cases = [
+            ("zero bound", vec![SizeTier { max_src_amount: 0, ..express_tier() }], Error::InvalidAmount),
+            ("not ascending", vec![express_tier(), express_tier()], Error::InvalidAmount),
+            ("too many", vec![express_tier(); 5], Error::InvalidLength),
+            (
+                "inverted stages",
+                vec![SizeTier {
+                    stage_delays: StageDelays { src_withdrawal: MINUTE, ..Default::default() },
+                    ..express_tier()
+                }],
+                Error::InvalidTimelocks,
+            ),
+        ];
+        for (name, tiers, expected) in cases {
+            assert_eq!(escrow.set_size_tiers(tiers), Err(expected), "{name}");
+        }
+        assert_eq!(escrow.get_size_tiers(), vec![express_tier()]);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+/// Tier bounds count DOT planck (10 decimals): other decimals are rescaled first
+#[test]
+fn tiers_compare_amounts_in_native_decimals() {
+    let cases = [
+        ("6 decimals, at the bound", 6, EXPRESS_MAX_AMOUNT / 10_000, true),
+        ("6 decimals, raw bound", 6, EXPRESS_MAX_AMOUNT, false),
+        ("12 decimals, at the bound", 12, EXPRESS_MAX_AMOUNT * 100, true),
+        ("12 decimals, above the bound", 12, EXPRESS_MAX_AMOUNT * 100 + 100, false),
+    ];
+    for (name, decimals, src_amount, express) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow();
+            escrow.set_src_token_decimals(native(), decimals).expect("decimals");
+            let order_hash = create_sized_order(&mut escrow, src_amount);
+            let tier = escrow.get_order(order_hash).expect("order").size_tier;
+            assert_eq!(tier, express.then(express_tier), "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn basket_legs_count_toward_the_tier() {
+    test::run_test::<Env, _>(|_| {
+        let escrow = escrow();
+        let leg = |amount| vec![BasketLeg { token: AccountId::from([0x77; 32]), amount }];
+        let half = EXPRESS_MAX_AMOUNT / 2;
+        assert_eq!(escrow.get_size_tier(native(), half, leg(half)), Some(express_tier()));
+        assert_eq!(escrow.get_size_tier(native(), half, leg(half + 1)), None);
+        assert_eq!(escrow.get_size_tier(native(), half, leg(u128::MAX)), None);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,81 @@
+//! Fee exemption: an exempt maker skips the maker fee and an exempt resolver the taker fee,
+//! everyone else still pays, and only the owner grants or revokes an exemption.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, FusionPolkadotEscrow};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const MAKER_FEE_BPS: u32 = 30;
+const TAKER_FEE_BPS: u32 = 20;
+
+/// Execute one plain order with both protocol fees set, after `setup` ran as the owner, and
+/// return the protocol fees it accrued
+fn protocol_fees_after(setup: fn(&mut FusionPolkadotEscrow)) -> u128 {
+    let mut accrued = None;
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        escrow.set_maker_fee_bps(MAKER_FEE_BPS).expect("maker fee");
+        escrow.set_taker_fee_bps(TAKER_FEE_BPS).expect("taker fee");
+        setup(&mut escrow);
+        let order_hash = create_order(&mut escrow, order_params());
+
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        escrow.deploy_escrow(order_hash, resolver_params(blake2x256(&SECRET))).expect("lock");
+        let src_withdrawal = escrow.get_order(order_hash).expect("order").time_locks.src_withdrawal;
+        call_as(resolver(), 0, src_withdrawal);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        accrued = Some(escrow.get_accrued_fees(None));
+        Ok(())
+    })
+    .expect("off-chain env");
+    accrued.expect("executed")
+}
+
+#[test]
+fn exempt_parties_skip_their_own_fee() {
+    let maker_fee = SRC_AMOUNT * MAKER_FEE_BPS as u128 / 10_000;
+    let taker_fee = SRC_AMOUNT * TAKER_FEE_BPS as u128 / 10_000;
+
+    assert_eq!(protocol_fees_after(|_| ()), maker_fee + taker_fee);
+    assert_eq!(protocol_fees_after(|escrow| escrow.add_fee_exemption(maker()).expect("exempt")), taker_fee);
+    assert_eq!(protocol_fees_after(|escrow| escrow.add_fee_exemption(resolver()).expect("exempt")), maker_fee);
+    assert_eq!(
+        protocol_fees_after(|escrow| {
+            escrow.add_fee_exemption(maker()).expect("exempt");
+            escrow.add_fee_exemption(resolver()).expect("exempt");
+        }),
+        0,
+    );
+    assert_eq!(
+        protocol_fees_after(|escrow| {
+            escrow.add_fee_exemption(maker()).expect("exempt");
+            escrow.remove_fee_exemption(maker()).expect("revoke");
+        }),
+        maker_fee + taker_fee,
+    );
+}
+
+#[test]
+fn only_the_owner_manages_exemptions() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(0);
+        escrow.add_fee_exemption(maker()).expect("exempt");
+        assert!(escrow.is_fee_exempt(maker()));
+        assert!(!escrow.is_fee_exempt(resolver()));
+
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.add_fee_exemption(stranger()), Err(Error::OnlyOwner));
+        assert_eq!(escrow.remove_fee_exemption(maker()), Err(Error::OnlyOwner));
+        assert!(escrow.is_fee_exempt(maker()));
+        assert!(!escrow.is_fee_exempt(stranger()));
+
+        call_as(owner(), 0, CREATED_AT);
+        escrow.remove_fee_exemption(maker()).expect("revoke");
+        assert!(!escrow.is_fee_exempt(maker()));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
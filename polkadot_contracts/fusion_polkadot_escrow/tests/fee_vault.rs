@@ -0,0 +1,153 @@
+//! Protocol fees accrue into the vault (or fee recipients' shares) at execution and leave the
+//! contract only through `withdraw_fees` / `claim_fee_share`.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FeeRecipient, FusionPolkadotEscrow, HashAlgo, TransferBackend,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const MAKER_FEE_BPS: u32 = 100;
+const SRC_AMOUNT: u128 = 1_000_003;
+const PROTOCOL_FEE: u128 = 10_000; // 1% of SRC_AMOUNT, rounded down
+const SAFETY_DEPOSIT: u128 = 1_000;
+const TRANSFER_FUNC_ID: u32 = 0x0001_0001;
+
+fn treasury() -> AccountId {
+    AccountId::from([0x04; 32])
+}
+
+fn partner() -> AccountId {
+    AccountId::from([0x05; 32])
+}
+
+fn escrow() -> FusionPolkadotEscrow {
+    fund_accounts();
+    test::set_account_balance::<Env>(partner(), STARTING_BALANCE);
+
+    call_as(owner(), 0, CREATED_AT);
+    let mut escrow = FusionPolkadotEscrow::new(MAKER_FEE_BPS, SAFETY_DEPOSIT, 1, [0u8; 20]);
+    escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+    escrow.approve_resolver(resolver()).expect("approve");
+    escrow
+}
+
+/// Create, lock and execute one order, charging `PROTOCOL_FEE`
+fn settle_order(escrow: &mut FusionPolkadotEscrow) {
+    let order_hash = create_order(escrow, CreateOrderParams { src_amount: SRC_AMOUNT, ..order_params() });
+
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+    escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+
+    call_as(resolver(), 0, CREATED_AT);
+    escrow.execute_swap(order_hash, SECRET).expect("execute");
+}
+
+#[test]
+fn vault_holds_fees_until_withdrawn() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        settle_order(&mut escrow);
+        assert_eq!(escrow.get_accrued_fees(None), PROTOCOL_FEE);
+        assert_eq!(escrow.reconcile(None).expect("reconcile").ledger.fees, PROTOCOL_FEE);
+
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(escrow.withdraw_fees(maker(), None), Err(Error::OnlyOwner));
+
+        call_as(owner(), 0, CREATED_AT);
+        let before = balance(treasury());
+        assert_eq!(escrow.withdraw_fees(treasury(), None), Ok(PROTOCOL_FEE));
+        assert_eq!(balance(treasury()) - before, PROTOCOL_FEE);
+        assert_eq!(escrow.get_accrued_fees(None), 0);
+        assert_eq!(escrow.withdraw_fees(treasury(), None), Ok(0));
+
+        let reconciliation = escrow.reconcile(None).expect("reconcile");
+        assert_eq!(reconciliation.ledger.fees, 0);
+        assert_eq!(reconciliation.shortfall, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn fee_recipients_claim_their_shares() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        call_as(owner(), 0, CREATED_AT);
+        escrow
+            .set_fee_recipients(vec![
+                FeeRecipient { account: treasury(), share_bps: 7_000 },
+                FeeRecipient { account: partner(), share_bps: 3_000 },
+            ])
+            .expect("fee recipients");
+        settle_order(&mut escrow);
+
+        assert_eq!(escrow.get_accrued_fees(None), 0);
+        assert_eq!(escrow.get_accrued_fee_share(treasury(), None), 7_000);
+        assert_eq!(escrow.get_accrued_fee_share(partner(), None), 3_000);
+
+        for (recipient, share) in [(treasury(), 7_000), (partner(), 3_000)] {
+            call_as(recipient, 0, CREATED_AT);
+            let before = balance(recipient);
+            assert_eq!(escrow.claim_fee_share(None), Ok(share));
+            assert_eq!(balance(recipient) - before, share);
+        }
+        assert_eq!(escrow.reconcile(None).expect("reconcile").ledger.fees, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+/// Runtime transfer extension that rejects every transfer
+struct RejectingTransfers;
+
+impl test::ChainExtension for RejectingTransfers {
+    fn ext_id(&self) -> u16 {
+        (TRANSFER_FUNC_ID >> 16) as u16
+    }
+
+    fn call(&mut self, _func_id: u16, _input: &[u8], _output: &mut Vec<u8>) -> u32 {
+        1
+    }
+}
+
+#[test]
+fn failed_fee_payouts_stay_accrued() {
+    // (name, fee recipients, fees accrued to `partner()` rather than the vault)
+    let cases = [
+        ("vault", Vec::new(), false),
+        ("fee share", vec![FeeRecipient { account: partner(), share_bps: 10_000 }], true),
+    ];
+    for (name, recipients, shared) in cases {
+        test::run_test::<Env, _>(|_| {
+            fund_accounts();
+            test::set_account_balance::<Env>(partner(), STARTING_BALANCE);
+            test::register_chain_extension(RejectingTransfers);
+            call_as(owner(), 0, CREATED_AT);
+            let backend = TransferBackend::ChainExtension { func_id: TRANSFER_FUNC_ID };
+            let mut escrow =
+                FusionPolkadotEscrow::new_with_transfer_backend(MAKER_FEE_BPS, SAFETY_DEPOSIT, 1, [0u8; 20], backend);
+            escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+            escrow.approve_resolver(resolver()).expect("approve");
+            escrow.set_fee_recipients(recipients).expect("fee recipients");
+            settle_order(&mut escrow);
+
+            // The runtime refuses the transfer: the entry is restored
+            let (withdrawn, accrued) = if shared {
+                call_as(partner(), 0, CREATED_AT);
+                (escrow.claim_fee_share(None), escrow.get_accrued_fee_share(partner(), None))
+            } else {
+                call_as(owner(), 0, CREATED_AT);
+                (escrow.withdraw_fees(treasury(), None), escrow.get_accrued_fees(None))
+            };
+            assert_eq!(withdrawn, Err(Error::ProtocolTransferFailed), "{name}");
+            assert_eq!(accrued, PROTOCOL_FEE, "{name}");
+            assert_eq!(escrow.reconcile(None).expect("reconcile").ledger.fees, PROTOCOL_FEE, "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
@@ -0,0 +1,190 @@
+//! Fill receipts: zero-amount fills are refused, partial fills are bounded per order so the
+//! receipts can never outgrow storage, and the completing fill is always recorded. Each fill
+//! credits its share of the source funds, only the order's resolver fills, and a single fill of
+//! the whole order settles exactly what `execute_swap` does.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FusionPolkadotEscrow, LateFeeSchedule, OrderStatus, ResolverParams, SwapDirection,
+    MAX_PARTIAL_FILLS,
+};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const SRC_AMOUNT: u128 = 10_000;
+const RESOLVER_FEE: u128 = 400;
+const COLLATERAL: u128 = 5_000;
+
+/// Fresh contract with one order locked by the approved resolver
+fn escrow_with_locked_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    let order_hash = create_order(&mut escrow, CreateOrderParams { src_amount: SRC_AMOUNT, ..order_params() });
+
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params(blake2x256(&SECRET))).expect("lock");
+    (escrow, order_hash)
+}
+
+#[test]
+fn zero_fills_are_refused() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+        let src_withdrawal = escrow.get_order(order_hash).expect("order").time_locks.src_withdrawal;
+
+        call_as(resolver(), 0, src_withdrawal);
+        assert_eq!(escrow.execute_partial_fill(order_hash, 0, SECRET), Err(Error::InvalidAmount));
+        assert!(escrow.get_fill_receipts(order_hash).is_empty());
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn partial_fills_are_bounded_but_the_completing_fill_is_not() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+        let src_withdrawal = escrow.get_order(order_hash).expect("order").time_locks.src_withdrawal;
+
+        call_as(resolver(), 0, src_withdrawal);
+        for _ in 0..MAX_PARTIAL_FILLS {
+            escrow.execute_partial_fill(order_hash, 1, SECRET).expect("partial fill");
+        }
+        assert_eq!(escrow.execute_partial_fill(order_hash, 1, SECRET), Err(Error::TooManyFills));
+
+        let remaining = SRC_AMOUNT - MAX_PARTIAL_FILLS as u128;
+        escrow.execute_partial_fill(order_hash, remaining, SECRET).expect("completing fill");
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Executed);
+
+        let receipts = escrow.get_fill_receipts(order_hash);
+        assert_eq!(receipts.len(), MAX_PARTIAL_FILLS as usize + 1);
+        assert_eq!(receipts.last().map(|receipt| (receipt.fill_amount, receipt.total_filled)), Some((remaining, SRC_AMOUNT)));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn each_fill_credits_its_source_amount() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+        let src_withdrawal = escrow.get_order(order_hash).expect("order").time_locks.src_withdrawal;
+
+        call_as(stranger(), 0, src_withdrawal);
+        assert_eq!(escrow.execute_partial_fill(order_hash, 1, SECRET), Err(Error::OnlyResolver));
+
+        call_as(resolver(), 0, src_withdrawal);
+        escrow.execute_partial_fill(order_hash, SRC_AMOUNT / 4, SECRET).expect("partial fill");
+        assert_eq!(escrow.get_claimable(resolver(), None), SRC_AMOUNT / 4 + SAFETY_DEPOSIT / 4);
+        assert_eq!(escrow.get_asset_ledger(None).escrowed, SRC_AMOUNT - SRC_AMOUNT / 4);
+
+        escrow.execute_partial_fill(order_hash, SRC_AMOUNT - SRC_AMOUNT / 4, SECRET).expect("completing fill");
+        assert_eq!(escrow.get_claimable(resolver(), None), SRC_AMOUNT + SAFETY_DEPOSIT);
+        let reconciliation = escrow.reconcile(None).expect("reconcile");
+        assert_eq!((reconciliation.ledger.escrowed, reconciliation.ledger.deposits), (0, 0));
+        assert_eq!(reconciliation.shortfall, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+/// Claimable balances, accrued protocol fees and the final receipt's fees once an order settles
+#[derive(Debug, PartialEq)]
+struct Settlement {
+    resolver: u128,
+    maker: u128,
+    underwriter: u128,
+    protocol_fees: u128,
+    receipt_fees: (u128, u128, u128), // (resolver, maker, taker)
+}
+
+/// Settle, with `settle`, an underwritten order charging every fee, late in its fee decay window
+fn settle_order(direction: SwapDirection, settle: fn(&mut FusionPolkadotEscrow, [u8; 32])) -> Settlement {
+    let mut settlement = None;
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        escrow.set_maker_fee_bps(30).expect("maker fee");
+        escrow.set_taker_fee_bps(20).expect("taker fee");
+        escrow.set_underwriter_fee_share(1_000).expect("fee share");
+        let order_hash = create_order(&mut escrow, CreateOrderParams {
+            direction,
+            src_amount: SRC_AMOUNT,
+            max_resolver_fee: RESOLVER_FEE,
+            late_fee_schedule: Some(LateFeeSchedule { window: 60 * MINUTE, min_fee_bps: 5_000 }),
+            ..order_params()
+        });
+
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        let params = ResolverParams { resolver_fee: RESOLVER_FEE, ..resolver_params(blake2x256(&SECRET)) };
+        escrow.deploy_escrow(order_hash, params).expect("lock");
+        escrow.approve_underwriter(order_hash, stranger(), 1_000).expect("approve underwriter");
+        call_as(stranger(), COLLATERAL, CREATED_AT);
+        escrow.underwrite(order_hash).expect("underwrite");
+
+        call_as(resolver(), 0, FILL_DEADLINE - 10 * MINUTE);
+        settle(&mut escrow, order_hash);
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Executed);
+        assert_eq!(escrow.reconcile(None).expect("reconcile").shortfall, 0);
+
+        let receipt = escrow.get_fill_receipts(order_hash).pop().expect("receipt");
+        settlement = Some(Settlement {
+            resolver: escrow.get_claimable(resolver(), None),
+            maker: escrow.get_claimable(maker(), None),
+            underwriter: escrow.get_claimable(stranger(), None),
+            protocol_fees: escrow.get_accrued_fees(None),
+            receipt_fees: (receipt.resolver_fee, receipt.maker_fee, receipt.taker_fee),
+        });
+        Ok(())
+    })
+    .expect("off-chain env");
+    settlement.expect("settled")
+}
+
+#[test]
+fn one_fill_of_the_whole_order_settles_like_execute_swap() {
+    for direction in [SwapDirection::PolkadotToEthereum, SwapDirection::EthereumToPolkadot] {
+        let executed = settle_order(direction.clone(), |escrow, order_hash| {
+            escrow.execute_swap(order_hash, SECRET).expect("execute");
+        });
+        let filled = settle_order(direction, |escrow, order_hash| {
+            escrow.execute_partial_fill(order_hash, SRC_AMOUNT, SECRET).expect("fill");
+        });
+        assert_eq!(filled, executed);
+
+        // Every fee was charged: protocol fees, the decayed resolver fee with its rebate to the
+        // maker, and the underwriter's share
+        assert_eq!(executed.protocol_fees, SRC_AMOUNT * 50 / 10_000);
+        assert!(executed.maker > 0 && executed.underwriter > COLLATERAL);
+        assert!(executed.receipt_fees.0 > 0 && executed.receipt_fees.0 < RESOLVER_FEE);
+    }
+}
+
+#[test]
+fn partial_fills_pay_their_share_of_the_fees() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(SAFETY_DEPOSIT);
+        escrow.set_maker_fee_bps(30).expect("maker fee");
+        let order_hash = create_order(&mut escrow, CreateOrderParams {
+            src_amount: SRC_AMOUNT,
+            max_resolver_fee: RESOLVER_FEE,
+            ..order_params()
+        });
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        let params = ResolverParams { resolver_fee: RESOLVER_FEE, ..resolver_params(blake2x256(&SECRET)) };
+        escrow.deploy_escrow(order_hash, params).expect("lock");
+
+        for fill_amount in [SRC_AMOUNT / 3, SRC_AMOUNT / 3, SRC_AMOUNT - 2 * (SRC_AMOUNT / 3)] {
+            escrow.execute_partial_fill(order_hash, fill_amount, SECRET).expect("fill");
+        }
+
+        // The rounded shares add up to the whole order's fees
+        let receipts = escrow.get_fill_receipts(order_hash);
+        assert_eq!(receipts.iter().map(|receipt| receipt.maker_fee).sum::<u128>(), SRC_AMOUNT * 30 / 10_000);
+        assert_eq!(receipts.iter().map(|receipt| receipt.resolver_fee).sum::<u128>(), RESOLVER_FEE);
+        assert_eq!(escrow.get_accrued_fees(None), SRC_AMOUNT * 30 / 10_000);
+        assert_eq!(escrow.get_claimable(resolver(), None), SRC_AMOUNT - SRC_AMOUNT * 30 / 10_000 + SAFETY_DEPOSIT);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
@@ -0,0 +1,596 @@
+//! 1inch Fusion+ source-escrow rules as tables, run against the contract in the off-chain
+//! environment.
+//!
+//! Fusion+ stages are stamped onto an order when it is locked: finality lock, private (resolver
+//! only) and public withdrawal until `fill_deadline` plus the execution grace period, then
+//! private and public cancellation. With the default (zero) stage delays withdrawal is public
+//! from lock time and public cancellation starts strictly after the cutoff. Withdrawal needs the
+//! secret, and the funds always go to the taker. Cancellation after the cutoff refunds the maker.
+//! The resolver's safety deposit comes back when the resolver or maker cancels, and is slashed
+//! when a third party cancels in public cancellation: the canceller takes the cancellation tip out
+//! of it first, the maker gets the default share of the rest. The maker never cancels a locked
+//! order before public cancellation. Every row starts from a fresh contract, and so does every
+//! action `get_order_timeline` lists, which must succeed. Neither cancellation interface runs
+//! while the contract is paused.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    ActionActor, CreateOrderParams, Error, EscrowImmutables, FusionPolkadotEscrow, HashAlgo, KeeperConfig,
+    OrderAction, OrderStatus, StageDelays, TimeLocks,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SRC_AMOUNT: u128 = 1_000_000;
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+const PRIVATE_CANCELLATION: u64 = CREATED_AT + 30 * MINUTE; // Default per-direction period
+const EXECUTION_CUTOFF: u64 = FILL_DEADLINE + 5 * MINUTE; // Default grace period
+
+// Stage starts of `staged_order`, locked at CREATED_AT
+const FINALITY_LOCK: u64 = 2 * MINUTE;
+const PUBLIC_WITHDRAWAL_DELAY: u64 = 10 * MINUTE;
+const PUBLIC_CANCELLATION_DELAY: u64 = 10 * MINUTE;
+const SRC_WITHDRAWAL: u64 = CREATED_AT + FINALITY_LOCK;
+const SRC_PUBLIC_WITHDRAWAL: u64 = CREATED_AT + PUBLIC_WITHDRAWAL_DELAY;
+const SRC_CANCELLATION: u64 = EXECUTION_CUTOFF + 1;
+const SRC_PUBLIC_CANCELLATION: u64 = SRC_CANCELLATION + PUBLIC_CANCELLATION_DELAY;
+
+const WRONG_SECRET: [u8; 32] = [0x5f; 32];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Actor {
+    Maker,
+    Resolver,
+    Stranger,
+}
+
+impl Actor {
+    fn account(self) -> AccountId {
+        match self {
+            Actor::Maker => maker(),
+            Actor::Resolver => resolver(),
+            Actor::Stranger => stranger(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Action {
+    ExecuteSwap,
+    ExecuteWrongSecret,
+    Withdraw,   // Resolver-compatible `withdraw`
+    CancelOrder,
+    Cancel,     // Resolver-compatible `cancel`
+}
+
+/// Native balance owed to each party
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Payouts {
+    maker: u128,
+    resolver: u128,
+    stranger: u128,
+}
+
+struct Case {
+    name: &'static str,
+    at: u64,
+    actor: Actor,
+    action: Action,
+    expected: Result<Payouts, Error>,
+}
+
+const TAKER_PAID: Payouts = Payouts { maker: 0, resolver: SRC_AMOUNT + SAFETY_DEPOSIT, stranger: 0 };
+/// The resolver's own exit from a failed swap: its deposit comes back unslashed
+const MAKER_REFUNDED: Payouts = Payouts { maker: SRC_AMOUNT, resolver: SAFETY_DEPOSIT, stranger: 0 };
+/// Resolver timed out: the maker takes `DEFAULT_SLASH_MAKER_SHARE_BPS` of the slashed deposit
+const MAKER_COMPENSATED: Payouts = Payouts { maker: SRC_AMOUNT + SAFETY_DEPOSIT / 2, resolver: 0, stranger: 0 };
+
+fn locked_order_cases() -> Vec<Case> {
+    use Action::*;
+    use Actor::*;
+    vec![
+        // Withdrawal: any secret holder, funds always to the taker
+        Case { name: "resolver withdraws", at: CREATED_AT + MINUTE, actor: Resolver, action: ExecuteSwap, expected: Ok(TAKER_PAID) },
+        Case { name: "public withdrawal", at: CREATED_AT + MINUTE, actor: Stranger, action: ExecuteSwap, expected: Ok(TAKER_PAID) },
+        Case { name: "resolver-compatible withdraw", at: CREATED_AT + MINUTE, actor: Resolver, action: Withdraw, expected: Ok(TAKER_PAID) },
+        Case { name: "wrong secret", at: CREATED_AT + MINUTE, actor: Resolver, action: ExecuteWrongSecret, expected: Err(Error::InvalidSecret) },
+        Case { name: "withdraw after private cancellation", at: PRIVATE_CANCELLATION + 1, actor: Resolver, action: ExecuteSwap, expected: Ok(TAKER_PAID) },
+        Case { name: "withdraw in grace window", at: FILL_DEADLINE + 1, actor: Stranger, action: ExecuteSwap, expected: Ok(TAKER_PAID) },
+        Case { name: "withdraw at cutoff", at: EXECUTION_CUTOFF, actor: Resolver, action: Withdraw, expected: Ok(TAKER_PAID) },
+        Case {
+            name: "withdraw after cutoff",
+            at: EXECUTION_CUTOFF + 1,
+            actor: Resolver,
+            action: ExecuteSwap,
+            expected: Err(Error::DeadlineExpiredV2 { deadline: EXECUTION_CUTOFF, current_time: EXECUTION_CUTOFF + 1 }),
+        },
+        Case {
+            name: "resolver-compatible withdraw after cutoff",
+            at: EXECUTION_CUTOFF + 1,
+            actor: Resolver,
+            action: Withdraw,
+            expected: Err(Error::DeadlineExpiredV2 { deadline: EXECUTION_CUTOFF, current_time: EXECUTION_CUTOFF + 1 }),
+        },
+        // No cancellation, not even by the maker, while the secret still unlocks the order
+        Case { name: "resolver cancels early", at: CREATED_AT + MINUTE, actor: Resolver, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case { name: "stranger cancels early", at: CREATED_AT + MINUTE, actor: Stranger, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case { name: "maker cancels in grace window", at: FILL_DEADLINE + 1, actor: Maker, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case { name: "stranger cancels in grace window", at: FILL_DEADLINE + 1, actor: Stranger, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case {
+            name: "resolver-compatible cancel after private cancellation",
+            at: PRIVATE_CANCELLATION + 1,
+            actor: Stranger,
+            action: Cancel,
+            expected: Err(Error::TimelockNotExpiredV2 { unlocks_at: EXECUTION_CUTOFF + 1, current_time: PRIVATE_CANCELLATION + 1 }),
+        },
+        Case {
+            name: "resolver-compatible cancel in grace window",
+            at: FILL_DEADLINE + 1,
+            actor: Resolver,
+            action: Cancel,
+            expected: Err(Error::TimelockNotExpiredV2 { unlocks_at: EXECUTION_CUTOFF + 1, current_time: FILL_DEADLINE + 1 }),
+        },
+        // Public cancellation: maker refunded and compensated out of the slashed safety deposit
+        Case { name: "maker cancels after cutoff", at: EXECUTION_CUTOFF + 1, actor: Maker, action: CancelOrder, expected: Ok(MAKER_REFUNDED) },
+        Case { name: "resolver cancels after cutoff", at: EXECUTION_CUTOFF + 1, actor: Resolver, action: CancelOrder, expected: Ok(MAKER_REFUNDED) },
+        Case { name: "public cancellation", at: EXECUTION_CUTOFF + 1, actor: Stranger, action: CancelOrder, expected: Ok(MAKER_COMPENSATED) },
+        Case { name: "resolver-compatible cancel after cutoff", at: EXECUTION_CUTOFF + 1, actor: Stranger, action: Cancel, expected: Ok(MAKER_COMPENSATED) },
+    ]
+}
+
+fn staged_order_cases() -> Vec<Case> {
+    use Action::*;
+    use Actor::*;
+    vec![
+        // Finality lock: nobody withdraws or cancels
+        Case {
+            name: "withdraw in finality lock",
+            at: SRC_WITHDRAWAL - 1,
+            actor: Resolver,
+            action: ExecuteSwap,
+            expected: Err(Error::TimelockNotExpiredV2 { unlocks_at: SRC_WITHDRAWAL, current_time: SRC_WITHDRAWAL - 1 }),
+        },
+        Case { name: "maker cancels in finality lock", at: SRC_WITHDRAWAL - 1, actor: Maker, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        // Private withdrawal: resolver only
+        Case { name: "resolver withdraws privately", at: SRC_WITHDRAWAL, actor: Resolver, action: ExecuteSwap, expected: Ok(TAKER_PAID) },
+        Case { name: "resolver-compatible private withdraw", at: SRC_WITHDRAWAL, actor: Resolver, action: Withdraw, expected: Ok(TAKER_PAID) },
+        Case { name: "stranger in private withdrawal", at: SRC_WITHDRAWAL, actor: Stranger, action: ExecuteSwap, expected: Err(Error::OnlyResolver) },
+        Case { name: "maker cancels in private withdrawal", at: SRC_WITHDRAWAL, actor: Maker, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case { name: "stranger withdraw in private withdrawal", at: SRC_PUBLIC_WITHDRAWAL - 1, actor: Stranger, action: Withdraw, expected: Err(Error::OnlyResolver) },
+        // Public withdrawal: any secret holder until cancellation opens
+        Case { name: "public withdrawal", at: SRC_PUBLIC_WITHDRAWAL, actor: Stranger, action: ExecuteSwap, expected: Ok(TAKER_PAID) },
+        Case { name: "maker cancels in public withdrawal", at: SRC_PUBLIC_WITHDRAWAL, actor: Maker, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case {
+            name: "resolver cancels in public withdrawal",
+            at: SRC_PUBLIC_WITHDRAWAL,
+            actor: Resolver,
+            action: Cancel,
+            expected: Err(Error::TimelockNotExpiredV2 { unlocks_at: SRC_CANCELLATION, current_time: SRC_PUBLIC_WITHDRAWAL }),
+        },
+        Case {
+            name: "stranger cancels in public withdrawal",
+            at: SRC_PUBLIC_WITHDRAWAL,
+            actor: Stranger,
+            action: Cancel,
+            expected: Err(Error::TimelockNotExpiredV2 { unlocks_at: SRC_PUBLIC_CANCELLATION, current_time: SRC_PUBLIC_WITHDRAWAL }),
+        },
+        Case {
+            name: "withdraw in private cancellation",
+            at: SRC_CANCELLATION,
+            actor: Resolver,
+            action: ExecuteSwap,
+            expected: Err(Error::DeadlineExpiredV2 { deadline: EXECUTION_CUTOFF, current_time: SRC_CANCELLATION }),
+        },
+        // Private cancellation: resolver only
+        Case { name: "resolver cancels privately", at: SRC_CANCELLATION, actor: Resolver, action: Cancel, expected: Ok(MAKER_REFUNDED) },
+        Case { name: "resolver cancel_order privately", at: SRC_CANCELLATION, actor: Resolver, action: CancelOrder, expected: Ok(MAKER_REFUNDED) },
+        Case { name: "stranger in private cancellation", at: SRC_CANCELLATION, actor: Stranger, action: Cancel, expected: Err(Error::OnlyResolver) },
+        Case { name: "stranger cancel_order in private cancellation", at: SRC_PUBLIC_CANCELLATION - 1, actor: Stranger, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        Case { name: "maker in private cancellation", at: SRC_CANCELLATION, actor: Maker, action: CancelOrder, expected: Err(Error::Unauthorized) },
+        // Public cancellation
+        Case { name: "public cancellation", at: SRC_PUBLIC_CANCELLATION, actor: Stranger, action: CancelOrder, expected: Ok(MAKER_COMPENSATED) },
+        Case { name: "resolver-compatible public cancel", at: SRC_PUBLIC_CANCELLATION, actor: Stranger, action: Cancel, expected: Ok(MAKER_COMPENSATED) },
+    ]
+}
+
+fn pending_order_cases() -> Vec<Case> {
+    use Action::*;
+    use Actor::*;
+    let refunded = Ok(Payouts { maker: SRC_AMOUNT, resolver: 0, stranger: 0 });
+    vec![
+        Case { name: "execute before escrow", at: CREATED_AT + MINUTE, actor: Resolver, action: ExecuteSwap, expected: Err(Error::InvalidOrderStatus) },
+        Case { name: "withdraw before escrow", at: CREATED_AT + MINUTE, actor: Resolver, action: Withdraw, expected: Err(Error::EscrowNotFound) },
+        Case { name: "cancel before escrow", at: EXECUTION_CUTOFF + 1, actor: Stranger, action: Cancel, expected: Err(Error::EscrowNotFound) },
+        Case { name: "maker withdraws order", at: PRIVATE_CANCELLATION, actor: Maker, action: CancelOrder, expected: refunded },
+        Case { name: "stranger cancels pending", at: CREATED_AT + MINUTE, actor: Stranger, action: CancelOrder, expected: Err(Error::Unauthorized) },
+    ]
+}
+
+/// Fresh contract holding one pending order from `maker()`, with no cancellation tip
+fn pending_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    let order_hash = create_order(&mut escrow, CreateOrderParams { src_amount: SRC_AMOUNT, ..order_params() });
+    (escrow, order_hash)
+}
+
+/// `pending_order` locked by `resolver()` with a native safety deposit
+fn locked_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let (escrow, order_hash) = pending_order();
+    lock(escrow, order_hash)
+}
+
+/// `locked_order` under non-zero stage delays
+fn staged_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let (mut escrow, order_hash) = pending_order();
+    call_as(owner(), 0, CREATED_AT);
+    escrow.set_stage_delays(staged_delays()).expect("stage delays");
+    lock(escrow, order_hash)
+}
+
+fn staged_delays() -> StageDelays {
+    StageDelays {
+        src_withdrawal: FINALITY_LOCK,
+        src_public_withdrawal: PUBLIC_WITHDRAWAL_DELAY,
+        src_public_cancellation: PUBLIC_CANCELLATION_DELAY,
+        ..Default::default()
+    }
+}
+
+fn lock(mut escrow: FusionPolkadotEscrow, order_hash: [u8; 32]) -> (FusionPolkadotEscrow, [u8; 32]) {
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+    escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+    (escrow, order_hash)
+}
+
+fn act(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32], action: Action) -> Result<(), Error> {
+    match action {
+        Action::ExecuteSwap => escrow.execute_swap(order_hash, SECRET),
+        Action::ExecuteWrongSecret => escrow.execute_swap(order_hash, WRONG_SECRET),
+        Action::Withdraw => {
+            let immutables = escrow.get_escrow_immutables(order_hash);
+            escrow.withdraw(order_hash, SECRET, immutables.unwrap_or_else(|| fake_immutables(order_hash)))
+        }
+        Action::CancelOrder => escrow.cancel_order(order_hash),
+        Action::Cancel => {
+            let immutables = escrow.get_escrow_immutables(order_hash);
+            escrow.cancel(order_hash, immutables.unwrap_or_else(|| fake_immutables(order_hash)))
+        }
+    }
+}
+
+/// Stand-in for orders without a hash lock yet; the escrow ignores the argument
+fn fake_immutables(order_hash: [u8; 32]) -> EscrowImmutables {
+    EscrowImmutables {
+        order_hash,
+        hash_lock: [0u8; 32],
+        maker: maker(),
+        taker: resolver(),
+        token: native(),
+        amount: SRC_AMOUNT,
+        safety_deposit: 0,
+        timelocks: TimeLocks { fill_deadline: FILL_DEADLINE, private_cancellation: PRIVATE_CANCELLATION, ..Default::default() },
+        deployed_at: None,
+    }
+}
+
+/// Fresh contract holding the order under test
+type Setup = fn() -> (FusionPolkadotEscrow, [u8; 32]);
+
+fn run(cases: Vec<Case>, setup: Setup) {
+    for case in cases {
+        test::run_test::<Env, _>(|_| {
+            let (mut escrow, order_hash) = setup();
+            let before = [maker(), resolver(), stranger()].map(|account| escrow.get_claimable(account, None));
+
+            call_as(case.actor.account(), 0, case.at);
+            let result = act(&mut escrow, order_hash, case.action);
+            let after = [maker(), resolver(), stranger()].map(|account| escrow.get_claimable(account, None));
+            let payouts = Payouts {
+                maker: after[0] - before[0],
+                resolver: after[1] - before[1],
+                stranger: after[2] - before[2],
+            };
+            assert_eq!(result.map(|()| payouts), case.expected, "{}", case.name);
+
+            let reconciliation = escrow.reconcile(None).expect("native reconcile");
+            assert_eq!(reconciliation.shortfall, 0, "{}", case.name);
+            if case.expected.is_ok() {
+                assert_eq!(reconciliation.ledger.escrowed, 0, "{}", case.name);
+                assert_eq!(reconciliation.ledger.deposits, 0, "{}", case.name);
+            }
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn locked_order_follows_fusion_plus_stages() {
+    run(locked_order_cases(), locked_order);
+}
+
+#[test]
+fn staged_order_follows_fusion_plus_stages() {
+    run(staged_order_cases(), staged_order);
+}
+
+#[test]
+fn stage_delays_must_fit_the_order() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = pending_order();
+        call_as(owner(), 0, CREATED_AT);
+        let inverted = StageDelays { src_withdrawal: PUBLIC_WITHDRAWAL_DELAY + 1, ..staged_delays() };
+        assert_eq!(escrow.set_stage_delays(inverted), Err(Error::InvalidTimelocks));
+
+        // A finality lock outlasting the execution cutoff leaves no withdrawal stage
+        let too_long = StageDelays {
+            src_withdrawal: SRC_CANCELLATION - CREATED_AT,
+            src_public_withdrawal: SRC_CANCELLATION - CREATED_AT,
+            ..Default::default()
+        };
+        escrow.set_stage_delays(too_long).expect("stage delays");
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+        assert_eq!(escrow.deploy_escrow(order_hash, params), Err(Error::InvalidTimelocks));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn locked_order_stamps_stage_starts() {
+    test::run_test::<Env, _>(|_| {
+        let (escrow, order_hash) = staged_order();
+        let time_locks = escrow.get_order(order_hash).expect("order").time_locks;
+        assert_eq!(
+            time_locks,
+            TimeLocks {
+                fill_deadline: FILL_DEADLINE,
+                private_cancellation: PRIVATE_CANCELLATION,
+                src_withdrawal: SRC_WITHDRAWAL,
+                src_public_withdrawal: SRC_PUBLIC_WITHDRAWAL,
+                src_cancellation: SRC_CANCELLATION,
+                src_public_cancellation: SRC_PUBLIC_CANCELLATION,
+                dst_withdrawal: CREATED_AT,
+                dst_public_withdrawal: CREATED_AT,
+                dst_cancellation: CREATED_AT,
+            }
+        );
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn pending_order_cannot_settle() {
+    run(pending_order_cases(), pending_order);
+}
+
+#[test]
+fn settled_order_cannot_be_cancelled() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = locked_order();
+        call_as(resolver(), 0, CREATED_AT + MINUTE);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+
+        call_as(stranger(), 0, EXECUTION_CUTOFF + 1);
+        assert_eq!(escrow.cancel(order_hash, fake_immutables(order_hash)), Err(Error::InvalidOrderStatus));
+        assert_eq!(escrow.cancel_order(order_hash), Err(Error::Unauthorized));
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Executed);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn paused_contract_cancels_through_neither_interface() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = locked_order();
+        call_as(owner(), 0, CREATED_AT);
+        escrow.set_paused(true).expect("pause");
+
+        call_as(stranger(), 0, EXECUTION_CUTOFF + 1);
+        assert_eq!(escrow.cancel(order_hash, fake_immutables(order_hash)), Err(Error::ContractPaused));
+        assert_eq!(escrow.cancel_order(order_hash), Err(Error::ContractPaused));
+
+        call_as(owner(), 0, EXECUTION_CUTOFF + 1);
+        escrow.set_paused(false).expect("unpause");
+        call_as(stranger(), 0, EXECUTION_CUTOFF + 1);
+        escrow.cancel(order_hash, fake_immutables(order_hash)).expect("cancel");
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Cancelled);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+/// `get_order_timeline` agrees with the table, and the stages never overlap
+#[test]
+fn timeline_orders_withdrawal_before_public_cancellation() {
+    test::run_test::<Env, _>(|_| {
+        let (escrow, order_hash) = locked_order();
+        let mut checkpoints: Vec<u64> = (CREATED_AT..=EXECUTION_CUTOFF + 2 * MINUTE).step_by(MINUTE as usize).collect();
+        checkpoints.push(EXECUTION_CUTOFF + 1);
+        checkpoints.sort_unstable();
+
+        let mut last_execute = None;
+        let mut first_public_cancel = None;
+        for at in checkpoints {
+            call_as(stranger(), 0, at);
+            let timeline = escrow.get_order_timeline(order_hash).expect("timeline");
+            let execute = timeline.allowed_actions.contains(&(OrderAction::Execute, ActionActor::SecretHolder));
+            let public_cancel = timeline.allowed_actions.contains(&(OrderAction::CancelOrder, ActionActor::Anyone));
+            assert!(!(execute && public_cancel), "stages overlap at {at}");
+            if execute {
+                last_execute = Some(at);
+            }
+            if public_cancel {
+                first_public_cancel.get_or_insert(at);
+            }
+        }
+        assert_eq!(last_execute, Some(EXECUTION_CUTOFF));
+        assert_eq!(first_public_cancel, Some(EXECUTION_CUTOFF + 1));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+/// The slashed deposit of a timed-out resolver splits between maker and fee vault
+#[test]
+fn timed_out_deposit_follows_the_slash_share() {
+    for share_bps in [0, 2_500, 10_000] {
+        test::run_test::<Env, _>(|_| {
+            let (mut escrow, order_hash) = locked_order();
+            call_as(owner(), 0, CREATED_AT);
+            escrow.set_slash_maker_share(share_bps).expect("slash share");
+            let to_maker = SAFETY_DEPOSIT * share_bps as u128 / 10_000;
+
+            let before = [maker(), resolver()].map(|account| escrow.get_claimable(account, None));
+            call_as(stranger(), 0, EXECUTION_CUTOFF + 1);
+            act(&mut escrow, order_hash, Action::Cancel).expect("cancel");
+            let after = [maker(), resolver()].map(|account| escrow.get_claimable(account, None));
+            assert_eq!(after[0] - before[0], SRC_AMOUNT + to_maker, "{share_bps}");
+            assert_eq!(after[1], before[1], "{share_bps}");
+            assert_eq!(escrow.get_accrued_fees(None), SAFETY_DEPOSIT - to_maker, "{share_bps}");
+            assert_eq!(escrow.reconcile(None).expect("reconcile").shortfall, 0, "{share_bps}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, _) = pending_order();
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.set_slash_maker_share(0), Err(Error::OnlyOwner));
+        call_as(owner(), 0, CREATED_AT);
+        assert_eq!(escrow.set_slash_maker_share(10_001), Err(Error::InvalidAmount));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+/// Once public cancellation opens a third-party canceller takes the tip before the deposit is
+/// slashed; the order's own parties are paid neither
+#[test]
+fn public_canceller_takes_the_cancellation_tip() {
+    const TIP: u128 = SAFETY_DEPOSIT / 5;
+    let slashed_to_maker = (SAFETY_DEPOSIT - TIP) / 2;
+    let cases = [
+        ("stranger cancel_order", locked_order as fn() -> _, EXECUTION_CUTOFF + 1, Actor::Stranger, Action::CancelOrder,
+            Payouts { maker: SRC_AMOUNT + slashed_to_maker, resolver: 0, stranger: TIP }),
+        ("stranger cancel", locked_order, EXECUTION_CUTOFF + 1, Actor::Stranger, Action::Cancel,
+            Payouts { maker: SRC_AMOUNT + slashed_to_maker, resolver: 0, stranger: TIP }),
+        ("maker cancel_order", locked_order, EXECUTION_CUTOFF + 1, Actor::Maker, Action::CancelOrder, MAKER_REFUNDED),
+        ("resolver cancel_order", locked_order, EXECUTION_CUTOFF + 1, Actor::Resolver, Action::CancelOrder, MAKER_REFUNDED),
+        ("private cancellation pays no tip", staged_order, SRC_CANCELLATION, Actor::Resolver, Action::Cancel, MAKER_REFUNDED),
+    ];
+    for (name, setup, at, actor, action, expected) in cases {
+        test::run_test::<Env, _>(|_| {
+            let (mut escrow, order_hash) = setup();
+            call_as(owner(), 0, CREATED_AT);
+            escrow.set_cancellation_tip(2_000).expect("tip");
+            // The keeper bounty is not paid on top of the tip
+            escrow
+                .set_keeper_config(KeeperConfig { bounty_bps: 10_000, max_bounty: SAFETY_DEPOSIT, allow_list_only: false })
+                .expect("keeper config");
+
+            let before = [maker(), resolver(), stranger()].map(|account| escrow.get_claimable(account, None));
+            call_as(actor.account(), 0, at);
+            act(&mut escrow, order_hash, action).expect(name);
+            let after = [maker(), resolver(), stranger()].map(|account| escrow.get_claimable(account, None));
+            let payouts = Payouts {
+                maker: after[0] - before[0],
+                resolver: after[1] - before[1],
+                stranger: after[2] - before[2],
+            };
+            assert_eq!(payouts, expected, "{name}");
+            assert_eq!(escrow.reconcile(None).expect("reconcile").ledger.deposits, 0, "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+/// Whoever `actor` stands for in these tests
+fn actor_account(actor: &ActionActor) -> AccountId {
+    match actor {
+        ActionActor::Maker => maker(),
+        ActionActor::Resolver(account) => *account,
+        ActionActor::AnyResolver => resolver(),
+        ActionActor::SecretHolder | ActionActor::Anyone => stranger(),
+    }
+}
+
+/// `locked_order` executed by its resolver
+fn executed_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let (mut escrow, order_hash) = locked_order();
+    call_as(resolver(), 0, CREATED_AT + MINUTE);
+    escrow.execute_swap(order_hash, SECRET).expect("execute");
+    (escrow, order_hash)
+}
+
+/// Every action `get_order_timeline` offers, at every stage boundary, succeeds for its actor
+#[test]
+fn timeline_actions_succeed() {
+    let checkpoints = [
+        CREATED_AT + MINUTE,
+        SRC_WITHDRAWAL - 1,
+        SRC_WITHDRAWAL,
+        SRC_PUBLIC_WITHDRAWAL,
+        PRIVATE_CANCELLATION,
+        PRIVATE_CANCELLATION + 1,
+        FILL_DEADLINE,
+        FILL_DEADLINE + 1,
+        EXECUTION_CUTOFF,
+        SRC_CANCELLATION,
+        SRC_PUBLIC_CANCELLATION,
+        CREATED_AT + 31 * 24 * 60 * MINUTE, // Past the default archive retention
+    ];
+    let setups: [(&str, Setup); 4] = [
+        ("pending", pending_order),
+        ("locked", locked_order),
+        ("staged", staged_order),
+        ("executed", executed_order),
+    ];
+    for (name, setup) in setups {
+        for at in checkpoints {
+            let mut allowed_actions = Vec::new();
+            test::run_test::<Env, _>(|_| {
+                let (escrow, order_hash) = setup();
+                call_as(stranger(), 0, at);
+                allowed_actions = escrow.get_order_timeline(order_hash).expect("timeline").allowed_actions;
+                Ok(())
+            })
+            .expect("off-chain env");
+
+            for (action, actor) in allowed_actions {
+                test::run_test::<Env, _>(|_| {
+                    let (mut escrow, order_hash) = setup();
+                    let result = match action {
+                        OrderAction::DeployEscrow => {
+                            call_as(actor_account(&actor), SAFETY_DEPOSIT, at);
+                            escrow.deploy_escrow(order_hash, resolver_params(HashAlgo::Blake2x256.hash(&SECRET)))
+                        }
+                        OrderAction::Execute => {
+                            call_as(actor_account(&actor), 0, at);
+                            escrow.execute_swap(order_hash, SECRET)
+                        }
+                        OrderAction::CancelOrder => {
+                            call_as(actor_account(&actor), 0, at);
+                            escrow.cancel_order(order_hash)
+                        }
+                        OrderAction::Archive => {
+                            call_as(actor_account(&actor), 0, at);
+                            escrow.archive_order(order_hash)
+                        }
+                    };
+                    assert_eq!(result, Ok(()), "{name} order at {at}: {action:?} by {actor:?}");
+                    Ok(())
+                })
+                .expect("off-chain env");
+            }
+        }
+    }
+}
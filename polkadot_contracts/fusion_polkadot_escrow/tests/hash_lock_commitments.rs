@@ -0,0 +1,40 @@
+//! Hash-lock pre-commitments: a lapsed commitment cannot be renewed by the same resolver until
+//! a cooldown has passed, so one resolver cannot hold a pending order indefinitely.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, Error, FusionPolkadotEscrow};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+/// Fresh contract with two approved resolvers and one pending order
+fn escrow_with_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    escrow.approve_resolver(stranger()).expect("approve");
+    let order_hash = create_order(&mut escrow, CreateOrderParams { src_amount: 10_000, ..order_params() });
+    (escrow, order_hash)
+}
+
+#[test]
+fn a_resolver_waits_out_the_cooldown_before_recommitting() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order();
+        let ttl = escrow.get_config().commitment_ttl;
+
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.commit_hash_lock(order_hash, [0x42; 32]).expect("commit");
+        let lapsed_at = CREATED_AT + ttl;
+        call_as(resolver(), 0, lapsed_at);
+        assert_eq!(escrow.commit_hash_lock(order_hash, [0x42; 32]), Err(Error::CommitmentCooldown));
+
+        // Others may commit as soon as it lapses; the resolver only after a further ttl
+        call_as(stranger(), 0, lapsed_at);
+        escrow.commit_hash_lock(order_hash, [0x43; 32]).expect("other resolver commits");
+        call_as(resolver(), 0, lapsed_at + 2 * ttl);
+        escrow.commit_hash_lock(order_hash, [0x42; 32]).expect("recommit after cooldown");
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
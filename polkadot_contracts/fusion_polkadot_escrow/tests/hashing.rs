@@ -0,0 +1,62 @@
+//! keccak256 vectors for both the host-function path and the vendored `pure-keccak` one,
+//! plus the per-order hashlock algorithms, each of which `get_capabilities` reports.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::capabilities;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::HashAlgo;
+use fusion_polkadot_escrow::hashing::{keccak, keccak256};
+use ink::env::test;
+
+const VECTORS: [(&[u8], &str); 5] = [
+    (b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+    (b"abc", "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"),
+    // Rate boundary (136 bytes) and multi-block input
+    (&[0x61; 135], "34367dc248bbd832f4e3e69dfaac2f92638bd0bbd18f2912ba4ef454919cf446"),
+    (&[0x61; 136], "a6c4d403279fe3e0af03729caada8374b5ca54d8065329a3ebcaeb4b60aa386e"),
+    (&[0x61; 300], "5b7e0e47a96f32a88b4f14ca177982790807c40e1a105742ba0fc1babe1ef826"),
+];
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[test]
+fn keccak256_matches_ethereum_vectors() {
+    for (input, expected) in VECTORS {
+        assert_eq!(hex(&keccak256(input)), expected, "input len {}", input.len());
+    }
+}
+
+#[test]
+fn vendored_keccak_matches_ethereum_vectors() {
+    for (input, expected) in VECTORS {
+        assert_eq!(hex(&keccak::keccak256(input)), expected, "input len {}", input.len());
+    }
+}
+
+#[test]
+fn hash_algo_matches_reference_digests() {
+    assert_eq!(
+        hex(&HashAlgo::Blake2x256.hash(b"abc")),
+        "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319"
+    );
+    assert_eq!(hex(&HashAlgo::Keccak256.hash(b"abc")), VECTORS[1].1);
+    assert_eq!(
+        hex(&HashAlgo::Sha256.hash(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn every_hash_algo_is_reported_as_a_capability() {
+    test::run_test::<Env, _>(|_| {
+        let reported = escrow(0).get_capabilities();
+        for flag in [capabilities::HASH_BLAKE2X256, capabilities::HASH_KECCAK256, capabilities::HASH_SHA256] {
+            assert_ne!(reported & flag, 0, "flag {flag:#x}");
+        }
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+//! Maker signatures on `deploy_src`: ECDSA and sr25519 signatures must cover this contract, the
+//! order hash and the maker's current nonce, each is good for one deploy, and contract
+//! signatures are only accepted from contract makers.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    EscrowImmutables, Error, FusionPolkadotEscrow, MakerSignature, OrderStatus, TimeLocks,
+};
+use fusion_polkadot_escrow::nonces;
+use ink::env::test;
+use ink::primitives::AccountId;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+/// sr25519 public key of the test secret scalar `0x5e5e..5e mod l`
+const SR25519_MAKER: [u8; 32] = [
+    0xde, 0x32, 0x8e, 0x5c, 0xe7, 0xd2, 0x38, 0xff, 0xf8, 0xc7, 0x93, 0x08, 0x7c, 0xc6, 0xd4, 0x8d,
+    0x6c, 0x3b, 0x9d, 0x5d, 0xf5, 0xc9, 0xd6, 0xed, 0xdf, 0x8d, 0x9e, 0x56, 0x2a, 0x0d, 0x94, 0x05,
+];
+/// Its signatures over `nonces::digest(contract(), order_hash, 0)` of the maker's first and
+/// second order from `escrow_with_orders`
+const SR25519_FIRST: [u8; 64] = [
+    0x08, 0x91, 0x09, 0x76, 0x54, 0x01, 0xeb, 0xbb, 0x31, 0x89, 0x2c, 0x1c, 0x6e, 0x1b, 0x03, 0x6c,
+    0x13, 0x97, 0xfd, 0x91, 0xb9, 0xe2, 0x71, 0x42, 0xb9, 0x0d, 0x46, 0x76, 0x7b, 0x30, 0x8c, 0x52,
+    0xff, 0x59, 0xcc, 0x57, 0x74, 0x56, 0x71, 0xbe, 0x5f, 0x21, 0x27, 0xbb, 0x38, 0x19, 0x9a, 0xe0,
+    0x43, 0x98, 0x30, 0xc2, 0xc4, 0xef, 0xa1, 0x5b, 0x89, 0xfd, 0x2d, 0xc5, 0xcd, 0x72, 0xb8, 0x86,
+];
+const SR25519_SECOND: [u8; 64] = [
+    0xd0, 0xe9, 0xd1, 0x41, 0xe8, 0x37, 0x5a, 0xaa, 0x0d, 0xbb, 0xa0, 0x66, 0x14, 0xfd, 0xf9, 0x2b,
+    0x59, 0xb0, 0x65, 0x51, 0x7c, 0xc4, 0x9b, 0xec, 0x86, 0x95, 0xb6, 0x19, 0xe8, 0x61, 0x01, 0x34,
+    0x85, 0x85, 0x3d, 0x7f, 0xcc, 0xa2, 0xe9, 0xd7, 0x6b, 0x46, 0xa1, 0xba, 0x33, 0x23, 0x8e, 0x9d,
+    0x2c, 0x94, 0x8f, 0xb2, 0x7a, 0x18, 0x11, 0x54, 0xd7, 0x25, 0x35, 0xe1, 0x44, 0x36, 0x6f, 0x87,
+];
+
+fn ecdsa_key(seed: u8) -> SecretKey {
+    SecretKey::from_slice(&[seed; 32]).expect("secret key")
+}
+
+/// Substrate ECDSA account of `key`: Blake2x256 of the compressed public key
+fn ecdsa_account(key: &SecretKey) -> AccountId {
+    AccountId::from(blake2x256(&PublicKey::from_secret_key(&Secp256k1::new(), key).serialize()))
+}
+
+fn ecdsa_sign(key: &SecretKey, digest: [u8; 32]) -> MakerSignature {
+    let message = Message::from_slice(&digest).expect("digest");
+    let (recovery_id, rs) = Secp256k1::new().sign_ecdsa_recoverable(&message, key).serialize_compact();
+    let mut signature = [0u8; 65];
+    signature[..64].copy_from_slice(&rs);
+    signature[64] = recovery_id.to_i32() as u8;
+    MakerSignature::Ecdsa(signature)
+}
+
+/// What the maker signs for this contract
+fn digest(order_hash: [u8; 32], nonce: u64) -> [u8; 32] {
+    nonces::digest(contract(), order_hash, nonce)
+}
+
+/// Fresh contract with two pending orders from `maker`
+fn escrow_with_orders(maker: AccountId) -> (FusionPolkadotEscrow, [[u8; 32]; 2]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    test::set_account_balance::<Env>(maker, STARTING_BALANCE);
+    let mut orders = [[0u8; 32]; 2];
+    for order_hash in orders.iter_mut() {
+        call_as(maker, SRC_AMOUNT, CREATED_AT);
+        *order_hash = escrow.create_order(order_params()).expect("create order");
+    }
+    (escrow, orders)
+}
+
+/// `resolver()` deploys the source escrow of `order_hash` with `signature`
+fn deploy(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32], signature: MakerSignature) -> Result<AccountId, Error> {
+    let immutables = EscrowImmutables {
+        order_hash,
+        hash_lock: blake2x256(&order_hash),
+        maker: escrow.get_order(order_hash).expect("order").maker,
+        taker: resolver(),
+        token: native(),
+        amount: SRC_AMOUNT,
+        safety_deposit: SAFETY_DEPOSIT,
+        timelocks: TimeLocks::default(),
+        deployed_at: None,
+    };
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_src(immutables, order_hash, signature, 0, Vec::new())
+}
+
+#[test]
+fn ecdsa_signatures_cover_contract_order_and_nonce_once() {
+    test::run_test::<Env, _>(|_| {
+        let key = ecdsa_key(0x4b);
+        let maker = ecdsa_account(&key);
+        let (mut escrow, [first, second]) = escrow_with_orders(maker);
+
+        // Another signer, another contract, another order or a nonce not yet reached
+        let other_contract = AccountId::from([0xc1; 32]);
+        for signature in [
+            ecdsa_sign(&ecdsa_key(0x4c), digest(first, 0)),
+            ecdsa_sign(&key, nonces::digest(other_contract, first, 0)),
+            ecdsa_sign(&key, digest(second, 0)),
+            ecdsa_sign(&key, digest(first, 1)),
+        ] {
+            assert_eq!(deploy(&mut escrow, first, signature), Err(Error::InvalidSignature));
+        }
+        assert_eq!(escrow.get_nonce(maker), 0);
+
+        // Both orders signed up front over the same nonce: only the first deploy gets to use it
+        let signed_second = ecdsa_sign(&key, digest(second, 0));
+        deploy(&mut escrow, first, ecdsa_sign(&key, digest(first, 0))).expect("deploy");
+        assert_eq!(escrow.get_order(first).expect("order").status, OrderStatus::Locked);
+        assert_eq!(escrow.get_nonce(maker), 1);
+        assert_eq!(deploy(&mut escrow, second, signed_second), Err(Error::InvalidSignature));
+
+        deploy(&mut escrow, second, ecdsa_sign(&key, digest(second, 1))).expect("deploy");
+        assert_eq!(escrow.get_nonce(maker), 2);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn sr25519_signatures_cover_the_order_once() {
+    test::run_test::<Env, _>(|_| {
+        let maker = AccountId::from(SR25519_MAKER);
+        let (mut escrow, [first, second]) = escrow_with_orders(maker);
+
+        let mut tampered = SR25519_FIRST;
+        tampered[0] ^= 1;
+        assert_eq!(deploy(&mut escrow, first, MakerSignature::Sr25519(tampered)), Err(Error::InvalidSignature));
+        assert_eq!(deploy(&mut escrow, second, MakerSignature::Sr25519(SR25519_FIRST)), Err(Error::InvalidSignature));
+
+        deploy(&mut escrow, first, MakerSignature::Sr25519(SR25519_FIRST)).expect("deploy");
+        assert_eq!(escrow.get_nonce(maker), 1);
+        // Signed over the nonce the first deploy consumed
+        assert_eq!(deploy(&mut escrow, second, MakerSignature::Sr25519(SR25519_SECOND)), Err(Error::InvalidSignature));
+        assert_eq!(escrow.get_order(second).expect("order").status, OrderStatus::Pending);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn contract_signatures_need_a_contract_maker() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, [first, _]) = escrow_with_orders(maker());
+
+        let signature = MakerSignature::Contract(digest(first, 0).to_vec());
+        assert_eq!(deploy(&mut escrow, first, signature), Err(Error::InvalidSignature));
+        assert_eq!(escrow.get_nonce(maker()), 0);
+        assert_eq!(escrow.get_order(first).expect("order").status, OrderStatus::Pending);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
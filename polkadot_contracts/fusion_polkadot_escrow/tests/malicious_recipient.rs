@@ -0,0 +1,232 @@
+//! E2E: payout paths against an adversarial deposit token / payee.
+//!
+//! Run with `cargo test --features e2e-tests` against a running contracts node.
+#![cfg(feature = "e2e-tests")]
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, HashAlgo, OrderStatus, ResolverParams, StageDelays,
+};
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{FusionPolkadotEscrow, FusionPolkadotEscrowRef};
+use ink_e2e::{AccountKeyring, ContractsBackend};
+use malicious_recipient::malicious_recipient::{Behavior, MaliciousRecipient, MaliciousRecipientRef};
+use scale::Encode;
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const SRC_AMOUNT: u128 = 1_000_000;
+const SECRET_A: [u8; 32] = [0xa5; 32];
+const SECRET_B: [u8; 32] = [0xb5; 32];
+
+/// `common::order_params()` sized for the node, `fill_deadline` needs the duration bounds widened
+fn e2e_order_params() -> CreateOrderParams {
+    CreateOrderParams { src_amount: SRC_AMOUNT, fill_deadline: u64::MAX / 2, ..order_params() }
+}
+
+fn bob_params(hash_lock: [u8; 32], token_safety_deposit: u128) -> ResolverParams {
+    ResolverParams {
+        resolver: ink_e2e::account_id(AccountKeyring::Bob),
+        token_safety_deposit,
+        ..resolver_params(hash_lock)
+    }
+}
+
+#[ink_e2e::test]
+async fn rejecting_deposit_token_does_not_block_settlement<Client: E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let mut token_ctor = MaliciousRecipientRef::new(Behavior::Accept);
+    let token = client
+        .instantiate("malicious_recipient", &ink_e2e::alice(), &mut token_ctor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+    let mut token_call = token.call_builder::<MaliciousRecipient>();
+
+    let mut escrow_ctor = FusionPolkadotEscrowRef::new(0, 1, 1, [0u8; 20]);
+    let escrow = client
+        .instantiate("fusion_polkadot_escrow", &ink_e2e::alice(), &mut escrow_ctor)
+        .submit()
+        .await
+        .expect("escrow instantiate failed");
+    let mut escrow_call = escrow.call_builder::<FusionPolkadotEscrow>();
+
+    client.call(&ink_e2e::alice(), &escrow_call.set_allow_all_dst_tokens(true)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.approve_resolver(ink_e2e::account_id(AccountKeyring::Bob))).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_order_duration_bounds(1, u64::MAX)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_deposit_token(Some(token.account_id), 1)).submit().await?;
+    // Zero stage delays: the resolver may withdraw as soon as the order is locked
+    client.call(&ink_e2e::alice(), &escrow_call.set_stage_delays(StageDelays::default())).submit().await?;
+
+    // Two independent orders: A backed by the token deposit, B by a native deposit
+    let order_a = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params()))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create A failed");
+    let order_b = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params()))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create B failed");
+
+    client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order_a, bob_params(HashAlgo::Blake2x256.hash(&SECRET_A), 10)))
+        .submit()
+        .await?;
+    client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order_b, bob_params(HashAlgo::Blake2x256.hash(&SECRET_B), 0)))
+        .value(10)
+        .submit()
+        .await?;
+
+    // Token starts rejecting: both orders still settle, every leg is credited to the resolver
+    client.call(&ink_e2e::alice(), &token_call.set_behavior(Behavior::Reject)).submit().await?;
+    client.call(&ink_e2e::bob(), &escrow_call.execute_swap(order_a, SECRET_A)).submit().await?;
+    client.call(&ink_e2e::bob(), &escrow_call.execute_swap(order_b, SECRET_B)).submit().await?;
+    for order_hash in [order_a, order_b] {
+        let order = client.call(&ink_e2e::alice(), &escrow_call.get_order(order_hash)).dry_run().await?.return_value();
+        assert_eq!(order.expect("order").status, OrderStatus::Executed);
+    }
+    let bob = ink_e2e::account_id(AccountKeyring::Bob);
+    let owed = client.call(&ink_e2e::alice(), &escrow_call.get_claimable(bob, Some(token.account_id))).dry_run().await?;
+    assert_eq!(owed.return_value(), 10);
+
+    // The token claim fails and stays owed; the native claim is unaffected
+    let claim = client.call(&ink_e2e::bob(), &escrow_call.claim(Some(token.account_id))).dry_run().await?;
+    assert_eq!(claim.return_value(), Err(Error::TransferFailed));
+    let claimed = client.call(&ink_e2e::bob(), &escrow_call.claim(None)).submit().await?.return_value();
+    assert_eq!(claimed, Ok(2 * SRC_AMOUNT + 10));
+
+    // A keeper may push the balance but cannot redirect it; the payee can
+    let charlie = ink_e2e::account_id(AccountKeyring::Charlie);
+    client.call(&ink_e2e::alice(), &escrow_call.set_keeper(charlie, true)).submit().await?;
+    let retry = client.call(&ink_e2e::charlie(), &escrow_call.retry_payout(bob, Some(token.account_id))).dry_run().await?;
+    assert_eq!(retry.return_value(), Err(Error::TransferFailed));
+    client.call(&ink_e2e::alice(), &token_call.set_behavior(Behavior::Accept)).submit().await?;
+    let claimed = client
+        .call(&ink_e2e::bob(), &escrow_call.claim_to(Some(token.account_id), charlie))
+        .submit()
+        .await?
+        .return_value();
+    assert_eq!(claimed, Ok(10));
+    assert_eq!(client.call(&ink_e2e::alice(), &token_call.balance_of(charlie)).dry_run().await?.return_value(), 10);
+    let owed = client.call(&ink_e2e::alice(), &escrow_call.get_claimable(bob, Some(token.account_id))).dry_run().await?;
+    assert_eq!(owed.return_value(), 0);
+
+    Ok(())
+}
+
+#[ink_e2e::test]
+async fn reentrant_deposit_token_cannot_reenter_escrow<Client: E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let mut token_ctor = MaliciousRecipientRef::new(Behavior::Reenter);
+    let token = client
+        .instantiate("malicious_recipient", &ink_e2e::alice(), &mut token_ctor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+    let mut token_call = token.call_builder::<MaliciousRecipient>();
+
+    let mut escrow_ctor = FusionPolkadotEscrowRef::new(0, 1, 1, [0u8; 20]);
+    let escrow = client
+        .instantiate("fusion_polkadot_escrow", &ink_e2e::alice(), &mut escrow_ctor)
+        .submit()
+        .await
+        .expect("escrow instantiate failed");
+    let mut escrow_call = escrow.call_builder::<FusionPolkadotEscrow>();
+
+    client.call(&ink_e2e::alice(), &escrow_call.set_allow_all_dst_tokens(true)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.approve_resolver(ink_e2e::account_id(AccountKeyring::Bob))).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_order_duration_bounds(1, u64::MAX)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_deposit_token(Some(token.account_id), 1)).submit().await?;
+
+    let victim = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params()))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create victim failed");
+    let order = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params()))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create order failed");
+
+    // While pulling the deposit, the token tries to cancel someone else's order
+    let mut reentry = ink::selector_bytes!("cancel_order").to_vec();
+    victim.encode_to(&mut reentry);
+    client.call(&ink_e2e::alice(), &token_call.set_reentry(escrow.account_id, reentry)).submit().await?;
+
+    client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order, bob_params([0xcc; 32], 10)))
+        .submit()
+        .await?;
+
+    let (attempts, successes) = client
+        .call(&ink_e2e::alice(), &token_call.get_reentry_stats())
+        .dry_run()
+        .await?
+        .return_value();
+    assert_eq!(attempts, 1);
+    assert_eq!(successes, 0);
+
+    let victim_order = client.call(&ink_e2e::alice(), &escrow_call.get_order(victim)).dry_run().await?.return_value();
+    assert_eq!(victim_order.expect("victim").status, OrderStatus::Pending);
+
+    Ok(())
+}
+
+#[ink_e2e::test]
+async fn fee_on_transfer_deposit_records_received_amount<Client: E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    // 1% transfer fee
+    let mut token_ctor = MaliciousRecipientRef::new(Behavior::TakeFee(100));
+    let token = client
+        .instantiate("malicious_recipient", &ink_e2e::alice(), &mut token_ctor)
+        .submit()
+        .await
+        .expect("token instantiate failed");
+
+    let mut escrow_ctor = FusionPolkadotEscrowRef::new(0, 1, 1, [0u8; 20]);
+    let escrow = client
+        .instantiate("fusion_polkadot_escrow", &ink_e2e::alice(), &mut escrow_ctor)
+        .submit()
+        .await
+        .expect("escrow instantiate failed");
+    let mut escrow_call = escrow.call_builder::<FusionPolkadotEscrow>();
+
+    client.call(&ink_e2e::alice(), &escrow_call.set_allow_all_dst_tokens(true)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.approve_resolver(ink_e2e::account_id(AccountKeyring::Bob))).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_order_duration_bounds(1, u64::MAX)).submit().await?;
+    client.call(&ink_e2e::alice(), &escrow_call.set_deposit_token(Some(token.account_id), 1)).submit().await?;
+
+    let order = client
+        .call(&ink_e2e::alice(), &escrow_call.create_order(e2e_order_params()))
+        .value(SRC_AMOUNT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("create order failed");
+    client
+        .call(&ink_e2e::bob(), &escrow_call.deploy_escrow(order, bob_params([0xdd; 32], 1_000)))
+        .submit()
+        .await?;
+
+    // Only what reached the escrow is owed back
+    let stored = client.call(&ink_e2e::alice(), &escrow_call.get_order(order)).dry_run().await?.return_value();
+    assert_eq!(stored.expect("order").safety_deposit, 990);
+
+    Ok(())
+}
\ No newline at end of file
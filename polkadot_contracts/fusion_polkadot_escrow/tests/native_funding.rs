@@ -0,0 +1,39 @@
+//! Orders are funded by exactly the native value attached to `create_order`: `src_token` must be
+//! the native id, underpayment and overpayment are both rejected, and locked value is recorded
+//! under the native id only.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, Error, MAX_PAGE_LEN};
+use ink::env::test;
+
+#[test]
+fn orders_escrow_exactly_the_attached_native_value() {
+    let psp22_source = CreateOrderParams { src_token: stranger(), ..order_params() };
+    // (name, params, native attached, expected result)
+    let cases = [
+        ("exact payment", order_params(), SRC_AMOUNT, Ok(())),
+        (
+            "underpayment",
+            order_params(),
+            SRC_AMOUNT - 1,
+            Err(Error::InsufficientFundsV2 { required: SRC_AMOUNT, provided: SRC_AMOUNT - 1 }),
+        ),
+        ("overpayment", order_params(), SRC_AMOUNT + 1, Err(Error::InvalidAmount)),
+        ("PSP22 source token", psp22_source, SRC_AMOUNT, Err(Error::UnsupportedToken)),
+    ];
+    for (name, params, attached, expected) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow(0);
+            call_as(maker(), attached, CREATED_AT);
+            let created = escrow.create_order(params);
+
+            assert_eq!(created.map(|_| ()), expected, "{name}");
+            let locked = if expected.is_ok() { vec![(native(), SRC_AMOUNT)] } else { Vec::new() };
+            assert_eq!(escrow.get_locked_values(0, MAX_PAGE_LEN), locked, "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
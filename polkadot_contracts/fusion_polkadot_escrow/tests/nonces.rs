@@ -0,0 +1,30 @@
+//! Signature nonces: each account starts at zero and can revoke its outstanding signatures by
+//! skipping past their nonces, but never move back.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::Error;
+use ink::env::test;
+
+#[test]
+fn invalidating_a_nonce_skips_past_it() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(0);
+        assert_eq!(escrow.get_nonce(maker()), 0);
+
+        call_as(maker(), 0, CREATED_AT);
+        escrow.invalidate_nonce(0).expect("invalidate");
+        assert_eq!(escrow.get_nonce(maker()), 1);
+        escrow.invalidate_nonce(5).expect("invalidate");
+        assert_eq!(escrow.get_nonce(maker()), 6);
+
+        // Retired nonces stay retired, and only the caller's own nonces move
+        assert_eq!(escrow.invalidate_nonce(5), Err(Error::NonceAlreadyUsed));
+        assert_eq!(escrow.invalidate_nonce(2), Err(Error::NonceAlreadyUsed));
+        assert_eq!(escrow.get_nonce(maker()), 6);
+        assert_eq!(escrow.get_nonce(stranger()), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
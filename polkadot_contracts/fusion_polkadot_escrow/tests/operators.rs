@@ -0,0 +1,76 @@
+//! Operators: a resolver proposes a hot key, which binds only once it accepts, and never while
+//! it is a resolver itself or already acts for another one.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, FusionPolkadotEscrow};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+fn other_resolver() -> AccountId {
+    stranger()
+}
+
+fn operator() -> AccountId {
+    AccountId::from([0x05; 32])
+}
+
+fn escrow() -> FusionPolkadotEscrow {
+    let mut escrow = common::escrow(1_000);
+    escrow.approve_resolver(other_resolver()).expect("approve");
+    escrow
+}
+
+#[test]
+fn operator_binds_only_after_accepting() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.propose_operator(Some(operator())).expect("propose");
+        assert_eq!(escrow.get_operator(resolver()), None);
+        assert_eq!(escrow.get_proposed_operator(resolver()), Some(operator()));
+
+        // Only the proposed key can accept, and only for the resolver that proposed it
+        call_as(other_resolver(), 0, CREATED_AT);
+        assert_eq!(escrow.accept_operator(resolver()), Err(Error::Unauthorized));
+        call_as(operator(), 0, CREATED_AT);
+        assert_eq!(escrow.accept_operator(other_resolver()), Err(Error::Unauthorized));
+
+        escrow.accept_operator(resolver()).expect("accept");
+        assert_eq!(escrow.get_operator(resolver()), Some(operator()));
+        assert_eq!(escrow.get_proposed_operator(resolver()), None);
+
+        // Revoking unbinds at once
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.propose_operator(None).expect("revoke");
+        assert_eq!(escrow.get_operator(resolver()), None);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn resolvers_and_bound_operators_cannot_become_operators() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+
+        call_as(resolver(), 0, CREATED_AT);
+        assert_eq!(escrow.propose_operator(Some(other_resolver())), Err(Error::OperatorInUse));
+        assert_eq!(escrow.propose_operator(Some(resolver())), Err(Error::OperatorInUse));
+
+        escrow.propose_operator(Some(operator())).expect("propose");
+        call_as(other_resolver(), 0, CREATED_AT);
+        escrow.propose_operator(Some(operator())).expect("competing proposal");
+        call_as(operator(), 0, CREATED_AT);
+        escrow.accept_operator(resolver()).expect("accept");
+        assert_eq!(escrow.accept_operator(other_resolver()), Err(Error::OperatorInUse));
+
+        // A bound operator cannot be made a resolver either
+        call_as(owner(), 0, CREATED_AT);
+        assert_eq!(escrow.approve_resolver(operator()), Err(Error::OperatorInUse));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,77 @@
+//! Migration snapshot: `export_orders` pages through the live orders and `state_root` commits
+//! to exactly what the pages return.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, ExportedOrder, FusionPolkadotEscrow, MAX_PAGE_LEN};
+use ink::env::test;
+use scale::Encode;
+
+/// Root the deployer tool recomputes from exported pages
+fn root_of(orders: &[ExportedOrder]) -> [u8; 32] {
+    let leaves: Vec<[u8; 32]> = orders.iter().map(|exported| blake2x256(&exported.encode())).collect();
+    blake2x256(&leaves.encode())
+}
+
+/// Fresh contract holding `count` pending orders
+fn escrow_with_orders(count: u128) -> (FusionPolkadotEscrow, Vec<[u8; 32]>) {
+    let mut escrow = escrow(1_000);
+    let order_hashes = (1..=count)
+        .map(|i| create_order(&mut escrow, CreateOrderParams { src_amount: 10_000 * i, ..order_params() }))
+        .collect();
+    (escrow, order_hashes)
+}
+
+#[test]
+fn pages_cover_every_live_order_once() {
+    test::run_test::<Env, _>(|_| {
+        let (escrow, order_hashes) = escrow_with_orders(3);
+
+        let mut exported = escrow.export_orders(0, 2);
+        assert_eq!(exported.len(), 2);
+        exported.extend(escrow.export_orders(2, 2));
+        let exported_hashes: Vec<[u8; 32]> = exported.iter().map(|exported| exported.order.order_hash).collect();
+        assert_eq!(exported_hashes, order_hashes);
+        for exported in exported.iter() {
+            assert_eq!(escrow.get_order(exported.order.order_hash).as_ref(), Some(&exported.order));
+        }
+
+        assert_eq!(escrow.export_orders(3, 2), Vec::new());
+        assert_eq!(escrow.export_orders(0, 0), Vec::new());
+        assert_eq!(escrow.export_orders(0, u32::MAX).len(), 3);
+        assert_eq!(escrow.state_root(0, MAX_PAGE_LEN), root_of(&exported));
+        assert_eq!(escrow.state_root(2, 2), root_of(&exported[2..]));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn page_size_is_capped() {
+    test::run_test::<Env, _>(|_| {
+        let (escrow, _) = escrow_with_orders(MAX_PAGE_LEN as u128 + 1);
+        assert_eq!(escrow.export_orders(0, u32::MAX).len(), MAX_PAGE_LEN as usize);
+        assert_eq!(escrow.export_orders(MAX_PAGE_LEN, u32::MAX).len(), 1);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn root_tracks_the_live_set() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hashes) = escrow_with_orders(2);
+        let before = escrow.state_root(0, MAX_PAGE_LEN);
+
+        call_as(maker(), 0, CREATED_AT);
+        escrow.cancel_order(order_hashes[0]).expect("cancel");
+        let live = escrow.export_orders(0, MAX_PAGE_LEN);
+        assert_eq!(live.len(), 1);
+        assert_eq!(live[0].order.order_hash, order_hashes[1]);
+        assert_ne!(escrow.state_root(0, MAX_PAGE_LEN), before);
+        assert_eq!(escrow.state_root(0, MAX_PAGE_LEN), root_of(&live));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,176 @@
+//! Migration round trip: orders exported from one deployment import into a fresh one with the
+//! same state root, until the import window closes.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, ExecutorTip, ExportedOrder, FusionPolkadotEscrow, OrderStatus, MAX_PAGE_LEN,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const TIP_BUDGET: u128 = 300;
+const COLLATERAL: u128 = 5_000;
+
+fn old_contract() -> AccountId {
+    contract()
+}
+
+fn new_contract() -> AccountId {
+    AccountId::from([0xc1; 32])
+}
+
+/// `call_as` against one of the two deployments
+fn call_on(contract: AccountId, caller: AccountId, value: u128) {
+    test::set_caller::<Env>(caller);
+    test::set_callee::<Env>(contract);
+    test::set_block_timestamp::<Env>(CREATED_AT);
+    test::transfer_in::<Env>(value);
+}
+
+fn deploy(contract: AccountId) -> FusionPolkadotEscrow {
+    test::set_account_balance::<Env>(contract, 0);
+    call_on(contract, owner(), 0);
+    let mut escrow = FusionPolkadotEscrow::new(0, SAFETY_DEPOSIT, 1, [0u8; 20]);
+    escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+    escrow.approve_resolver(resolver()).expect("approve");
+    escrow
+}
+
+fn create_order_on(escrow: &mut FusionPolkadotEscrow, contract: AccountId, src_amount: u128) -> [u8; 32] {
+    call_on(contract, maker(), src_amount);
+    escrow.create_order(CreateOrderParams { src_amount, ..order_params() }).expect("create order")
+}
+
+/// Old deployment with one tipped pending order and one underwritten locked order, exported
+/// in full
+fn exported_orders() -> (Vec<ExportedOrder>, [u8; 32]) {
+    fund_accounts();
+    let mut old = deploy(old_contract());
+    call_on(old_contract(), maker(), 10_000 + TIP_BUDGET);
+    let tip = ExecutorTip { per_action: 100, remaining: TIP_BUDGET };
+    old.create_order(CreateOrderParams { src_amount: 10_000, executor_tip: Some(tip), ..order_params() })
+        .expect("create order");
+    let locked = create_order_on(&mut old, old_contract(), 20_000);
+
+    call_on(old_contract(), resolver(), SAFETY_DEPOSIT);
+    old.deploy_escrow(locked, resolver_params([0x42; 32])).expect("deploy escrow");
+    call_on(old_contract(), resolver(), 0);
+    old.approve_underwriter(locked, stranger(), 0).expect("approve underwriter");
+    call_on(old_contract(), stranger(), COLLATERAL);
+    old.underwrite(locked).expect("underwrite");
+    (old.export_orders(0, MAX_PAGE_LEN), old.state_root(0, MAX_PAGE_LEN))
+}
+
+/// Native value the exported orders owe: both source amounts, the tip budget, the locked
+/// order's deposit and its underwriting collateral
+const OBLIGATIONS: u128 = 10_000 + 20_000 + TIP_BUDGET + SAFETY_DEPOSIT + COLLATERAL;
+
+#[test]
+fn exported_orders_import_with_the_same_root() {
+    test::run_test::<Env, _>(|_| {
+        let (orders, root) = exported_orders();
+        let mut new = deploy(new_contract());
+
+        call_on(new_contract(), owner(), OBLIGATIONS);
+        assert_eq!(new.import_orders(orders.clone()), Ok(2));
+        assert_eq!(new.state_root(0, MAX_PAGE_LEN), root);
+        assert_eq!(new.export_orders(0, MAX_PAGE_LEN), orders);
+        for exported in orders.iter() {
+            assert_eq!(new.get_order(exported.order.order_hash).as_ref(), Some(&exported.order));
+        }
+        assert_eq!(new.get_order_count_by_status(OrderStatus::Locked), 1);
+        assert_eq!(new.get_maker_order_count(maker()), 2);
+        let ledger = new.reconcile(None).expect("reconcile");
+        assert_eq!(
+            (ledger.ledger.escrowed, ledger.ledger.deposits, ledger.ledger.tips),
+            (30_000, SAFETY_DEPOSIT + COLLATERAL, TIP_BUDGET)
+        );
+        assert_eq!(ledger.shortfall, 0);
+
+        // Escrow addresses stay behind, so the migrated locked order cannot `withdraw`
+        let locked = orders.iter().find(|exported| exported.order.status == OrderStatus::Locked).expect("locked");
+        assert_eq!(
+            new.check_executable(locked.order.order_hash, [0x42; 32], locked.order.src_amount),
+            Err(Error::EscrowNotFound)
+        );
+
+        // The migrated pending order behaves like a local one
+        let pending = orders.iter().find(|exported| exported.order.status == OrderStatus::Pending).expect("pending");
+        call_on(new_contract(), maker(), 0);
+        new.cancel_order(pending.order.order_hash).expect("cancel");
+        assert_eq!(new.get_asset_ledger(None).escrowed, 20_000);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn bad_batches_are_rejected() {
+    test::run_test::<Env, _>(|_| {
+        let (orders, _) = exported_orders();
+        let mut executed = orders[0].clone();
+        executed.order.status = OrderStatus::Executed;
+        let mut underwritten_pending = orders[0].clone();
+        underwritten_pending.underwriting = orders[1].underwriting.clone();
+
+        let cases = [
+            ("not the owner", maker(), OBLIGATIONS, orders.clone(), Error::OnlyOwner),
+            (
+                "underfunded",
+                owner(),
+                OBLIGATIONS - 1,
+                orders.clone(),
+                Error::InsufficientFundsV2 { required: OBLIGATIONS, provided: OBLIGATIONS - 1 },
+            ),
+            ("overpaid", owner(), OBLIGATIONS + 1, orders.clone(), Error::InvalidAmount),
+            ("settled order", owner(), 10_000 + TIP_BUDGET, vec![executed], Error::InvalidOrderStatus),
+            (
+                "underwritten pending order",
+                owner(),
+                10_000 + TIP_BUDGET + COLLATERAL,
+                vec![underwritten_pending],
+                Error::InvalidOrderStatus,
+            ),
+            (
+                "duplicate",
+                owner(),
+                2 * (10_000 + TIP_BUDGET),
+                vec![orders[0].clone(), orders[0].clone()],
+                Error::OrderAlreadyExists,
+            ),
+        ];
+        for (name, caller, value, batch, expected) in cases {
+            let mut new = deploy(new_contract());
+            call_on(new_contract(), caller, value);
+            assert_eq!(new.import_orders(batch), Err(expected), "{name}");
+        }
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn import_closes_for_good() {
+    test::run_test::<Env, _>(|_| {
+        let (orders, _) = exported_orders();
+
+        let mut finalized = deploy(new_contract());
+        assert_eq!(finalized.finalize_import(), Ok(()));
+        assert!(!finalized.is_import_open());
+        call_on(new_contract(), owner(), OBLIGATIONS);
+        assert_eq!(finalized.import_orders(orders.clone()), Err(Error::ImportClosed));
+        assert_eq!(finalized.finalize_import(), Err(Error::ImportClosed));
+
+        let mut live = deploy(new_contract());
+        assert!(live.is_import_open());
+        create_order_on(&mut live, new_contract(), 5_000);
+        assert!(!live.is_import_open());
+        call_on(new_contract(), owner(), OBLIGATIONS);
+        assert_eq!(live.import_orders(orders), Err(Error::ImportClosed));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,79 @@
+//! Order discovery: the per-maker and per-status indexes follow every status change and drop
+//! orders on archival.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, FusionPolkadotEscrow, OrderStatus, DEFAULT_ARCHIVE_RETENTION,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+/// Two makers
+fn alice() -> AccountId {
+    maker()
+}
+
+fn bob() -> AccountId {
+    stranger()
+}
+
+fn create_order_as(escrow: &mut FusionPolkadotEscrow, maker: AccountId, src_amount: u128) -> [u8; 32] {
+    call_as(maker, src_amount, CREATED_AT);
+    escrow.create_order(CreateOrderParams { src_amount, ..order_params() }).expect("create order")
+}
+
+fn hashes_by_maker(escrow: &FusionPolkadotEscrow, maker: AccountId, offset: u32, limit: u32) -> Vec<[u8; 32]> {
+    escrow.get_orders_by_maker(maker, offset, limit).iter().map(|order| order.order_hash).collect()
+}
+
+fn hashes_by_status(escrow: &FusionPolkadotEscrow, status: OrderStatus) -> Vec<[u8; 32]> {
+    escrow.get_orders_by_status(status, 0, u32::MAX).iter().map(|order| order.order_hash).collect()
+}
+
+#[test]
+fn orders_are_listed_by_maker_and_status() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1_000);
+        let first = create_order_as(&mut escrow, alice(), 10_000);
+        let second = create_order_as(&mut escrow, alice(), 20_000);
+        let third = create_order_as(&mut escrow, bob(), 30_000);
+
+        assert_eq!(escrow.get_maker_order_count(alice()), 2);
+        assert_eq!(hashes_by_maker(&escrow, alice(), 0, 1), vec![first]);
+        assert_eq!(hashes_by_maker(&escrow, alice(), 1, 1), vec![second]);
+        assert_eq!(hashes_by_maker(&escrow, alice(), 2, 1), Vec::<[u8; 32]>::new());
+        assert_eq!(hashes_by_maker(&escrow, bob(), 0, 10), vec![third]);
+        assert_eq!(hashes_by_status(&escrow, OrderStatus::Pending), vec![first, second, third]);
+        assert_eq!(escrow.get_order_count_by_status(OrderStatus::Cancelled), 0);
+
+        call_as(alice(), 0, CREATED_AT);
+        escrow.cancel_order(first).expect("cancel");
+        assert_eq!(hashes_by_status(&escrow, OrderStatus::Pending), vec![third, second]);
+        assert_eq!(hashes_by_status(&escrow, OrderStatus::Cancelled), vec![first]);
+        assert_eq!(escrow.get_maker_order_count(alice()), 2);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn archival_drops_orders_from_the_indexes() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1_000);
+        let cancelled = create_order_as(&mut escrow, alice(), 10_000);
+        let pending = create_order_as(&mut escrow, alice(), 20_000);
+
+        call_as(alice(), 0, CREATED_AT);
+        escrow.cancel_order(cancelled).expect("cancel");
+        call_as(alice(), 0, CREATED_AT + DEFAULT_ARCHIVE_RETENTION);
+        escrow.archive_order(cancelled).expect("archive");
+
+        assert_eq!(hashes_by_maker(&escrow, alice(), 0, 10), vec![pending]);
+        assert_eq!(escrow.get_order_count_by_status(OrderStatus::Cancelled), 0);
+        assert_eq!(hashes_by_status(&escrow, OrderStatus::Pending), vec![pending]);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
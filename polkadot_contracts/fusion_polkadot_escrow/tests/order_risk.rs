@@ -0,0 +1,94 @@
+//! `get_order_risk`: each component moves with its signal, and settled orders have no score.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{CreateOrderParams, FusionPolkadotEscrow, OrderRisk, RiskLevel};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 5_000; // 5% of SRC_AMOUNT, half the reference ratio
+
+const FILL_DEADLINE: u64 = CREATED_AT + 55 * MINUTE;
+const EXECUTION_CUTOFF: u64 = FILL_DEADLINE + 5 * MINUTE; // Default grace period
+
+/// Fresh contract with one pending order; the resolver has bonded `stake`
+fn pending_order(stake: u128) -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    escrow.set_min_resolver_stake(1).expect("min stake");
+    call_as(resolver(), stake, CREATED_AT);
+    escrow.register_resolver().expect("register");
+
+    let order_hash = create_order(&mut escrow, CreateOrderParams { fill_deadline: FILL_DEADLINE, ..order_params() });
+    (escrow, order_hash)
+}
+
+fn lock(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32]) {
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params([0x42; 32])).expect("deploy escrow");
+}
+
+#[test]
+fn pending_order_has_no_backing_yet() {
+    test::run_test::<Env, _>(|_| {
+        let (escrow, order_hash) = pending_order(1);
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(
+            escrow.get_order_risk(order_hash),
+            Some(OrderRisk {
+                level: RiskLevel::High,
+                score_bps: 7_500,
+                resolver_reputation: None,
+                reputation_risk_bps: 10_000,
+                deposit_ratio_bps: 0,
+                deposit_risk_bps: 10_000,
+                time_remaining: EXECUTION_CUTOFF - CREATED_AT,
+                time_risk_bps: 0,
+                size_risk_bps: 10_000,
+            }),
+        );
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn locked_order_components_follow_their_signals() {
+    // (stake, at) -> (size risk, time risk); reputation stays unknown without a coordinator
+    let cases = [
+        ("fully staked, fresh", SRC_AMOUNT, CREATED_AT, 0, 0, RiskLevel::Medium),
+        ("quarter staked, fresh", SRC_AMOUNT / 4, CREATED_AT, 7_500, 0, RiskLevel::Medium),
+        ("fully staked, half way", SRC_AMOUNT, CREATED_AT + 30 * MINUTE, 0, 5_000, RiskLevel::Medium),
+        ("barely staked, at cutoff", 1, EXECUTION_CUTOFF, 10_000, 10_000, RiskLevel::High),
+    ];
+    for (name, stake, at, size_risk_bps, time_risk_bps, level) in cases {
+        test::run_test::<Env, _>(|_| {
+            let (mut escrow, order_hash) = pending_order(stake);
+            lock(&mut escrow, order_hash);
+
+            call_as(maker(), 0, at);
+            let risk = escrow.get_order_risk(order_hash).expect(name);
+            assert_eq!(risk.deposit_ratio_bps, 500, "{name}");
+            assert_eq!(risk.deposit_risk_bps, 5_000, "{name}");
+            assert_eq!(risk.reputation_risk_bps, 10_000, "{name}");
+            assert_eq!(risk.size_risk_bps, size_risk_bps, "{name}");
+            assert_eq!(risk.time_risk_bps, time_risk_bps, "{name}");
+            assert_eq!(risk.score_bps, (15_000 + size_risk_bps + time_risk_bps) / 4, "{name}");
+            assert_eq!(risk.level, level, "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn settled_orders_have_no_score() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = pending_order(1);
+        call_as(maker(), 0, CREATED_AT);
+        escrow.cancel_order(order_hash).expect("cancel");
+        assert_eq!(escrow.get_order_risk(order_hash), None);
+        assert_eq!(escrow.get_order_risk([0x99; 32]), None);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
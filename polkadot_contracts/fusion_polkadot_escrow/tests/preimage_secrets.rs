@@ -0,0 +1,65 @@
+//! Preimages that are not 32 bytes: `execute_swap_with_preimage` unlocks an order whose hash
+//! lock is over a shorter or longer secret, refuses empty and oversized preimages, and records
+//! the revealed preimage on the order.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FusionPolkadotEscrow, HashAlgo, OrderStatus, MAX_SECRET_LEN,
+};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+/// Fresh contract with one order locked under `hash_algo` of `preimage`, at its withdrawal stage
+fn escrow_locked_on(hash_algo: HashAlgo, preimage: &[u8]) -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    let hash_lock = hash_algo.hash(preimage);
+    let order_hash = create_order(&mut escrow, CreateOrderParams { hash_algo, ..order_params() });
+
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params(hash_lock)).expect("lock");
+    let src_withdrawal = escrow.get_order(order_hash).expect("order").time_locks.src_withdrawal;
+    call_as(resolver(), 0, src_withdrawal);
+    (escrow, order_hash)
+}
+
+#[test]
+fn preimages_of_any_allowed_length_unlock_the_order() {
+    for hash_algo in [HashAlgo::Blake2x256, HashAlgo::Keccak256, HashAlgo::Sha256] {
+        for preimage in [vec![0x5e], vec![0x5e; 20], vec![0x5e; MAX_SECRET_LEN]] {
+            test::run_test::<Env, _>(|_| {
+                let (mut escrow, order_hash) = escrow_locked_on(hash_algo.clone(), &preimage);
+                escrow.execute_swap_with_preimage(order_hash, preimage.clone()).expect("execute");
+
+                let order = escrow.get_order(order_hash).expect("order");
+                assert_eq!(order.status, OrderStatus::Executed);
+                assert_eq!(order.hash_lock_info.secret, Some(preimage.clone()));
+                assert_eq!(escrow.get_claimable(resolver(), None), SRC_AMOUNT + SAFETY_DEPOSIT);
+                Ok(())
+            })
+            .expect("off-chain env");
+        }
+    }
+}
+
+#[test]
+fn wrong_empty_and_oversized_preimages_are_refused() {
+    test::run_test::<Env, _>(|_| {
+        let preimage = vec![0x5e; 20];
+        let (mut escrow, order_hash) = escrow_locked_on(HashAlgo::Keccak256, &preimage);
+
+        assert_eq!(escrow.execute_swap_with_preimage(order_hash, Vec::new()), Err(Error::InvalidLength));
+        assert_eq!(escrow.execute_swap_with_preimage(order_hash, vec![0x5e; MAX_SECRET_LEN + 1]), Err(Error::InvalidLength));
+        assert_eq!(escrow.execute_swap_with_preimage(order_hash, vec![0x5e; 21]), Err(Error::InvalidSecret));
+        // The same bytes padded to 32 are a different secret
+        let mut padded = [0u8; 32];
+        padded[..20].copy_from_slice(&preimage);
+        assert_eq!(escrow.execute_swap(order_hash, padded), Err(Error::InvalidSecret));
+
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Locked);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+//! `prune_expired_orders`: expired orders are refunded, archived and pay the sweeper once; live
+//! or unknown ones are skipped.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FusionPolkadotEscrow, KeeperConfig, OrderStatus, ORDER_STORAGE_BASE_BYTES,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const STORAGE_PRICE: u128 = 100;
+const STORAGE_COST: u128 = STORAGE_PRICE * (ORDER_STORAGE_BASE_BYTES as u128 + 2); // Empty basket and blocked list
+const MAX_BOUNTY: u128 = 500; // Below the 1% bounty on STORAGE_COST
+
+const HOUR: u64 = 60 * MINUTE;
+
+fn sweeper() -> AccountId {
+    stranger()
+}
+
+fn escrow() -> FusionPolkadotEscrow {
+    let mut escrow = common::escrow(SAFETY_DEPOSIT);
+    escrow
+        .set_keeper_config(KeeperConfig { bounty_bps: 100, max_bounty: MAX_BOUNTY, allow_list_only: false })
+        .expect("keeper config");
+    escrow.set_cancellation_tip(100).expect("cancellation tip");
+    escrow.set_storage_deposit_per_byte(STORAGE_PRICE).expect("storage price");
+    escrow
+}
+
+fn create_expiring_order(escrow: &mut FusionPolkadotEscrow, fill_deadline: u64) -> [u8; 32] {
+    call_as(maker(), SRC_AMOUNT + STORAGE_COST, CREATED_AT);
+    escrow.create_order(CreateOrderParams { fill_deadline, ..order_params() }).expect("create order")
+}
+
+fn lock(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32]) {
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params([0x42; 32])).expect("deploy escrow");
+}
+
+#[test]
+fn expired_orders_are_refunded_and_archived() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        let expired = create_expiring_order(&mut escrow, CREATED_AT + HOUR);
+        let timed_out = create_expiring_order(&mut escrow, CREATED_AT + HOUR);
+        let live = create_expiring_order(&mut escrow, CREATED_AT + 24 * HOUR);
+        lock(&mut escrow, timed_out);
+        let sweep_at = escrow.get_order(timed_out).expect("order").time_locks.src_public_cancellation;
+
+        call_as(sweeper(), 0, sweep_at);
+        assert_eq!(escrow.prune_expired_orders(vec![expired, live, timed_out, [0x99; 32]]), Ok(2));
+
+        for order_hash in [expired, timed_out] {
+            assert_eq!(escrow.get_order(order_hash), None);
+            let archived = escrow.get_archived_order(order_hash).expect("archived");
+            assert_eq!(archived.status, OrderStatus::Cancelled);
+        }
+        assert_eq!(escrow.get_order(live).expect("live order").status, OrderStatus::Pending);
+
+        // The expired pending order pays the sweep out of its storage deposit only; the timed-out
+        // one pays the cancellation tip out of the resolver's deposit and no bounty on top
+        let owed_to_maker = 2 * (SRC_AMOUNT + STORAGE_COST) - MAX_BOUNTY + SAFETY_DEPOSIT / 2 - 5;
+        assert_eq!(escrow.get_claimable(maker(), None), owed_to_maker);
+        assert_eq!(escrow.get_claimable(sweeper(), None), MAX_BOUNTY + 10);
+
+        let reconciliation = escrow.reconcile(None).expect("reconcile");
+        assert_eq!(reconciliation.ledger.escrowed, SRC_AMOUNT);
+        assert_eq!(reconciliation.shortfall, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn makers_prune_their_own_orders_without_a_bounty() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        let order_hash = create_expiring_order(&mut escrow, CREATED_AT + HOUR);
+
+        call_as(maker(), 0, CREATED_AT + HOUR + 1);
+        assert_eq!(escrow.prune_expired_orders(vec![order_hash, order_hash]), Ok(1));
+        assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT + STORAGE_COST);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn batches_are_bounded() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        call_as(sweeper(), 0, CREATED_AT);
+        assert_eq!(escrow.prune_expired_orders(vec![[0x99; 32]; 51]), Err(Error::InvalidLength));
+        assert_eq!(escrow.prune_expired_orders(Vec::new()), Ok(0));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
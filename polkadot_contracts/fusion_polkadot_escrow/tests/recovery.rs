@@ -0,0 +1,113 @@
+//! Social recovery: an M-of-N council rotates the owner once enough members approved and the
+//! delay elapsed, and the current owner can veto a pending recovery in the meantime.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, FusionPolkadotEscrow, MIN_RECOVERY_DELAY};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+fn member(index: u8) -> AccountId {
+    AccountId::from([0x60 + index; 32])
+}
+
+fn new_owner() -> AccountId {
+    AccountId::from([0x6f; 32])
+}
+
+/// Fresh contract with a 2-of-3 council
+fn escrow_with_council() -> FusionPolkadotEscrow {
+    let mut escrow = escrow(0);
+    escrow
+        .set_recovery_council(vec![member(0), member(1), member(2)], 2, MIN_RECOVERY_DELAY)
+        .expect("council");
+    escrow
+}
+
+#[test]
+fn council_config_is_validated() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(0);
+        let members = vec![member(0), member(1)];
+        for (threshold, delay) in [(0, MIN_RECOVERY_DELAY), (3, MIN_RECOVERY_DELAY), (1, MIN_RECOVERY_DELAY - 1)] {
+            assert_eq!(escrow.set_recovery_council(members.clone(), threshold, delay), Err(Error::InvalidRecoveryConfig));
+        }
+        assert_eq!(
+            escrow.set_recovery_council(vec![member(0), member(0)], 1, MIN_RECOVERY_DELAY),
+            Err(Error::InvalidRecoveryConfig),
+        );
+
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.set_recovery_council(members, 1, MIN_RECOVERY_DELAY), Err(Error::OnlyOwner));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn recovery_needs_the_threshold_and_the_delay() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow_with_council();
+
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.propose_recovery(stranger()), Err(Error::OnlyRecoveryCouncil));
+        assert_eq!(escrow.execute_recovery(), Err(Error::RecoveryNotPending));
+
+        // The proposer's approval counts, once
+        call_as(member(0), 0, CREATED_AT);
+        escrow.propose_recovery(new_owner()).expect("propose");
+        assert_eq!(escrow.propose_recovery(stranger()), Err(Error::RecoveryAlreadyPending));
+        assert_eq!(escrow.approve_recovery(), Err(Error::RecoveryAlreadyApproved));
+
+        let unlocks_at = CREATED_AT + MIN_RECOVERY_DELAY;
+        call_as(stranger(), 0, unlocks_at);
+        assert_eq!(escrow.execute_recovery(), Err(Error::RecoveryThresholdNotMet));
+
+        call_as(member(1), 0, CREATED_AT + MINUTE);
+        escrow.approve_recovery().expect("approve");
+        assert_eq!(
+            escrow.execute_recovery(),
+            Err(Error::TimelockNotExpiredV2 { unlocks_at, current_time: CREATED_AT + MINUTE }),
+        );
+
+        // Anyone finalizes once both conditions hold
+        call_as(stranger(), 0, unlocks_at);
+        escrow.execute_recovery().expect("execute");
+        assert_eq!(escrow.get_owner(), new_owner());
+        assert_eq!(escrow.execute_recovery(), Err(Error::RecoveryNotPending));
+
+        call_as(owner(), 0, unlocks_at);
+        assert_eq!(escrow.set_relayer_quorum(1), Err(Error::OnlyOwner));
+        call_as(new_owner(), 0, unlocks_at);
+        escrow.set_relayer_quorum(1).expect("new owner administers");
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn owner_vetoes_a_pending_recovery() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow_with_council();
+
+        call_as(member(0), 0, CREATED_AT);
+        escrow.propose_recovery(new_owner()).expect("propose");
+        call_as(member(1), 0, CREATED_AT);
+        escrow.approve_recovery().expect("approve");
+
+        call_as(member(2), 0, CREATED_AT);
+        assert_eq!(escrow.cancel_recovery(), Err(Error::OnlyOwner));
+        call_as(owner(), 0, CREATED_AT);
+        escrow.cancel_recovery().expect("cancel");
+        assert_eq!(escrow.cancel_recovery(), Err(Error::RecoveryNotPending));
+
+        call_as(stranger(), 0, CREATED_AT + MIN_RECOVERY_DELAY);
+        assert_eq!(escrow.execute_recovery(), Err(Error::RecoveryNotPending));
+        call_as(member(1), 0, CREATED_AT + MIN_RECOVERY_DELAY);
+        assert_eq!(escrow.approve_recovery(), Err(Error::RecoveryNotPending));
+        assert_eq!(escrow.get_owner(), owner());
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,100 @@
+//! Relayer-initiated refunds: a locked order is refunded to its maker as soon as a quorum of
+//! currently trusted relayers report the Ethereum leg failed, and a relayer removed after
+//! reporting no longer counts toward the quorum.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, FusionPolkadotEscrow, OrderStatus};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+fn relayer(index: u8) -> AccountId {
+    AccountId::from([0x50 + index; 32])
+}
+
+/// Fresh contract with three trusted relayers, a quorum of two, and one order locked by the
+/// approved resolver
+fn escrow_with_locked_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    for index in 0..3 {
+        escrow.add_trusted_relayer(relayer(index)).expect("add relayer");
+    }
+    escrow.set_relayer_quorum(2).expect("quorum");
+    let order_hash = create_order(&mut escrow, order_params());
+
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params(blake2x256(&SECRET))).expect("lock");
+    (escrow, order_hash)
+}
+
+#[test]
+fn quorum_of_trusted_relayers_refunds_the_maker() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+
+        call_as(stranger(), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Err(Error::Unauthorized));
+
+        // Below the quorum nothing moves, and a relayer reports only once
+        call_as(relayer(0), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, b"tx reverted".to_vec()), Ok(false));
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Err(Error::RefundAlreadyAttested));
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Locked);
+        assert_eq!(escrow.get_claimable(maker(), None), 0);
+
+        // The quorum refunds the maker before any timelock expires
+        call_as(relayer(1), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Ok(true));
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Cancelled);
+        assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT);
+        assert_eq!(escrow.get_asset_ledger(None).escrowed, 0);
+        assert_eq!(escrow.reconcile(None).expect("reconcile").shortfall, 0);
+
+        call_as(relayer(2), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Err(Error::InvalidOrderStatus));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn removed_relayers_no_longer_count() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+
+        call_as(relayer(0), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Ok(false));
+        call_as(owner(), 0, CREATED_AT + MINUTE);
+        escrow.remove_trusted_relayer(relayer(0)).expect("remove relayer");
+
+        // The removed relayer's report no longer makes a quorum with a second one
+        call_as(relayer(1), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Ok(false));
+        assert_eq!(escrow.get_order(order_hash).expect("order").status, OrderStatus::Locked);
+        call_as(relayer(0), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Err(Error::Unauthorized));
+
+        call_as(relayer(2), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Ok(true));
+        assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn zero_quorum_disables_relayer_refunds() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_locked_order();
+
+        call_as(owner(), 0, CREATED_AT + MINUTE);
+        escrow.set_relayer_quorum(0).expect("quorum");
+        call_as(relayer(0), 0, CREATED_AT + MINUTE);
+        assert_eq!(escrow.relayer_initiated_refund(order_hash, Vec::new()), Err(Error::Unauthorized));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
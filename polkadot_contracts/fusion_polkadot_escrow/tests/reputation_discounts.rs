@@ -0,0 +1,91 @@
+//! Reputation-based taker-fee discounts: tier validation, full fees when no coordinator can
+//! vouch for the resolver, and the cap at what the resolver is paid.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FusionPolkadotEscrow, HashAlgo, ReputationDiscount, SwapDirection,
+};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const TAKER_FEE: u128 = 500; // 50 bps of SRC_AMOUNT
+
+fn discount(min_reputation: u32, discount_bps: u32) -> ReputationDiscount {
+    ReputationDiscount { min_reputation, discount_bps }
+}
+
+fn escrow() -> FusionPolkadotEscrow {
+    let mut escrow = common::escrow(SAFETY_DEPOSIT);
+    escrow.set_taker_fee_bps(50).expect("taker fee");
+    escrow
+}
+
+#[test]
+fn discount_tiers_must_ascend() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        let tiers = vec![discount(50, 1_000), discount(100, 2_500)];
+        let cases = [
+            ("over 100%", vec![discount(50, 10_001)], Error::InvalidAmount),
+            ("not ascending", vec![discount(100, 1_000), discount(100, 2_500)], Error::InvalidAmount),
+            ("too many", vec![discount(50, 1_000); 5], Error::InvalidLength),
+        ];
+        for (name, discounts, expected) in cases {
+            assert_eq!(escrow.set_reputation_discounts(discounts), Err(expected), "{name}");
+        }
+        assert_eq!(escrow.set_reputation_discounts(tiers.clone()), Ok(()));
+        assert_eq!(escrow.get_reputation_discounts(), tiers);
+
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(escrow.set_reputation_discounts(Vec::new()), Err(Error::OnlyOwner));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn no_coordinator_means_no_discount() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        escrow.set_reputation_discounts(vec![discount(0, 10_000)]).expect("discounts");
+
+        let order_hash = create_order(&mut escrow, order_params());
+
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+        escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        assert_eq!(escrow.get_accrued_fees(None), TAKER_FEE);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn fee_above_the_resolver_payout_is_capped() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        escrow.set_src_token_decimals(native(), 18).expect("decimals");
+        // EthereumToPolkadot without a resolver fee: the resolver is paid nothing here
+        let order_hash = create_order(
+            &mut escrow,
+            CreateOrderParams { direction: SwapDirection::EthereumToPolkadot, ..order_params() },
+        );
+
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        let params = resolver_params(HashAlgo::Blake2x256.hash(&SECRET));
+        escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        // Settlement goes through; `TakerFeeCapped` reports the waived fee
+        assert_eq!(escrow.get_accrued_fees(None), 0);
+        assert_eq!(escrow.get_claimable(maker(), None), SRC_AMOUNT);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
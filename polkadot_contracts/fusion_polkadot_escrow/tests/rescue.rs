@@ -0,0 +1,88 @@
+//! Stray funds leave through an announced `rescue_funds` after the rescue delay, and only up to
+//! what the contract holds beyond its ledger.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FundsRescue, FusionPolkadotEscrow, DEFAULT_RESCUE_DELAY,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SRC_AMOUNT: u128 = 1_000_000;
+const STRAY: u128 = 5_000;
+
+const EXECUTABLE_AT: u64 = CREATED_AT + DEFAULT_RESCUE_DELAY;
+
+fn treasury() -> AccountId {
+    stranger()
+}
+
+/// Contract escrowing one open order, plus `STRAY` sent to it directly
+fn escrow() -> FusionPolkadotEscrow {
+    let mut escrow = common::escrow(1);
+    create_order(&mut escrow, CreateOrderParams { src_amount: SRC_AMOUNT, ..order_params() });
+
+    test::set_account_balance::<Env>(contract(), balance(contract()) + STRAY);
+    escrow
+}
+
+#[test]
+fn rescue_waits_for_the_delay() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        assert_eq!(escrow.reconcile(None).expect("reconcile").surplus, STRAY);
+
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(escrow.request_rescue(None, STRAY, maker()), Err(Error::OnlyOwner));
+
+        call_as(owner(), 0, CREATED_AT);
+        escrow.request_rescue(None, STRAY, treasury()).expect("request");
+        assert_eq!(
+            escrow.get_pending_rescue(),
+            Some(FundsRescue { token: None, amount: STRAY, recipient: treasury(), executable_at: EXECUTABLE_AT }),
+        );
+        assert_eq!(escrow.request_rescue(None, STRAY, treasury()), Err(Error::RescueAlreadyPending));
+
+        call_as(owner(), 0, EXECUTABLE_AT - 1);
+        assert_eq!(
+            escrow.rescue_funds(None, STRAY, treasury()),
+            Err(Error::TimelockNotExpiredV2 { unlocks_at: EXECUTABLE_AT, current_time: EXECUTABLE_AT - 1 }),
+        );
+
+        call_as(owner(), 0, EXECUTABLE_AT);
+        assert_eq!(escrow.rescue_funds(None, STRAY, owner()), Err(Error::RescueNotPending));
+        let before = balance(treasury());
+        assert_eq!(escrow.rescue_funds(None, STRAY, treasury()), Ok(()));
+        assert_eq!(balance(treasury()) - before, STRAY);
+        assert_eq!(escrow.get_pending_rescue(), None);
+
+        let reconciliation = escrow.reconcile(None).expect("reconcile");
+        assert_eq!(reconciliation.ledger.escrowed, SRC_AMOUNT);
+        assert_eq!(reconciliation.surplus, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn escrowed_funds_cannot_be_rescued() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow();
+        call_as(owner(), 0, CREATED_AT);
+        escrow.request_rescue(None, STRAY + SRC_AMOUNT, treasury()).expect("request");
+
+        call_as(owner(), 0, EXECUTABLE_AT);
+        assert_eq!(
+            escrow.rescue_funds(None, STRAY + SRC_AMOUNT, treasury()),
+            Err(Error::RescueExceedsSurplus { surplus: STRAY, amount: STRAY + SRC_AMOUNT }),
+        );
+
+        escrow.cancel_rescue().expect("cancel");
+        assert_eq!(escrow.cancel_rescue(), Err(Error::RescueNotPending));
+        assert_eq!(escrow.set_rescue_delay(0), Err(Error::InvalidTimelocks));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
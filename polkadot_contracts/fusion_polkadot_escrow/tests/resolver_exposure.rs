@@ -0,0 +1,30 @@
+//! A resolver's exposure sums its open orders in `DEFAULT_SRC_DECIMALS` units, whatever the
+//! decimals of their tokens, and clears when its last order settles.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{HashAlgo, DEFAULT_SRC_DECIMALS};
+use ink::env::test;
+
+#[test]
+fn exposure_is_counted_in_normalized_units() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        escrow.set_src_token_decimals(native(), DEFAULT_SRC_DECIMALS + 2).expect("decimals");
+        let order_hash = create_order(&mut escrow, order_params());
+
+        call_as(resolver(), 1, CREATED_AT);
+        escrow.deploy_escrow(order_hash, resolver_params(HashAlgo::Blake2x256.hash(&SECRET))).expect("deploy escrow");
+        assert_eq!(escrow.get_resolver_exposure(resolver()), SRC_AMOUNT / 100);
+
+        // Decimals changing under an open order leave no residue behind
+        call_as(owner(), 0, CREATED_AT);
+        escrow.set_src_token_decimals(native(), DEFAULT_SRC_DECIMALS).expect("decimals");
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        assert_eq!(escrow.get_resolver_exposure(resolver()), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
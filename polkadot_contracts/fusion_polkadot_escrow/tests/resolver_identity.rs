@@ -0,0 +1,129 @@
+//! Locking vets the identity making the call: an order can only be locked in the name of the
+//! caller's own resolver identity (itself, or the resolver it operates for). Only the vetted
+//! resolver holding the lock records the destination escrow.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, EscrowImmutables, FusionPolkadotEscrow, OrderStatus, ResolverParams, TimeLocks,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+
+fn operator() -> AccountId {
+    AccountId::from([0x05; 32])
+}
+
+/// Fresh contract with an approved resolver, its accepted operator and one pending order
+fn escrow_with_order(preferred_resolver: Option<AccountId>) -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    test::set_account_balance::<Env>(operator(), STARTING_BALANCE);
+
+    call_as(resolver(), 0, CREATED_AT);
+    escrow.propose_operator(Some(operator())).expect("propose operator");
+    call_as(operator(), 0, CREATED_AT);
+    escrow.accept_operator(resolver()).expect("accept operator");
+
+    let order_hash =
+        create_order(&mut escrow, CreateOrderParams { src_amount: 10_000, preferred_resolver, ..order_params() });
+    (escrow, order_hash)
+}
+
+fn params(resolver: AccountId) -> ResolverParams {
+    ResolverParams { resolver, ..resolver_params([0x42; 32]) }
+}
+
+#[test]
+fn strangers_cannot_lock_in_an_approved_resolvers_name() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order(None);
+
+        call_as(stranger(), SAFETY_DEPOSIT, CREATED_AT);
+        assert_eq!(escrow.deploy_escrow(order_hash, params(resolver())), Err(Error::Unauthorized));
+        // Naming itself gets the stranger vetted, and rejected, as itself
+        call_as(stranger(), SAFETY_DEPOSIT, CREATED_AT);
+        assert_eq!(escrow.deploy_escrow(order_hash, params(stranger())), Err(Error::ResolverRejected));
+        assert_eq!(escrow.get_order(order_hash).map(|order| order.status), Some(OrderStatus::Pending));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn operators_lock_as_their_resolver_only() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order(None);
+
+        call_as(operator(), SAFETY_DEPOSIT, CREATED_AT);
+        assert_eq!(escrow.deploy_escrow(order_hash, params(operator())), Err(Error::Unauthorized));
+        call_as(operator(), SAFETY_DEPOSIT, CREATED_AT);
+        escrow.deploy_escrow(order_hash, params(resolver())).expect("lock as resolver");
+        assert_eq!(escrow.get_order(order_hash).and_then(|order| order.resolver), Some(resolver()));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn only_the_preferred_resolver_itself_locks_during_exclusivity() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order(Some(resolver()));
+        call_as(owner(), 0, CREATED_AT);
+        escrow.approve_resolver(stranger()).expect("approve");
+
+        // Another approved resolver can neither borrow the preferred identity nor use its own
+        call_as(stranger(), SAFETY_DEPOSIT, CREATED_AT);
+        assert_eq!(escrow.deploy_escrow(order_hash, params(resolver())), Err(Error::Unauthorized));
+        call_as(stranger(), SAFETY_DEPOSIT, CREATED_AT);
+        assert_eq!(escrow.deploy_escrow(order_hash, params(stranger())), Err(Error::PreferredResolverOnly));
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        escrow.deploy_escrow(order_hash, params(resolver())).expect("preferred lock");
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+#[test]
+fn only_the_locking_resolver_records_the_destination_escrow() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order(None);
+        call_as(owner(), 0, CREATED_AT);
+        escrow.approve_resolver(AccountId::from([0x06; 32])).expect("approve");
+        let dst_immutables = EscrowImmutables {
+            order_hash,
+            hash_lock: [0x42; 32],
+            maker: maker(),
+            taker: resolver(),
+            token: native(),
+            amount: 10_000,
+            safety_deposit: SAFETY_DEPOSIT,
+            timelocks: TimeLocks::default(),
+            deployed_at: None,
+        };
+
+        call_as(resolver(), 0, CREATED_AT);
+        assert_eq!(escrow.deploy_dst(dst_immutables.clone(), 0), Err(Error::OnlyResolver));
+        call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+        escrow.deploy_escrow(order_hash, params(resolver())).expect("lock");
+        let source_escrow = escrow.get_escrow_address(order_hash);
+
+        for (caller, expected) in [
+            (stranger(), Error::ResolverRejected),
+            (AccountId::from([0x06; 32]), Error::OnlyResolver),
+        ] {
+            call_as(caller, 0, CREATED_AT);
+            assert_eq!(escrow.deploy_dst(dst_immutables.clone(), 0), Err(expected));
+        }
+        assert_eq!(escrow.get_escrow_address(order_hash), source_escrow);
+
+        // The resolver's operator records it in the resolver's name; the Ethereum escrow stays
+        call_as(operator(), 0, CREATED_AT);
+        escrow.deploy_dst(dst_immutables, 0).expect("deploy dst");
+        let ethereum_escrow = escrow.get_order(order_hash).and_then(|order| order.ethereum_escrow);
+        assert_eq!(ethereum_escrow.map(|info| info.escrow_address), Some([0xab; 20]));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
@@ -0,0 +1,42 @@
+//! Self-registered resolvers bond native stake; the stake stays bonded, and slashable, while
+//! the resolver holds any open order.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, HashAlgo, ResolverParams};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const STAKE: u128 = 10_000;
+const UNBONDING_PERIOD: u64 = 10 * MINUTE;
+
+fn staked_resolver() -> AccountId {
+    stranger()
+}
+
+#[test]
+fn open_orders_keep_the_stake_bonded() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        escrow.set_min_resolver_stake(STAKE).expect("min stake");
+        escrow.set_resolver_unbonding_period(UNBONDING_PERIOD).expect("unbonding period");
+        let order_hash = create_order(&mut escrow, order_params());
+
+        call_as(staked_resolver(), STAKE, CREATED_AT);
+        escrow.register_resolver().expect("register");
+        call_as(staked_resolver(), 1, CREATED_AT);
+        let params = ResolverParams { resolver: staked_resolver(), ..resolver_params(HashAlgo::Blake2x256.hash(&SECRET)) };
+        escrow.deploy_escrow(order_hash, params).expect("deploy escrow");
+
+        call_as(staked_resolver(), 0, CREATED_AT);
+        assert_eq!(escrow.unregister_resolver(), Err(Error::ResolverHasOpenOrders));
+
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        escrow.unregister_resolver().expect("unregister");
+        call_as(staked_resolver(), 0, CREATED_AT + UNBONDING_PERIOD);
+        assert_eq!(escrow.withdraw_resolver_stake(), Ok(STAKE));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
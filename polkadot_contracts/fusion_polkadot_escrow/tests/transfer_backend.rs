@@ -0,0 +1,72 @@
+//! Transfer backends: settlement payouts are credited and collected later under every backend;
+//! other payouts are sent on the spot unless the backend is pull payment.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{FusionPolkadotEscrow, TransferBackend};
+use ink::env::test;
+
+const STAKE: u128 = 10_000;
+
+/// Fresh contract on `backend` with one pending order, cancelled by its maker
+fn cancelled_order(backend: TransferBackend) -> FusionPolkadotEscrow {
+    fund_accounts();
+    call_as(owner(), 0, CREATED_AT);
+    let mut escrow = FusionPolkadotEscrow::new_with_transfer_backend(0, 1, 1, [0u8; 20], backend);
+    escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+    let order_hash = create_order(&mut escrow, order_params());
+
+    call_as(maker(), 0, CREATED_AT);
+    escrow.cancel_order(order_hash).expect("cancel");
+    escrow
+}
+
+#[test]
+fn direct_backend_pays_outside_settlement_immediately() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = cancelled_order(TransferBackend::Direct);
+        assert_eq!(balance(maker()), STARTING_BALANCE - SRC_AMOUNT);
+        assert_eq!(escrow.get_pending_payout(maker()), SRC_AMOUNT);
+        assert_eq!(escrow.get_config().transfer_backend, TransferBackend::Direct);
+
+        call_as(owner(), 0, CREATED_AT);
+        escrow.set_min_resolver_stake(STAKE).expect("min stake");
+        escrow.set_resolver_unbonding_period(0).expect("unbonding period");
+        call_as(stranger(), STAKE, CREATED_AT);
+        escrow.register_resolver().expect("register");
+        call_as(stranger(), 0, CREATED_AT);
+        escrow.unregister_resolver().expect("unregister");
+        assert_eq!(escrow.withdraw_resolver_stake(), Ok(STAKE));
+        assert_eq!(balance(stranger()), STARTING_BALANCE);
+        assert_eq!(escrow.get_pending_payout(stranger()), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn pull_payment_credits_until_withdrawn() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = cancelled_order(TransferBackend::PullPayment);
+        assert_eq!(balance(maker()), STARTING_BALANCE - SRC_AMOUNT);
+        assert_eq!(escrow.get_pending_payout(maker()), SRC_AMOUNT);
+
+        let reconciliation = escrow.reconcile(None).expect("reconcile");
+        assert_eq!(reconciliation.ledger.escrowed, 0);
+        assert_eq!(reconciliation.ledger.payouts, SRC_AMOUNT);
+        assert_eq!(reconciliation.shortfall, 0);
+
+        call_as(owner(), 0, CREATED_AT);
+        assert_eq!(escrow.withdraw_payouts(), Ok(0));
+
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(escrow.withdraw_payouts(), Ok(SRC_AMOUNT));
+        assert_eq!(balance(maker()), STARTING_BALANCE);
+        assert_eq!(escrow.get_pending_payout(maker()), 0);
+        assert_eq!(escrow.withdraw_payouts(), Ok(0));
+        assert_eq!(escrow.reconcile(None).expect("reconcile").ledger.total(), 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
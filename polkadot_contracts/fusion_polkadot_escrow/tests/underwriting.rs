@@ -0,0 +1,79 @@
+//! Underwriting backs the resolver of a locked order; the resolver must accept the underwriter
+//! and the most of its fee it is willing to share.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{Error, FusionPolkadotEscrow, HashAlgo};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const COLLATERAL: u128 = 5_000;
+
+fn underwriter() -> AccountId {
+    stranger()
+}
+
+/// Contract with one order locked by `resolver()` and a 10% underwriter fee share
+fn locked_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(1);
+    escrow.set_underwriter_fee_share(1_000).expect("fee share");
+    let order_hash = create_order(&mut escrow, order_params());
+
+    call_as(resolver(), 1, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params(HashAlgo::Blake2x256.hash(&SECRET))).expect("deploy escrow");
+    (escrow, order_hash)
+}
+
+#[test]
+fn underwriting_needs_the_resolvers_approval() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = locked_order();
+
+        call_as(underwriter(), COLLATERAL, CREATED_AT);
+        assert_eq!(escrow.underwrite(order_hash), Err(Error::UnderwritingNotApproved));
+
+        call_as(maker(), 0, CREATED_AT);
+        assert_eq!(escrow.approve_underwriter(order_hash, underwriter(), 1_000), Err(Error::Unauthorized));
+
+        // The owner raising the share after approval voids it
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.approve_underwriter(order_hash, underwriter(), 1_000).expect("approve");
+        call_as(owner(), 0, CREATED_AT);
+        escrow.set_underwriter_fee_share(2_000).expect("fee share");
+        call_as(underwriter(), COLLATERAL, CREATED_AT);
+        assert_eq!(escrow.underwrite(order_hash), Err(Error::UnderwritingNotApproved));
+
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.approve_underwriter(order_hash, underwriter(), 2_000).expect("approve");
+        call_as(maker(), COLLATERAL, CREATED_AT);
+        assert_eq!(escrow.underwrite(order_hash), Err(Error::UnderwritingNotApproved));
+        call_as(underwriter(), COLLATERAL, CREATED_AT);
+        escrow.underwrite(order_hash).expect("underwrite");
+        let underwriting = escrow.get_underwriting(order_hash).expect("underwriting");
+        assert_eq!((underwriting.collateral, underwriting.fee_share_bps), (COLLATERAL, 2_000));
+
+        // Settlement hands the collateral back
+        call_as(resolver(), 0, CREATED_AT);
+        escrow.execute_swap(order_hash, SECRET).expect("execute");
+        assert_eq!(escrow.get_claimable(underwriter(), None), COLLATERAL);
+        assert_eq!(escrow.reconcile(None).expect("reconcile").shortfall, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn pending_orders_cannot_be_underwritten() {
+    test::run_test::<Env, _>(|_| {
+        let mut escrow = escrow(1);
+        let order_hash = create_order(&mut escrow, order_params());
+
+        call_as(resolver(), 0, CREATED_AT);
+        assert_eq!(escrow.approve_underwriter(order_hash, underwriter(), 0), Err(Error::Unauthorized));
+        call_as(underwriter(), COLLATERAL, CREATED_AT);
+        assert_eq!(escrow.underwrite(order_hash), Err(Error::InvalidOrderStatus));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,188 @@
+//! Upgrade path: only the owner swaps code, only the code it announced `UPGRADE_DELAY` earlier,
+//! and the owner or a guardian can drop the announcement. `migrate` is a no-op error once storage
+//! matches the code's `STORAGE_VERSION`; until storage gets there, only `migrate` and the upgrade
+//! and pause controls change state. The code swap itself needs a live chain.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CodeUpgrade, Error, EventVersion, FeeRecipient, FusionPolkadotEscrow, GasBudgets, KeeperConfig, OwnerRecovery,
+    SizeTier, StageDelays, TokenListMode, DEFAULT_ARCHIVE_RETENTION, DEFAULT_COMMITMENT_TTL,
+    DEFAULT_COORDINATOR_CALL_GAS, DEFAULT_EXECUTION_EXCLUSIVITY, DEFAULT_EXECUTION_GRACE_PERIOD,
+    DEFAULT_MAX_ORDER_DURATION, DEFAULT_MIN_ORDER_DURATION, DEFAULT_REFERRAL_EXCLUSIVITY, DEFAULT_RESOLVER_UNBONDING_PERIOD,
+    DEFAULT_SIGNATURE_CALL_GAS, DEFAULT_SLASH_MAKER_SHARE_BPS, DEFAULT_TOKEN_CALL_GAS, MIN_RECOVERY_DELAY,
+    UPGRADE_DELAY,
+};
+use fusion_polkadot_escrow::STORAGE_VERSION;
+use ink::env::test;
+use ink::primitives::{AccountId, Hash};
+use ink::storage::traits::StorageKey;
+use scale::{Decode, Encode};
+
+const ANNOUNCED_AT: u64 = CREATED_AT;
+
+fn guardian() -> AccountId {
+    AccountId::from([0x05; 32])
+}
+
+#[test]
+fn only_the_owner_upgrades_and_migrates() {
+    test::run_test::<Env, _>(|_| {
+        call_as(owner(), 0, CREATED_AT);
+        let mut escrow = FusionPolkadotEscrow::new(0, 1_000, 1, [0u8; 20]);
+        assert_eq!(escrow.get_storage_version(), STORAGE_VERSION);
+
+        call_as(stranger(), 0, CREATED_AT);
+        assert_eq!(escrow.upgrade(Hash::from([0x11; 32])), Err(Error::OnlyOwner));
+        assert_eq!(escrow.migrate(), Err(Error::OnlyOwner));
+
+        call_as(owner(), 0, CREATED_AT);
+        assert_eq!(escrow.migrate(), Err(Error::StorageUpToDate));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn upgrades_wait_for_their_announcement() {
+    test::run_test::<Env, _>(|_| {
+        call_as(owner(), 0, ANNOUNCED_AT);
+        let mut escrow = FusionPolkadotEscrow::new(0, 1_000, 1, [0u8; 20]);
+        let code_hash = Hash::from([0x11; 32]);
+        assert_eq!(escrow.upgrade(code_hash), Err(Error::UpgradeNotPending));
+
+        call_as(stranger(), 0, ANNOUNCED_AT);
+        assert_eq!(escrow.announce_upgrade(code_hash), Err(Error::OnlyOwner));
+
+        call_as(owner(), 0, ANNOUNCED_AT);
+        escrow.announce_upgrade(code_hash).expect("announce");
+        let executable_at = ANNOUNCED_AT + UPGRADE_DELAY;
+        assert_eq!(escrow.get_pending_upgrade(), Some(CodeUpgrade { code_hash, executable_at }));
+        assert_eq!(escrow.announce_upgrade(Hash::from([0x22; 32])), Err(Error::UpgradeAlreadyPending));
+
+        call_as(owner(), 0, executable_at - 1);
+        assert_eq!(
+            escrow.upgrade(code_hash),
+            Err(Error::TimelockNotExpiredV2 { unlocks_at: executable_at, current_time: executable_at - 1 })
+        );
+        call_as(owner(), 0, executable_at);
+        assert_eq!(escrow.upgrade(Hash::from([0x22; 32])), Err(Error::UpgradeNotPending));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn owner_or_guardian_cancels_a_pending_upgrade() {
+    test::run_test::<Env, _>(|_| {
+        call_as(owner(), 0, ANNOUNCED_AT);
+        let mut escrow = FusionPolkadotEscrow::new(0, 1_000, 1, [0u8; 20]);
+        escrow.add_guardian(guardian()).expect("guardian");
+        assert_eq!(escrow.cancel_upgrade(), Err(Error::UpgradeNotPending));
+
+        for canceller in [owner(), guardian()] {
+            call_as(owner(), 0, ANNOUNCED_AT);
+            escrow.announce_upgrade(Hash::from([0x11; 32])).expect("announce");
+
+            call_as(stranger(), 0, ANNOUNCED_AT);
+            assert_eq!(escrow.cancel_upgrade(), Err(Error::Unauthorized));
+            call_as(canceller, 0, ANNOUNCED_AT);
+            escrow.cancel_upgrade().expect("cancel");
+            assert_eq!(escrow.get_pending_upgrade(), None);
+        }
+
+        call_as(owner(), 0, ANNOUNCED_AT + UPGRADE_DELAY);
+        assert_eq!(escrow.upgrade(Hash::from([0x11; 32])), Err(Error::UpgradeNotPending));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+/// The packed fields of a root in declaration order, holding what
+/// `FusionPolkadotEscrow::new(30, 1_000, 1, [0xab; 20])` sets, stored by code at `storage_version`
+fn stored_root(storage_version: u32) -> Vec<u8> {
+    let mut root = Vec::new();
+    // Core storage: archive_retention .. storage_version
+    (DEFAULT_ARCHIVE_RETENTION, 0u128, 0u32, false, storage_version).encode_to(&mut root);
+    // Configuration: owner .. min_token_safety_deposit
+    (owner(), false, 0u32, Vec::<AccountId>::new(), 0u32, MIN_RECOVERY_DELAY, None::<OwnerRecovery>).encode_to(&mut root);
+    (30u32, 0u32, DEFAULT_EXECUTION_GRACE_PERIOD, StageDelays::default()).encode_to(&mut root);
+    (DEFAULT_MIN_ORDER_DURATION, DEFAULT_MAX_ORDER_DURATION, 1_000u128, None::<AccountId>, 0u128).encode_to(&mut root);
+    // Resolver management: min_resolver_stake .. referral_exclusivity
+    (0u128, false, DEFAULT_RESOLVER_UNBONDING_PERIOD, None::<AccountId>, 0u64, 0u32).encode_to(&mut root);
+    GasBudgets {
+        token: DEFAULT_TOKEN_CALL_GAS,
+        coordinator: DEFAULT_COORDINATOR_CALL_GAS,
+        signature_validator: DEFAULT_SIGNATURE_CALL_GAS,
+    }
+    .encode_to(&mut root);
+    KeeperConfig { bounty_bps: 0, max_bounty: 0, allow_list_only: false }.encode_to(&mut root);
+    (0u128, Vec::<SizeTier>::new()).encode_to(&mut root);
+    (DEFAULT_COMMITMENT_TTL, DEFAULT_EXECUTION_EXCLUSIVITY, DEFAULT_REFERRAL_EXCLUSIVITY).encode_to(&mut root);
+    // Underwriting, cross-chain coordination and token metadata
+    (0u32, DEFAULT_SLASH_MAKER_SHARE_BPS, 0u32).encode_to(&mut root);
+    ([0xabu8; 20], 0u32, 1u32, EventVersion::V1).encode_to(&mut root);
+    (TokenListMode::Open, false).encode_to(&mut root);
+    // Metrics: order_nonce .. collateral_ratio_bps
+    (0u64, 0u128, 0u64, 0u128, Vec::<FeeRecipient>::new(), 0u32, 0u32).encode_to(&mut root);
+    root
+}
+
+/// The escrow as the current code loads it from a root stored at `storage_version`
+fn load_escrow(storage_version: u32) -> FusionPolkadotEscrow {
+    let root_key = <FusionPolkadotEscrow as StorageKey>::KEY;
+    ink::env::set_contract_storage(&root_key, &RawRoot(stored_root(storage_version)));
+    ink::env::get_contract_storage::<_, FusionPolkadotEscrow>(&root_key)
+        .expect("root decodes")
+        .expect("root stored")
+}
+
+/// Already-encoded bytes, stored as they are
+struct RawRoot(Vec<u8>);
+
+impl Encode for RawRoot {
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+
+    fn encode_to<O: scale::Output + ?Sized>(&self, dest: &mut O) {
+        dest.write(&self.0);
+    }
+}
+
+impl Decode for RawRoot {
+    fn decode<I: scale::Input>(_: &mut I) -> Result<Self, scale::Error> {
+        Err("write-only".into())
+    }
+}
+
+#[test]
+fn only_migrate_and_the_upgrade_and_pause_controls_run_before_migrating() {
+    test::run_test::<Env, _>(|_| {
+        fund_accounts();
+        call_as(owner(), 0, CREATED_AT);
+        let mut escrow = load_escrow(STORAGE_VERSION - 1);
+        assert_eq!(escrow.get_storage_version(), STORAGE_VERSION - 1);
+        assert_eq!(escrow.get_config(), FusionPolkadotEscrow::new(30, 1_000, 1, [0xab; 20]).get_config());
+
+        assert_eq!(escrow.set_allow_all_dst_tokens(true), Err(Error::MigrationPending));
+        assert_eq!(escrow.transfer_ownership(stranger()), Err(Error::MigrationPending));
+        escrow.set_paused(true).expect("pause");
+        escrow.set_paused(false).expect("unpause");
+        escrow.announce_upgrade(Hash::from([0x11; 32])).expect("announce");
+        escrow.cancel_upgrade().expect("cancel");
+        call_as(maker(), SRC_AMOUNT, CREATED_AT);
+        assert_eq!(escrow.create_order(order_params()), Err(Error::MigrationPending));
+
+        call_as(owner(), 0, CREATED_AT);
+        escrow.migrate().expect("migrate");
+        assert_eq!(escrow.get_storage_version(), STORAGE_VERSION);
+        assert_eq!(escrow.migrate(), Err(Error::StorageUpToDate));
+        escrow.set_allow_all_dst_tokens(true).expect("dst tokens");
+        create_order(&mut escrow, order_params());
+        assert_eq!(escrow.get_open_order_count(), 1);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
@@ -0,0 +1,108 @@
+//! Watchtowers: paid registration, and one `OrderAtRisk` flag per timelock boundary an order
+//! passes without action.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, Error, FusionPolkadotEscrow, RiskBoundary, StageDelays,
+};
+use ink::env::test;
+use ink::primitives::AccountId;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const WATCHTOWER_FEE: u128 = 500;
+
+const SRC_CANCELLATION: u64 = FILL_DEADLINE + 5 * MINUTE + 1; // Default grace period
+const SRC_PUBLIC_CANCELLATION: u64 = SRC_CANCELLATION + 10 * MINUTE;
+
+fn watchtower() -> AccountId {
+    stranger()
+}
+
+/// Fresh contract with one pending order and a registered watchtower
+fn escrow_with_order() -> (FusionPolkadotEscrow, [u8; 32]) {
+    let mut escrow = escrow(SAFETY_DEPOSIT);
+    escrow.set_watchtower_fee(WATCHTOWER_FEE).expect("fee");
+    escrow
+        .set_stage_delays(StageDelays { src_public_cancellation: 10 * MINUTE, ..Default::default() })
+        .expect("stage delays");
+
+    call_as(watchtower(), WATCHTOWER_FEE, CREATED_AT);
+    escrow.register_watchtower().expect("register");
+
+    let order_hash = create_order(&mut escrow, CreateOrderParams { src_amount: 10_000, ..order_params() });
+    (escrow, order_hash)
+}
+
+fn lock(escrow: &mut FusionPolkadotEscrow, order_hash: [u8; 32]) {
+    call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+    escrow.deploy_escrow(order_hash, resolver_params([0x42; 32])).expect("deploy escrow");
+}
+
+#[test]
+fn registration_is_paid_into_the_fee_vault() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, _) = escrow_with_order();
+        assert!(escrow.is_watchtower(watchtower()));
+        assert_eq!(escrow.get_accrued_fees(None), WATCHTOWER_FEE);
+
+        call_as(watchtower(), WATCHTOWER_FEE, CREATED_AT);
+        assert_eq!(escrow.register_watchtower(), Err(Error::WatchtowerAlreadyRegistered));
+        call_as(maker(), WATCHTOWER_FEE - 1, CREATED_AT);
+        assert_eq!(
+            escrow.register_watchtower(),
+            Err(Error::InsufficientFundsV2 { required: WATCHTOWER_FEE, provided: WATCHTOWER_FEE - 1 }),
+        );
+
+        call_as(watchtower(), 0, CREATED_AT);
+        assert_eq!(escrow.unregister_watchtower(), Ok(()));
+        assert!(!escrow.is_watchtower(watchtower()));
+        assert_eq!(escrow.unregister_watchtower(), Err(Error::Unauthorized));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn locked_order_is_flagged_once_per_boundary() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order();
+        lock(&mut escrow, order_hash);
+
+        let cases = [
+            ("before cancellation", watchtower(), SRC_CANCELLATION - 1, Err(Error::OrderNotAtRisk)),
+            ("not a watchtower", maker(), SRC_CANCELLATION, Err(Error::Unauthorized)),
+            ("cancellation opens", watchtower(), SRC_CANCELLATION, Ok(RiskBoundary::Cancellation)),
+            ("same boundary again", watchtower(), SRC_PUBLIC_CANCELLATION - 1, Err(Error::OrderNotAtRisk)),
+            ("public cancellation opens", watchtower(), SRC_PUBLIC_CANCELLATION, Ok(RiskBoundary::PublicCancellation)),
+            ("nothing left to flag", watchtower(), SRC_PUBLIC_CANCELLATION + MINUTE, Err(Error::OrderNotAtRisk)),
+        ];
+        for (name, caller, at, expected) in cases {
+            call_as(caller, 0, at);
+            assert_eq!(escrow.flag_order_at_risk(order_hash), expected, "{name}");
+        }
+        assert_eq!(escrow.get_risk_flag(order_hash), Some(RiskBoundary::PublicCancellation));
+
+        call_as(maker(), 0, SRC_PUBLIC_CANCELLATION);
+        escrow.cancel_order(order_hash).expect("cancel");
+        call_as(watchtower(), 0, SRC_PUBLIC_CANCELLATION);
+        assert_eq!(escrow.flag_order_at_risk(order_hash), Err(Error::InvalidOrderStatus));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn pending_order_is_flagged_after_its_fill_deadline() {
+    test::run_test::<Env, _>(|_| {
+        let (mut escrow, order_hash) = escrow_with_order();
+        call_as(watchtower(), 0, FILL_DEADLINE);
+        assert_eq!(escrow.flag_order_at_risk(order_hash), Err(Error::OrderNotAtRisk));
+        call_as(watchtower(), 0, FILL_DEADLINE + 1);
+        assert_eq!(escrow.flag_order_at_risk(order_hash), Ok(RiskBoundary::FillDeadline));
+        assert_eq!(escrow.get_risk_flag(order_hash), Some(RiskBoundary::FillDeadline));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+//! Execution weight estimates price what executions do: every payout is a claimable credit
+//! (nothing is transferred until `claim`), on top of the base weight and, with a coordinator
+//! set, its calls.
+
+mod common;
+
+use common::*;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    CreateOrderParams, ExecutionEstimate, ExecutorTip, EXECUTE_BASE_WEIGHT, PAYOUT_CREDIT_WEIGHT,
+};
+use ink::env::test;
+
+const SAFETY_DEPOSIT: u128 = 1_000;
+const TIP_BUDGET: u128 = 300;
+
+fn expected(payout_credits: u32) -> ExecutionEstimate {
+    ExecutionEstimate {
+        ref_time: EXECUTE_BASE_WEIGHT.ref_time + PAYOUT_CREDIT_WEIGHT.ref_time * payout_credits as u64,
+        proof_size: EXECUTE_BASE_WEIGHT.proof_size + PAYOUT_CREDIT_WEIGHT.proof_size * payout_credits as u64,
+        payout_credits,
+        coordinator_calls: 0,
+    }
+}
+
+#[test]
+fn estimates_count_the_credited_payouts() {
+    let tipped = CreateOrderParams {
+        executor_tip: Some(ExecutorTip { per_action: 100, remaining: TIP_BUDGET }),
+        ..order_params()
+    };
+    // (name, params, native attached, payout credits of a whole execution and of one fill)
+    let cases = [
+        // Resolver payout and deposit return
+        ("plain order", order_params(), SRC_AMOUNT, 2, 2),
+        // Plus the executor tip and its refund to the maker
+        ("tipped order", tipped, SRC_AMOUNT + TIP_BUDGET, 4, 3),
+    ];
+    for (name, params, attached, execute_credits, fill_credits) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut escrow = escrow(SAFETY_DEPOSIT);
+            call_as(maker(), attached, CREATED_AT);
+            let order_hash = escrow.create_order(params).expect("create order");
+            call_as(resolver(), SAFETY_DEPOSIT, CREATED_AT);
+            escrow.deploy_escrow(order_hash, resolver_params(blake2x256(&SECRET))).expect("lock");
+
+            assert_eq!(escrow.estimate_execute_weight(order_hash), Some(expected(execute_credits)), "{name}");
+            assert_eq!(escrow.estimate_partial_fill_weight(order_hash), Some(expected(fill_credits)), "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
@@ -0,0 +1,165 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// Adversarial test fixture: a payee / PSP22 token that rejects transfers or tries to re-enter
+#[ink::contract]
+pub mod malicious_recipient {
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::prelude::{string::String, vec::Vec};
+    use ink::storage::Mapping;
+    use scale::{Decode, Encode, Output};
+
+    /// How the fixture reacts when it is paid or called as a token
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum Behavior {
+        Accept,  // Behave like an honest token
+        Reject,  // Fail every transfer
+        Reenter, // Call back into `reentry_target` before returning
+        TakeFee(u16), // Fee-on-transfer token: recipient gets `value - value * bps / 10_000`
+    }
+
+    /// PSP22 standard error (same encoding as real tokens)
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        Custom(String),
+        InsufficientBalance,
+        InsufficientAllowance,
+        ZeroRecipientAddress,
+        ZeroSenderAddress,
+        SafeTransferCheckFailed(String),
+    }
+
+    /// Pre-encoded call arguments appended verbatim after the selector
+    struct RawArgs<'a>(&'a [u8]);
+
+    impl Encode for RawArgs<'_> {
+        fn size_hint(&self) -> usize {
+            self.0.len()
+        }
+
+        fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(self.0);
+        }
+    }
+
+    #[ink(storage)]
+    pub struct MaliciousRecipient {
+        behavior: Behavior,
+        balances: Mapping<AccountId, Balance>, // Unbacked ledger: transfers credit without debiting
+        reentry_target: Option<AccountId>,
+        reentry_input: Vec<u8>, // selector(4) ++ SCALE-encoded args
+        reentry_attempts: u32,
+        reentry_successes: u32,
+    }
+
+    impl MaliciousRecipient {
+        #[ink(constructor)]
+        pub fn new(behavior: Behavior) -> Self {
+            Self {
+                behavior,
+                balances: Mapping::default(),
+                reentry_target: None,
+                reentry_input: Vec::new(),
+                reentry_attempts: 0,
+                reentry_successes: 0,
+            }
+        }
+
+        #[ink(message)]
+        pub fn set_behavior(&mut self, behavior: Behavior) {
+            self.behavior = behavior;
+        }
+
+        /// Configure the call attempted in `Reenter` mode
+        #[ink(message)]
+        pub fn set_reentry(&mut self, target: AccountId, input: Vec<u8>) {
+            self.reentry_target = Some(target);
+            self.reentry_input = input;
+        }
+
+        /// Act as a maker/resolver: forward a raw call (with value) to `target`
+        #[ink(message, payable)]
+        pub fn forward(&mut self, target: AccountId, input: Vec<u8>) -> bool {
+            let value = self.env().transferred_value();
+            Self::raw_call(target, &input, value)
+        }
+
+        /// (attempts, successful re-entries)
+        #[ink(message)]
+        pub fn get_reentry_stats(&self) -> (u32, u32) {
+            (self.reentry_attempts, self.reentry_successes)
+        }
+
+        // --- PSP22 surface (standard selectors) ---
+
+        #[ink(message, selector = 0xdb20f9f5)]
+        pub fn transfer(&mut self, to: AccountId, value: Balance, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            self.misbehave(to, value)
+        }
+
+        #[ink(message, selector = 0x54b3c76e)]
+        pub fn transfer_from(
+            &mut self,
+            _from: AccountId,
+            to: AccountId,
+            value: Balance,
+            _data: Vec<u8>,
+        ) -> Result<(), PSP22Error> {
+            self.misbehave(to, value)
+        }
+
+        #[ink(message, selector = 0x6568382f)]
+        pub fn balance_of(&self, owner: AccountId) -> Balance {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        // --- Helpers ---
+
+        fn misbehave(&mut self, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            match self.behavior {
+                Behavior::Accept => {
+                    self.credit(to, value);
+                    Ok(())
+                }
+                Behavior::TakeFee(bps) => {
+                    let fee = value.saturating_mul(bps as Balance) / 10_000;
+                    self.credit(to, value.saturating_sub(fee));
+                    Ok(())
+                }
+                Behavior::Reject => Err(PSP22Error::Custom(String::from("rejected"))),
+                Behavior::Reenter => {
+                    if let Some(target) = self.reentry_target {
+                        self.reentry_attempts = self.reentry_attempts.saturating_add(1);
+                        if Self::raw_call(target, &self.reentry_input, 0) {
+                            self.reentry_successes = self.reentry_successes.saturating_add(1);
+                        }
+                    }
+                    self.credit(to, value);
+                    Ok(())
+                }
+            }
+        }
+
+        fn credit(&mut self, account: AccountId, value: Balance) {
+            let balance = self.balances.get(account).unwrap_or(0);
+            self.balances.insert(account, &balance.saturating_add(value));
+        }
+
+        fn raw_call(target: AccountId, input: &[u8], value: Balance) -> bool {
+            if input.len() < 4 {
+                return false;
+            }
+            let mut selector = [0u8; 4];
+            selector.copy_from_slice(&input[..4]);
+            build_call::<ink::env::DefaultEnvironment>()
+                .call(target)
+                .transferred_value(value)
+                .exec_input(ExecutionInput::new(Selector::new(selector)).push_arg(RawArgs(&input[4..])))
+                .returns::<()>()
+                .try_invoke()
+                .map(|res| res.is_ok())
+                .unwrap_or(false)
+        }
+    }
+}
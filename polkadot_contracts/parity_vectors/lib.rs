@@ -0,0 +1,341 @@
+//! Deterministic hashing fixtures shared by the ink! and Solidity test suites
+//!
+//! `vectors()` builds every case through the contract crates' own encoders and `vectors.json`
+//! is its checked-in output. `tests/vectors.rs` fails when the two drift apart, and
+//! `contracts/test/ParityVectors.t.sol` recomputes the Ethereum-side values (packed timelocks,
+//! immutables hash, CREATE2 address, keccak hashlocks) from the same file, so an encoding change
+//! on either chain breaks a test instead of a swap.
+//!
+//! Regenerate after an intentional change with
+//! `PARITY_VECTORS_UPDATE=1 cargo test -p parity_vectors` and commit the diff.
+//!
+//! Amounts are decimal strings (they overflow JSON numbers), byte strings are `0x` hex.
+
+use fusion_polkadot_escrow::create2;
+use fusion_polkadot_escrow::fusion_polkadot_escrow::{
+    BasketLeg, EscrowImmutables, OrderHashPreimage, TimeLocks, MAX_SECRET_LEN,
+};
+use fusion_polkadot_escrow::hashing::keccak256;
+use fusion_polkadot_escrow::U256;
+use polkadot_resolver::polkadot_resolver::{
+    create2_immutables, EscrowImmutables as ResolverEscrowImmutables, TimeLocks as ResolverTimeLocks,
+};
+use ink::primitives::AccountId;
+use scale::Encode;
+use serde_json::{json, Value};
+
+/// Checked-in fixture path, relative to this crate
+pub const VECTORS_FILE: &str = "vectors.json";
+
+/// Bumped whenever a field is added, renamed or re-encoded
+pub const VECTORS_VERSION: u32 = 4;
+
+const CREATED_AT_MS: u64 = 1_700_000_000_000;
+
+pub fn hex(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(2 + bytes.len() * 2);
+    out.push_str("0x");
+    for byte in bytes {
+        out.push_str(&format!("{byte:02x}"));
+    }
+    out
+}
+
+pub fn blake2x256(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(input, &mut output);
+    output
+}
+
+/// Ethereum address left-padded into an account word
+fn eth_account(byte: u8) -> AccountId {
+    let mut account = [0u8; 32];
+    account[12..].copy_from_slice(&[byte; 20]);
+    AccountId::from(account)
+}
+
+fn order_cases() -> Vec<(&'static str, OrderHashPreimage)> {
+    vec![
+        (
+            "native_source",
+            OrderHashPreimage {
+                maker: AccountId::from([0x01; 32]),
+                src_token: AccountId::from([0u8; 32]),
+                dst_token: [0xdd; 20],
+                src_amount: 1_000_000_000_000,
+                min_dst_amount: U256::from(1_000_000_000_000_000_000u128),
+                fill_deadline: CREATED_AT_MS + 3_600_000,
+                nonce: 0,
+                created_at: CREATED_AT_MS,
+                basket: Vec::new(),
+            },
+        ),
+        (
+            "psp22_basket",
+            OrderHashPreimage {
+                maker: AccountId::from([0x02; 32]),
+                src_token: AccountId::from([0xa1; 32]),
+                dst_token: [0xee; 20],
+                src_amount: 250_000_000,
+                min_dst_amount: U256::from(249_000_000u128),
+                fill_deadline: CREATED_AT_MS + 600_000,
+                nonce: 42,
+                created_at: CREATED_AT_MS + 1,
+                basket: vec![
+                    BasketLeg { token: AccountId::from([0xa2; 32]), amount: 1 },
+                    BasketLeg { token: AccountId::from([0xa3; 32]), amount: 7_500_000 },
+                ],
+            },
+        ),
+        (
+            "max_values",
+            OrderHashPreimage {
+                maker: AccountId::from([0xff; 32]),
+                src_token: AccountId::from([0xff; 32]),
+                dst_token: [0xff; 20],
+                src_amount: u128::MAX,
+                min_dst_amount: U256::MAX,
+                fill_deadline: u64::MAX,
+                nonce: u64::MAX,
+                created_at: u64::MAX,
+                basket: Vec::new(),
+            },
+        ),
+    ]
+}
+
+fn order_vector(name: &str, preimage: &OrderHashPreimage) -> Value {
+    let encoded = preimage.encode();
+    let basket: Vec<Value> = preimage
+        .basket
+        .iter()
+        .map(|leg| json!({ "token": hex(leg.token.as_ref()), "amount": leg.amount.to_string() }))
+        .collect();
+    json!({
+        "name": name,
+        "maker": hex(preimage.maker.as_ref()),
+        "src_token": hex(preimage.src_token.as_ref()),
+        "dst_token": hex(&preimage.dst_token),
+        "src_amount": preimage.src_amount.to_string(),
+        "min_dst_amount": hex(&preimage.min_dst_amount.0),
+        "fill_deadline": preimage.fill_deadline.to_string(),
+        "nonce": preimage.nonce.to_string(),
+        "created_at": preimage.created_at.to_string(),
+        "basket": basket,
+        "preimage": hex(&encoded),
+        "order_hash": hex(&blake2x256(&encoded)),
+    })
+}
+
+/// Child hashes of `split_order`: `Blake2x256(SCALE((parent_order, index)))`
+fn split_vector(parent_order: [u8; 32], index: u32) -> Value {
+    let encoded = (parent_order, index).encode();
+    json!({
+        "parent_order": hex(&parent_order),
+        "index": index,
+        "preimage": hex(&encoded),
+        "order_hash": hex(&blake2x256(&encoded)),
+    })
+}
+
+struct EscrowCase {
+    name: &'static str,
+    immutables: create2::Immutables,
+    factory: [u8; 20],
+    bytecode_hash: [u8; 32],
+    deployer: AccountId,
+}
+
+fn escrow_cases() -> Vec<EscrowCase> {
+    vec![
+        // Same inputs as `fusion_polkadot_escrow/tests/create2_parity.rs`
+        EscrowCase {
+            name: "ethereum_parties",
+            immutables: EscrowImmutables {
+                order_hash: [0x11; 32],
+                hash_lock: [0x22; 32],
+                maker: eth_account(0xaa),
+                taker: eth_account(0xbb),
+                token: eth_account(0xcc),
+                amount: 1_000_000_000_000_000_000,
+                safety_deposit: 10_000_000_000_000_000,
+                timelocks: TimeLocks {
+                    fill_deadline: CREATED_AT_MS + 3_600_000,
+                    private_cancellation: CREATED_AT_MS + 1_800_000,
+                    src_cancellation: CREATED_AT_MS + 1_800_000,
+                    src_public_cancellation: CREATED_AT_MS + 3_600_000,
+                    ..Default::default()
+                },
+                deployed_at: Some(CREATED_AT_MS),
+            }
+            .create2(),
+            factory: [0x33; 20],
+            bytecode_hash: [0x44; 32],
+            deployer: AccountId::from([0x55; 32]),
+        },
+        // Full 32-byte Substrate accounts occupy the whole word; every stage is set
+        EscrowCase {
+            name: "substrate_parties",
+            immutables: EscrowImmutables {
+                order_hash: [0x66; 32],
+                hash_lock: [0x77; 32],
+                maker: AccountId::from([0x88; 32]),
+                taker: AccountId::from([0x99; 32]),
+                token: AccountId::from([0u8; 32]),
+                amount: 5_000_000_000_000,
+                safety_deposit: 100_000_000_000,
+                timelocks: TimeLocks {
+                    fill_deadline: CREATED_AT_MS + 7_200_999,
+                    private_cancellation: CREATED_AT_MS + 3_600_500,
+                    src_withdrawal: CREATED_AT_MS + 60_999,
+                    src_public_withdrawal: CREATED_AT_MS + 1_800_250,
+                    src_cancellation: CREATED_AT_MS + 3_600_500,
+                    src_public_cancellation: CREATED_AT_MS + 7_200_999,
+                    // Starts before deployment saturate to a zero offset
+                    dst_withdrawal: CREATED_AT_MS,
+                    dst_public_withdrawal: CREATED_AT_MS + 900_000,
+                    dst_cancellation: CREATED_AT_MS + 2_700_000,
+                },
+                deployed_at: Some(CREATED_AT_MS + 500),
+            }
+            .create2(),
+            factory: [0xab; 20],
+            bytecode_hash: [0xcd; 32],
+            deployer: AccountId::from([0xef; 32]),
+        },
+        // Undeployed escrow: `deployedAt` packs as zero and every offset saturates at u32::MAX
+        EscrowCase {
+            name: "max_values_undeployed",
+            immutables: EscrowImmutables {
+                order_hash: [0xff; 32],
+                hash_lock: [0xff; 32],
+                maker: AccountId::from([0xff; 32]),
+                taker: AccountId::from([0xff; 32]),
+                token: AccountId::from([0xff; 32]),
+                amount: u128::MAX,
+                safety_deposit: u128::MAX,
+                timelocks: TimeLocks {
+                    fill_deadline: u64::MAX,
+                    private_cancellation: u64::MAX,
+                    src_withdrawal: u64::MAX,
+                    src_public_withdrawal: u64::MAX,
+                    src_cancellation: u64::MAX,
+                    src_public_cancellation: u64::MAX,
+                    dst_withdrawal: u64::MAX,
+                    dst_public_withdrawal: u64::MAX,
+                    dst_cancellation: u64::MAX,
+                },
+                deployed_at: None,
+            }
+            .create2(),
+            factory: [0xff; 20],
+            bytecode_hash: [0xff; 32],
+            deployer: AccountId::from([0xff; 32]),
+        },
+        // Resolver escrows keep millisecond offsets from `deployed_at`; they pack as seconds
+        EscrowCase {
+            name: "resolver_offsets",
+            immutables: create2_immutables(&ResolverEscrowImmutables {
+                order_hash: [0x12; 32],
+                hash_lock: [0x34; 32],
+                maker: AccountId::from([0x56; 32]),
+                taker: eth_account(0x78),
+                token: AccountId::from([0u8; 32]),
+                amount: 2_000_000_000_000,
+                safety_deposit: 50_000_000_000,
+                timelocks: ResolverTimeLocks {
+                    src_withdrawal: 60_000,
+                    src_public_withdrawal: 1_800_000,
+                    src_cancellation: 3_600_000,
+                    src_public_cancellation: 7_200_000,
+                    dst_withdrawal: 30_000,
+                    dst_public_withdrawal: 900_000,
+                    dst_cancellation: 2_700_000,
+                },
+                deployed_at: Some(CREATED_AT_MS + 500),
+            }),
+            factory: [0x9a; 20],
+            bytecode_hash: [0xbc; 32],
+            deployer: AccountId::from([0xde; 32]),
+        },
+    ]
+}
+
+fn escrow_vector(case: &EscrowCase) -> Value {
+    let immutables = &case.immutables;
+    let [src_withdrawal, src_public_withdrawal, src_cancellation, src_public_cancellation, dst_withdrawal, dst_public_withdrawal, dst_cancellation] =
+        immutables.stage_starts;
+    let salt = create2::escrow_salt(immutables);
+    json!({
+        "name": case.name,
+        "order_hash": hex(&immutables.order_hash),
+        "hash_lock": hex(&immutables.hash_lock),
+        "maker": hex(immutables.maker.as_ref()),
+        "taker": hex(immutables.taker.as_ref()),
+        "token": hex(immutables.token.as_ref()),
+        "amount": immutables.amount.to_string(),
+        "safety_deposit": immutables.safety_deposit.to_string(),
+        "src_withdrawal": src_withdrawal.to_string(),
+        "src_public_withdrawal": src_public_withdrawal.to_string(),
+        "src_cancellation": src_cancellation.to_string(),
+        "src_public_cancellation": src_public_cancellation.to_string(),
+        "dst_withdrawal": dst_withdrawal.to_string(),
+        "dst_public_withdrawal": dst_public_withdrawal.to_string(),
+        "dst_cancellation": dst_cancellation.to_string(),
+        // Undeployed escrows pack `deployedAt` as zero
+        "deployed_at": immutables.deployed_at.to_string(),
+        "timelocks": hex(&create2::pack_timelocks(&immutables.stage_starts, immutables.deployed_at)),
+        "preimage": hex(&create2::immutables_preimage(immutables)),
+        "salt": hex(&salt),
+        "factory": hex(&case.factory),
+        "bytecode_hash": hex(&case.bytecode_hash),
+        "ethereum_address": hex(&create2::ethereum_escrow_address(
+            &case.factory,
+            &case.bytecode_hash,
+            &salt,
+        )),
+        "polkadot_deployer": hex(case.deployer.as_ref()),
+        "polkadot_address": hex(create2::polkadot_escrow_address(&case.deployer, &salt).as_ref()),
+    })
+}
+
+fn secret_cases() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("single_byte", vec![0x00]),
+        ("word", (0u8..32).collect()),
+        ("max_len", vec![0x5a; MAX_SECRET_LEN]),
+    ]
+}
+
+fn hashlock_vector(name: &str, secret: &[u8]) -> Value {
+    json!({
+        "name": name,
+        "secret": hex(secret),
+        "blake2x256": hex(&blake2x256(secret)),
+        "keccak256": hex(&keccak256(secret)),
+    })
+}
+
+/// Every fixture, in the layout written to `vectors.json`
+pub fn vectors() -> Value {
+    let orders = order_cases();
+    let parent_order = blake2x256(&orders[0].1.encode());
+
+    json!({
+        "version": VECTORS_VERSION,
+        "orders": orders.iter().map(|(name, preimage)| order_vector(name, preimage)).collect::<Vec<_>>(),
+        "split_orders": [split_vector(parent_order, 0), split_vector(parent_order, 3)],
+        "escrows": escrow_cases().iter().map(escrow_vector).collect::<Vec<_>>(),
+        "hashlocks": secret_cases()
+            .iter()
+            .map(|(name, secret)| hashlock_vector(name, secret))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// `vectors()` as written to disk
+pub fn render() -> String {
+    let mut rendered = serde_json::to_string_pretty(&vectors()).expect("fixtures serialize");
+    rendered.push('\n');
+    rendered
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+//! Fails when `vectors.json` no longer matches what the contract crate's encoders produce.
+
+use parity_vectors::{render, vectors, VECTORS_FILE};
+use std::path::Path;
+
+fn fixture_path() -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(VECTORS_FILE)
+}
+
+#[test]
+fn checked_in_vectors_are_current() {
+    let path = fixture_path();
+    if std::env::var_os("PARITY_VECTORS_UPDATE").is_some() {
+        std::fs::write(&path, render()).expect("write vectors.json");
+    }
+
+    let on_disk = std::fs::read_to_string(&path).expect("read vectors.json");
+    let on_disk: serde_json::Value = serde_json::from_str(&on_disk).expect("vectors.json is JSON");
+    assert_eq!(
+        on_disk,
+        vectors(),
+        "encoding drifted from vectors.json; if intended, rerun with PARITY_VECTORS_UPDATE=1 \
+         and update the Solidity side to match"
+    );
+}
+
+/// Rebuilds each escrow's `timelocks` word, `abi.encode(Immutables)` preimage and salt from the
+/// listed fields alone, without going through `create2`
+#[test]
+fn escrow_preimages_rebuild_from_the_fields() {
+    // `TimelocksLib.Stage` order: stage `i` sits at bit `32 * i`, `deployedAt` at bit 224
+    let stages = [
+        "src_withdrawal",
+        "src_public_withdrawal",
+        "src_cancellation",
+        "src_public_cancellation",
+        "dst_withdrawal",
+        "dst_public_withdrawal",
+        "dst_cancellation",
+    ];
+    for escrow in vectors()["escrows"].as_array().expect("escrows") {
+        let name = &escrow["name"];
+        let deployed_at = secs(&escrow["deployed_at"]);
+        let (mut high, mut low) = (u128::from(deployed_at) << 96, 0u128);
+        for (stage, key) in stages.iter().enumerate() {
+            let offset = u128::from(secs(&escrow[*key]).saturating_sub(deployed_at));
+            match stage {
+                0..=3 => low |= offset << (32 * stage),
+                _ => high |= offset << (32 * (stage - 4)),
+            }
+        }
+        let timelocks = [high.to_be_bytes(), low.to_be_bytes()].concat();
+        assert_eq!(parity_vectors::hex(&timelocks), escrow["timelocks"], "{name}");
+
+        let mut preimage = Vec::new();
+        for key in ["order_hash", "hash_lock", "maker", "taker", "token"] {
+            preimage.extend(unhex(escrow[key].as_str().expect("word")));
+        }
+        for key in ["amount", "safety_deposit"] {
+            let amount: u128 = escrow[key].as_str().expect("amount").parse().expect("decimal");
+            preimage.extend([0u8; 16]);
+            preimage.extend(amount.to_be_bytes());
+        }
+        preimage.extend(timelocks);
+        assert_eq!(parity_vectors::hex(&preimage), escrow["preimage"], "{name}");
+
+        // keccak256 itself is pinned against Solidity by the hashlock vectors
+        assert_eq!(
+            parity_vectors::hex(&fusion_polkadot_escrow::hashing::keccak::keccak256(&preimage)),
+            escrow["salt"],
+            "{name}",
+        );
+    }
+}
+
+#[test]
+fn cases_are_distinct() {
+    let vectors = vectors();
+    for (list, key) in [("orders", "order_hash"), ("split_orders", "order_hash"), ("escrows", "salt")] {
+        let mut hashes: Vec<&str> = vectors[list]
+            .as_array()
+            .expect("list")
+            .iter()
+            .map(|case| case[key].as_str().expect("hash"))
+            .collect();
+        let total = hashes.len();
+        hashes.sort_unstable();
+        hashes.dedup();
+        assert_eq!(hashes.len(), total, "duplicate {key} in {list}");
+    }
+}
+
+/// Millisecond decimal string to `TimelocksLib` seconds, saturating at u32::MAX
+fn secs(millis: &serde_json::Value) -> u32 {
+    let millis: u64 = millis.as_str().expect("timestamp").parse().expect("decimal");
+    u32::try_from(millis / 1000).unwrap_or(u32::MAX)
+}
+
+fn unhex(s: &str) -> Vec<u8> {
+    let s = s.strip_prefix("0x").expect("0x prefix");
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).expect("hex"))
+        .collect()
+}
\ No newline at end of file
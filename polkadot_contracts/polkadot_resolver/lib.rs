@@ -6,10 +6,54 @@ use alloc::vec::Vec;
 use ink::storage::Mapping;
 use scale::{Decode, Encode};
 
+pub use fusion_common::{capabilities, create2, hashing, nonces, psp22, U256};
+
+/// Semantic version of the contract interface (major, minor, patch)
+pub const CONTRACT_VERSION: (u16, u16, u16) = (0, 1, 0);
+
 /// Polkadot Resolver Contract 
 #[ink::contract]
-mod polkadot_resolver {
+#[allow(
+    clippy::large_enum_variant,
+    reason = "ink! dispatches messages through one enum of their arguments and `deploy_src` takes the whole signed order"
+)]
+pub mod polkadot_resolver {
     use super::*;
+    use fusion_escrow::fusion_escrow::{Error as EscrowError, Stage, NATIVE_TOKEN};
+    use fusion_escrow::fusion_escrow::FusionEscrowRef;
+    use ink::env::call::{build_call, ExecutionInput, Selector};
+    use ink::env::DefaultEnvironment;
+    use fusion_common::GasBudget;
+
+    // Escrow parameters are the escrow contract's own types, so both sides hash the same bytes
+    pub use fusion_escrow::fusion_escrow::{EscrowImmutables, EscrowSide, TimeLocks};
+
+    /// The `IBaseEscrow.Immutables` the CREATE2 salt is taken over; the escrow's timelocks are
+    /// millisecond offsets from `deployed_at`
+    pub fn create2_immutables(immutables: &EscrowImmutables) -> create2::Immutables {
+        let deployed_at = immutables.deployed_at.unwrap_or(0);
+        let stage_starts = [
+            Stage::SrcWithdrawal,
+            Stage::SrcPublicWithdrawal,
+            Stage::SrcCancellation,
+            Stage::SrcPublicCancellation,
+            Stage::DstWithdrawal,
+            Stage::DstPublicWithdrawal,
+            Stage::DstCancellation,
+        ]
+        .map(|stage| immutables.timelocks.stage_start(stage, deployed_at));
+        create2::Immutables {
+            order_hash: immutables.order_hash,
+            hash_lock: immutables.hash_lock,
+            maker: immutables.maker,
+            taker: immutables.taker,
+            token: immutables.token,
+            amount: immutables.amount,
+            safety_deposit: immutables.safety_deposit,
+            deployed_at,
+            stage_starts,
+        }
+    }
 
     /// Cross-chain swap direction 
     #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
@@ -19,35 +63,15 @@ mod polkadot_resolver {
         DestinationToSource,  // Ethereum to Polkadot
     }
 
-    /// Immutable escrow parameters 
+    /// Maker signature over the order hash
     #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
-    pub struct EscrowImmutables {
-        pub order_hash: [u8; 32],
-        pub hash_lock: [u8; 32],
-        pub maker: AccountId,
-        pub taker: AccountId,
-        pub token: AccountId,
-        pub amount: Balance,
-        pub safety_deposit: Balance,
-        pub timelocks: TimeLocks,
-        pub deployed_at: Option<Timestamp>,
-    }
-
-    /// Timelock structure 
-    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
-    pub struct TimeLocks {
-        pub src_withdrawal: u32,
-        pub src_public_withdrawal: u32, 
-        pub src_cancellation: u32,
-        pub src_public_cancellation: u32,
-        pub dst_withdrawal: u32,
-        pub dst_public_withdrawal: u32,
-        pub dst_cancellation: u32,
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum MakerSignature {
+        Ecdsa([u8; 65]),   // r(32) + s(32) + v(1)
+        Sr25519([u8; 64]), // Substrate wallet key; the maker AccountId is the public key
     }
 
-    /// Order structure 
+    /// Order structure; makers sign its `hash`
     #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct Order {
@@ -57,8 +81,31 @@ mod polkadot_resolver {
         pub maker_asset: AccountId,
         pub taker_asset: [u8; 20], // Ethereum address
         pub making_amount: Balance,
-        pub taking_amount: Balance,
-        pub maker_traits: U256, // Packed traits
+        pub taking_amount: U256,   // EVM-denominated
+        pub maker_traits: MakerTraits, // Packed traits
+    }
+
+    impl Order {
+        /// Blake2x256 of the SCALE-encoded order, the `order_hash` of its escrows
+        pub fn hash(&self) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(self, &mut output);
+            output
+        }
+    }
+
+    /// Packed 1inch maker traits; only the nonce is read here
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct MakerTraits(pub U256);
+
+    impl MakerTraits {
+        /// 40-bit nonce (or epoch) at bits 120..160
+        pub fn nonce(&self) -> u64 {
+            let mut bytes = [0u8; 8];
+            bytes[3..].copy_from_slice(&self.0 .0[12..17]);
+            u64::from_be_bytes(bytes)
+        }
     }
 
     /// Taker traits 
@@ -68,6 +115,17 @@ mod polkadot_resolver {
         pub traits: U256,
     }
 
+    /// Snapshot of every runtime parameter, for deploy tooling and monitoring
+    #[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ResolverConfig {
+        pub owner: AccountId,
+        pub escrow_factory: AccountId,
+        pub escrow_code_hash: Hash,
+        pub ethereum_resolver: [u8; 20],
+        pub min_safety_deposit: Balance,
+    }
+
     /// Events matching 1inch pattern
     #[ink(event)]
     pub struct SrcEscrowDeployed {
@@ -116,6 +174,14 @@ mod polkadot_resolver {
         pub data_hash: [u8; 32],
     }
 
+    /// A signer revoked its signatures over every nonce up to and including `nonce`
+    #[ink(event)]
+    pub struct NonceInvalidated {
+        #[ink(topic)]
+        pub account: AccountId,
+        pub nonce: u64,
+    }
+
     /// Errors
     #[derive(Debug, PartialEq, Eq, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -123,6 +189,7 @@ mod polkadot_resolver {
         // Access control
         Unauthorized,
         OnlyOwner,
+        InvalidSignature,
         
         // Order/Escrow errors  
         EscrowNotFound,
@@ -143,15 +210,45 @@ mod polkadot_resolver {
         LengthMismatch,
         InvalidLength,
         ArithmeticOverflow,
+
+        // V2: context-carrying variants, appended so existing variant indices are unchanged
+        InsufficientFundsV2 { required: Balance, provided: Balance },
+        TimelockNotExpiredV2 { unlocks_at: Timestamp, current_time: Timestamp },
+
+        NonceAlreadyUsed,
+        InvalidNonce,
+        EscrowDeploymentFailed,
+        EscrowCallFailed,
+    }
+
+    /// The escrow's own errors, as the resolver reports them
+    impl From<EscrowError> for Error {
+        fn from(error: EscrowError) -> Self {
+            match error {
+                EscrowError::InvalidCaller => Error::Unauthorized,
+                EscrowError::InvalidImmutables => Error::InvalidImmutables,
+                EscrowError::InvalidSecret => Error::InvalidSecret,
+                EscrowError::StageNotReached { unlocks_at, current_time } => {
+                    Error::TimelockNotExpiredV2 { unlocks_at, current_time }
+                }
+                EscrowError::StageClosed { .. } => Error::DeadlineExpired,
+                EscrowError::InsufficientFunds { required, provided } => {
+                    Error::InsufficientFundsV2 { required, provided }
+                }
+                EscrowError::TransferFailed => Error::TransferFailed,
+            }
+        }
     }
 
     #[ink(storage)]
     pub struct PolkadotResolver {
         /// Contract owner
         owner: AccountId,
+        operator: Option<AccountId>, // Hot key allowed to deploy escrows; admin stays with owner
         
         /// Escrow factory reference
         escrow_factory: AccountId,
+        escrow_code_hash: Hash, // Uploaded `fusion_escrow` code every escrow is instantiated from
         
         /// Active escrows
         escrows: Mapping<[u8; 32], EscrowImmutables>, // order_hash -> immutables
@@ -166,24 +263,35 @@ mod polkadot_resolver {
         
         /// Metrics
         total_escrows_created: u64,
+
+        /// Consumed by every accepted maker signature
+        nonces: nonces::NonceRegistry,
+
+        /// Operator named by the owner that has not accepted yet
+        proposed_operator: Option<AccountId>,
     }
 
     impl PolkadotResolver {
         #[ink(constructor)]
         pub fn new(
             escrow_factory: AccountId,
+            escrow_code_hash: Hash,
             ethereum_resolver: [u8; 20],
             min_safety_deposit: Balance,
         ) -> Self {
             Self {
                 owner: Self::env().caller(),
+                operator: None,
                 escrow_factory,
+                escrow_code_hash,
                 escrows: Mapping::default(),
                 escrow_addresses: Mapping::default(),
                 ethereum_resolver,
                 trusted_relayers: Mapping::default(),
                 min_safety_deposit,
                 total_escrows_created: 0,
+                nonces: Default::default(),
+                proposed_operator: None,
             }
         }
 
@@ -192,34 +300,61 @@ mod polkadot_resolver {
         pub fn deploy_src(
             &mut self,
             immutables: EscrowImmutables,
-            _order: Order,
-            _signature: [u8; 65], // r(32) + s(32) + v(1)
+            order: Order,
+            signature: MakerSignature,
             _amount: Balance,
             _taker_traits: TakerTraits,
             _args: Vec<u8>,
         ) -> Result<AccountId, Error> {
-            self.ensure_owner()?;
+            self.ensure_owner_or_operator()?;
             
             let safety_deposit = self.env().transferred_value();
             if safety_deposit < self.min_safety_deposit {
-                return Err(Error::InsufficientFunds);
+                return Err(Error::InsufficientFundsV2 {
+                    required: self.min_safety_deposit,
+                    provided: safety_deposit,
+                });
+            }
+
+            // The maker signs the order hash for this contract together with their current nonce,
+            // which the signed order must carry
+            let nonce = self.nonces.get(immutables.maker);
+            if order.maker_traits.nonce() != nonce {
+                return Err(Error::InvalidNonce);
+            }
+            let digest = nonces::digest(self.env().account_id(), immutables.order_hash, nonce);
+            let signed = match &signature {
+                MakerSignature::Sr25519(signature) => fusion_common::signatures::sr25519_signed(&immutables.maker, &digest, signature),
+                MakerSignature::Ecdsa(signature) => fusion_common::signatures::ecdsa_signed(&immutables.maker, &digest, signature),
+            };
+            if !signed {
+                return Err(Error::InvalidSignature);
+            }
+            self.nonces.consume(immutables.maker);
+
+            // The signature only covers the order through its hash, so the order supplied must
+            // be the one hashed before any of its fields bound the escrow
+            if order.hash() != immutables.order_hash {
+                return Err(Error::InvalidOrderHash);
             }
 
-            // Update immutables with deployment timestamp
+            // The maker's leg is pulled under the allowance the maker gave this contract, as the
+            // 1inch LOP does, so it must be a PSP22 token of the signed order
+            if immutables.maker != order.maker
+                || immutables.token != order.maker_asset
+                || immutables.token == AccountId::from(NATIVE_TOKEN)
+                || immutables.amount > order.making_amount
+            {
+                return Err(Error::InvalidImmutables);
+            }
+
+            // The escrow fixes `deployed_at` to its instantiation block
             let mut immutables_mem = immutables.clone();
             immutables_mem.deployed_at = Some(self.env().block_timestamp());
             immutables_mem.safety_deposit = safety_deposit;
 
-            // Compute escrow address deterministically 
-            let escrow_address = self.compute_escrow_address(&immutables_mem)?;
-
-            // Send safety deposit to computed address
-            self.env().transfer(escrow_address, safety_deposit)
-                .map_err(|_| Error::NativeTokenSendingFailure)?;
-
-            // Call escrow factory to create the escrow
-            // This would be a cross-contract call in practice
-            self.create_escrow_contract(escrow_address, &immutables_mem)?;
+            let escrow_address = self.create_escrow_contract(&immutables_mem, EscrowSide::Source, safety_deposit)?;
+            self.fund_escrow(&immutables_mem, escrow_address, Some(immutables.maker))?;
 
             // Store escrow data using order_hash as key
             self.escrows.insert(immutables_mem.order_hash, &immutables_mem);
@@ -244,19 +379,16 @@ mod polkadot_resolver {
             dst_immutables: EscrowImmutables,
             src_cancellation_timestamp: Timestamp,
         ) -> Result<AccountId, Error> {
-            self.ensure_owner()?;
+            self.ensure_owner_or_operator()?;
             
+            // Native escrows need the amount and the safety deposit attached, PSP22 ones only the
+            // deposit; the token amount comes from this contract's own balance
             let deposit_amount = self.env().transferred_value();
-            
-            // Compute escrow address
-            let escrow_address = self.compute_escrow_address(&dst_immutables)?;
-            
-            // Send deposit to escrow
-            self.env().transfer(escrow_address, deposit_amount)
-                .map_err(|_| Error::NativeTokenSendingFailure)?;
+            let mut dst_immutables = dst_immutables;
+            dst_immutables.deployed_at = Some(self.env().block_timestamp());
 
-            // Create escrow contract
-            self.create_escrow_contract(escrow_address, &dst_immutables)?;
+            let escrow_address = self.create_escrow_contract(&dst_immutables, EscrowSide::Destination, deposit_amount)?;
+            self.fund_escrow(&dst_immutables, escrow_address, None)?;
 
             // Store escrow data
             self.escrows.insert(dst_immutables.order_hash, &dst_immutables);
@@ -280,11 +412,10 @@ mod polkadot_resolver {
             secret: [u8; 32],
             immutables: EscrowImmutables,
         ) -> Result<(), Error> {
-            let _caller = self.env().caller();
-            
             // Get escrow address
             let escrow_address = self.escrow_addresses.get(order_hash)
                 .ok_or(Error::EscrowNotFound)?;
+            self.ensure_recorded_immutables(order_hash, &immutables)?;
 
             // Verify secret against hash lock
             let computed_hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&secret);
@@ -318,6 +449,7 @@ mod polkadot_resolver {
         ) -> Result<(), Error> {
             let escrow_address = self.escrow_addresses.get(order_hash)
                 .ok_or(Error::EscrowNotFound)?;
+            self.ensure_recorded_immutables(order_hash, &immutables)?;
 
             // Check cancellation timelock
             let current_time = self.env().block_timestamp();
@@ -396,6 +528,12 @@ mod polkadot_resolver {
 
         // --- View Functions ---
 
+        /// CREATE2 salt for `immutables`, identical to `ImmutablesLib.hash` on Ethereum
+        #[ink(message)]
+        pub fn get_escrow_salt(&self, immutables: EscrowImmutables) -> [u8; 32] {
+            create2::escrow_salt(&create2_immutables(&immutables))
+        }
+
         #[ink(message)]
         pub fn get_escrow_immutables(&self, order_hash: [u8; 32]) -> Option<EscrowImmutables> {
             self.escrows.get(order_hash)
@@ -421,6 +559,60 @@ mod polkadot_resolver {
             self.total_escrows_created
         }
 
+        /// All runtime parameters in one call
+        #[ink(message)]
+        pub fn get_config(&self) -> ResolverConfig {
+            ResolverConfig {
+                owner: self.owner,
+                escrow_factory: self.escrow_factory,
+                escrow_code_hash: self.escrow_code_hash,
+                ethereum_resolver: self.ethereum_resolver,
+                min_safety_deposit: self.min_safety_deposit,
+            }
+        }
+
+        /// Interface version, for SDK feature detection
+        #[ink(message)]
+        pub fn get_version(&self) -> (u16, u16, u16) {
+            CONTRACT_VERSION
+        }
+
+        /// Supported features as `capabilities` bitflags
+        #[ink(message)]
+        pub fn get_capabilities(&self) -> u64 {
+            capabilities::PSP22_TOKENS
+                | capabilities::HASH_BLAKE2X256
+                | capabilities::CHAIN_ETHEREUM
+                | capabilities::RICH_ERRORS
+        }
+
+        /// Nonce `account`'s next signature must cover (see `nonces::digest`)
+        #[ink(message)]
+        pub fn get_nonce(&self, account: AccountId) -> u64 {
+            self.nonces.get(account)
+        }
+
+        /// Revoke the caller's outstanding signatures over every nonce up to and including `nonce`
+        #[ink(message)]
+        pub fn invalidate_nonce(&mut self, nonce: u64) -> Result<(), Error> {
+            let account = self.env().caller();
+            if !self.nonces.invalidate_through(account, nonce) {
+                return Err(Error::NonceAlreadyUsed);
+            }
+            self.env().emit_event(NonceInvalidated { account, nonce });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_operator(&self) -> Option<AccountId> {
+            self.operator
+        }
+
+        #[ink(message)]
+        pub fn get_proposed_operator(&self) -> Option<AccountId> {
+            self.proposed_operator
+        }
+
         // --- Admin Functions ---
 
         #[ink(message)]
@@ -430,6 +622,30 @@ mod polkadot_resolver {
             Ok(())
         }
 
+        /// Offer escrow deployment to a hot key, which takes over once it calls
+        /// `accept_operator`; `None` withdraws the offer and revokes the current operator
+        #[ink(message)]
+        pub fn propose_operator(&mut self, operator: Option<AccountId>) -> Result<(), Error> {
+            self.ensure_owner()?;
+            self.proposed_operator = operator;
+            if operator.is_none() {
+                self.operator = None;
+            }
+            Ok(())
+        }
+
+        /// Become the operator the owner proposed
+        #[ink(message)]
+        pub fn accept_operator(&mut self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if self.proposed_operator != Some(caller) {
+                return Err(Error::Unauthorized);
+            }
+            self.proposed_operator = None;
+            self.operator = Some(caller);
+            Ok(())
+        }
+
         #[ink(message)]
         pub fn add_trusted_relayer(&mut self, relayer: AccountId) -> Result<(), Error> {
             self.ensure_owner()?;
@@ -453,32 +669,62 @@ mod polkadot_resolver {
             Ok(())
         }
 
-        fn compute_escrow_address(&self, immutables: &EscrowImmutables) -> Result<AccountId, Error> {
-            // Deterministic address computation similar to 1inch CREATE2
-            let seed_data = (
-                &immutables.order_hash,
-                &immutables.hash_lock,
-                &immutables.maker,
-                &immutables.taker,
-                immutables.amount,
-                immutables.deployed_at.unwrap_or(0),
-            );
-            let encoded = scale::Encode::encode(&seed_data);
-            let hash = self.env().hash_bytes::<ink::env::hash::Blake2x256>(&encoded);
-            
-            // Convert hash to AccountId (simplified)
-            let mut account_bytes = [0u8; 32];
-            account_bytes.copy_from_slice(&hash);
-            Ok(AccountId::from(account_bytes))
+        fn ensure_owner_or_operator(&self) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.owner && Some(caller) != self.operator {
+                return Err(Error::Unauthorized);
+            }
+            Ok(())
         }
 
+        /// Instantiate the escrow from `escrow_code_hash`, salted like the Ethereum factory's
+        /// CREATE2 (see `create2`), with `endowment` attached
         fn create_escrow_contract(
             &self,
-            _escrow_address: AccountId,
-            _immutables: &EscrowImmutables,
+            immutables: &EscrowImmutables,
+            side: EscrowSide,
+            endowment: Balance,
+        ) -> Result<AccountId, Error> {
+            let escrow = FusionEscrowRef::new(immutables.clone(), side)
+                .code_hash(self.escrow_code_hash)
+                .endowment(endowment)
+                .salt_bytes(create2::escrow_salt(&create2_immutables(immutables)))
+                .try_instantiate()
+                .map_err(|_| Error::EscrowDeploymentFailed)?
+                .map_err(|_| Error::EscrowDeploymentFailed)??;
+            Ok(ink::ToAccountId::to_account_id(&escrow))
+        }
+
+        /// Move a PSP22 escrow's amount in, from `payer` under its allowance or else from this
+        /// contract, and check the escrow holds it; native amounts arrived with the endowment
+        fn fund_escrow(
+            &self,
+            immutables: &EscrowImmutables,
+            escrow_address: AccountId,
+            payer: Option<AccountId>,
         ) -> Result<(), Error> {
-            // This would deploy or initialize the escrow contract
-            // For now, assume success
+            let token = immutables.token;
+            if token == AccountId::from(NATIVE_TOKEN) || immutables.amount == 0 {
+                return Ok(());
+            }
+            match payer {
+                Some(payer) => psp22::transfer_from(token, payer, escrow_address, immutables.amount, GasBudget::UNLIMITED),
+                None => psp22::transfer(token, escrow_address, immutables.amount, GasBudget::UNLIMITED),
+            }
+            .map_err(|_| Error::TransferFailed)?;
+
+            let held = psp22::balance_of(token, escrow_address, GasBudget::UNLIMITED).map_err(|_| Error::TransferFailed)?;
+            if held < immutables.amount {
+                return Err(Error::InsufficientFundsV2 { required: immutables.amount, provided: held });
+            }
+            Ok(())
+        }
+
+        /// Stages are only read from the immutables recorded at deployment, never the caller's
+        fn ensure_recorded_immutables(&self, order_hash: [u8; 32], immutables: &EscrowImmutables) -> Result<(), Error> {
+            if self.escrows.get(order_hash).as_ref() != Some(immutables) {
+                return Err(Error::InvalidImmutables);
+            }
             Ok(())
         }
 
@@ -487,11 +733,11 @@ mod polkadot_resolver {
             immutables: &EscrowImmutables,
             current_time: Timestamp,
         ) -> Result<(), Error> {
-            let deployed_at = immutables.deployed_at.unwrap_or(0);
-            let withdrawal_time = deployed_at.saturating_add(immutables.timelocks.src_withdrawal as u64);
+            let deployed_at = immutables.deployed_at.ok_or(Error::InvalidImmutables)?;
+            let withdrawal_time = immutables.timelocks.stage_start(Stage::SrcWithdrawal, deployed_at);
             
             if current_time < withdrawal_time {
-                return Err(Error::TimelockNotExpired);
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at: withdrawal_time, current_time });
             }
             
             Ok(())
@@ -502,11 +748,11 @@ mod polkadot_resolver {
             immutables: &EscrowImmutables,
             current_time: Timestamp,
         ) -> Result<(), Error> {
-            let deployed_at = immutables.deployed_at.unwrap_or(0);
-            let cancellation_time = deployed_at.saturating_add(immutables.timelocks.src_cancellation as u64);
+            let deployed_at = immutables.deployed_at.ok_or(Error::InvalidImmutables)?;
+            let cancellation_time = immutables.timelocks.stage_start(Stage::SrcCancellation, deployed_at);
             
             if current_time < cancellation_time {
-                return Err(Error::TimelockNotExpired);
+                return Err(Error::TimelockNotExpiredV2 { unlocks_at: cancellation_time, current_time });
             }
             
             Ok(())
@@ -514,25 +760,57 @@ mod polkadot_resolver {
 
         fn execute_escrow_withdrawal(
             &self,
-            _escrow_address: AccountId,
-            _secret: [u8; 32],
-            _immutables: &EscrowImmutables,
+            escrow_address: AccountId,
+            secret: [u8; 32],
+            immutables: &EscrowImmutables,
         ) -> Result<(), Error> {
             // Cross-contract call to escrow.withdraw(secret, immutables)
-            // For now, assume success
-            Ok(())
+            let result = build_call::<DefaultEnvironment>()
+                .call(escrow_address)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("withdraw")))
+                        .push_arg(secret)
+                        .push_arg(immutables),
+                )
+                .returns::<Result<(), EscrowError>>()
+                .try_invoke();
+            self.escrow_call_result(escrow_address, result)
         }
 
         fn execute_escrow_cancellation(
             &self,
-            _escrow_address: AccountId,
+            escrow_address: AccountId,
             immutables: &EscrowImmutables,
         ) -> Result<Balance, Error> {
-            // Cross-contract call to escrow.cancel(immutables)
-            // Return refunded amount
+            // Cross-contract call to escrow.cancel(immutables); the full amount is refunded
+            let result = build_call::<DefaultEnvironment>()
+                .call(escrow_address)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("cancel")))
+                        .push_arg(immutables),
+                )
+                .returns::<Result<(), EscrowError>>()
+                .try_invoke();
+            self.escrow_call_result(escrow_address, result)?;
             Ok(immutables.amount)
         }
 
+        /// The escrow's reply: its own errors are passed on. A settled escrow terminates itself
+        /// and returns no output, which surfaces as a decode error; that only counts as success
+        /// once the escrow's code is actually gone.
+        fn escrow_call_result(
+            &self,
+            escrow_address: AccountId,
+            result: Result<ink::MessageResult<Result<(), EscrowError>>, ink::env::Error>,
+        ) -> Result<(), Error> {
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                Ok(Ok(Err(error))) => Err(error.into()),
+                Err(ink::env::Error::Decode(_)) if !self.env().is_contract(&escrow_address) => Ok(()),
+                _ => Err(Error::EscrowCallFailed),
+            }
+        }
+
         fn execute_arbitrary_call(
             &self,
             _target: AccountId,
@@ -543,7 +821,4 @@ mod polkadot_resolver {
             Ok(())
         }
     }
-
-    // --- Type aliases for compatibility ---
-    type U256 = [u8; 32]; // Simplified u256 representation
 }
\ No newline at end of file
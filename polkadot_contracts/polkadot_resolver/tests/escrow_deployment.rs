@@ -0,0 +1,127 @@
+//! E2E: the resolver instantiates real escrows from their uploaded code and drives them through
+//! their stages, passing the escrow's own errors back.
+//!
+//! Run with `cargo test --features e2e-tests` against a running contracts node.
+#![cfg(feature = "e2e-tests")]
+
+use fusion_escrow::fusion_escrow::NATIVE_TOKEN;
+use ink::primitives::AccountId;
+use ink_e2e::{AccountKeyring, ContractsBackend};
+use polkadot_resolver::polkadot_resolver::{
+    EscrowImmutables, Error, PolkadotResolver, PolkadotResolverRef, TimeLocks,
+};
+
+type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const MIN_SAFETY_DEPOSIT: u128 = 1_000;
+const AMOUNT: u128 = 1_000_000;
+const SECRET: [u8; 32] = [0x5e; 32];
+const ORDER_HASH: [u8; 32] = [0x11; 32];
+
+fn blake2x256(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(input, &mut output);
+    output
+}
+
+/// Withdrawal opens at deployment and cancellation a day later
+fn immutables(taker: AccountId) -> EscrowImmutables {
+    EscrowImmutables {
+        order_hash: ORDER_HASH,
+        hash_lock: blake2x256(&SECRET),
+        maker: ink_e2e::account_id(AccountKeyring::Charlie),
+        taker,
+        token: AccountId::from(NATIVE_TOKEN),
+        amount: AMOUNT,
+        safety_deposit: MIN_SAFETY_DEPOSIT,
+        timelocks: TimeLocks {
+            src_withdrawal: 0,
+            src_public_withdrawal: 3_600_000,
+            src_cancellation: 86_400_000,
+            src_public_cancellation: 90_000_000,
+            dst_withdrawal: 0,
+            dst_public_withdrawal: 3_600_000,
+            dst_cancellation: 86_400_000,
+        },
+        deployed_at: None,
+    }
+}
+
+#[ink_e2e::test]
+async fn dst_escrow_is_instantiated_funded_and_withdrawn<Client: E2EBackend>(
+    mut client: Client,
+) -> E2EResult<()> {
+    let escrow_code_hash = client
+        .upload("fusion_escrow", &ink_e2e::alice())
+        .submit()
+        .await
+        .expect("escrow upload failed")
+        .code_hash;
+
+    let mut constructor = PolkadotResolverRef::new(
+        ink_e2e::account_id(AccountKeyring::Ferdie),
+        escrow_code_hash,
+        [0xee; 20],
+        MIN_SAFETY_DEPOSIT,
+    );
+    let resolver = client
+        .instantiate("polkadot_resolver", &ink_e2e::alice(), &mut constructor)
+        .submit()
+        .await
+        .expect("resolver instantiate failed");
+    let mut call = resolver.call_builder::<PolkadotResolver>();
+    let supplied = immutables(resolver.account_id);
+
+    // Too little value for a native escrow: the escrow's constructor refuses it
+    let underfunded = client
+        .call(&ink_e2e::alice(), &call.deploy_dst(supplied.clone(), u64::MAX))
+        .value(AMOUNT)
+        .dry_run()
+        .await?;
+    assert_eq!(
+        underfunded.return_value(),
+        Err(Error::InsufficientFundsV2 { required: AMOUNT + MIN_SAFETY_DEPOSIT, provided: AMOUNT })
+    );
+
+    let escrow_address = client
+        .call(&ink_e2e::alice(), &call.deploy_dst(supplied.clone(), u64::MAX))
+        .value(AMOUNT + MIN_SAFETY_DEPOSIT)
+        .submit()
+        .await?
+        .return_value()
+        .expect("deploy_dst");
+    assert_eq!(client.free_balance(escrow_address).await?, AMOUNT + MIN_SAFETY_DEPOSIT);
+
+    // `deployed_at` is stamped at deployment; only the recorded immutables are accepted
+    let recorded = client
+        .call(&ink_e2e::alice(), &call.get_escrow_immutables(ORDER_HASH))
+        .dry_run()
+        .await?
+        .return_value()
+        .expect("recorded immutables");
+    assert!(recorded.deployed_at.is_some());
+    let mut forged = recorded.clone();
+    forged.deployed_at = Some(0);
+    let result = client
+        .call(&ink_e2e::bob(), &call.withdraw(ORDER_HASH, SECRET, forged))
+        .dry_run()
+        .await?;
+    assert_eq!(result.return_value(), Err(Error::InvalidImmutables));
+
+    // Destination escrows are never cancelled before their cancellation stage
+    let result = client
+        .call(&ink_e2e::bob(), &call.cancel(ORDER_HASH, recorded.clone()))
+        .dry_run()
+        .await?;
+    assert!(matches!(result.return_value(), Err(Error::TimelockNotExpiredV2 { .. })));
+
+    let maker_before = client.free_balance(recorded.maker).await?;
+    client
+        .call(&ink_e2e::bob(), &call.withdraw(ORDER_HASH, SECRET, recorded.clone()))
+        .submit()
+        .await?
+        .return_value()
+        .expect("withdraw");
+    assert_eq!(client.free_balance(recorded.maker).await?, maker_before + AMOUNT);
+    Ok(())
+}
\ No newline at end of file
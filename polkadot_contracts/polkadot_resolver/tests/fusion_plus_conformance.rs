@@ -0,0 +1,248 @@
+//! 1inch Fusion+ resolver rules as tables, run against the contract in the off-chain environment.
+//!
+//! Only the owner or its operator deploys escrows, deposits below the minimum are refused, and a
+//! signed order must carry the maker's current nonce, which the maker can skip past to revoke it.
+//! A successful deploy instantiates the escrow contract, which the off-chain environment cannot
+//! do, so only the rows rejected beforehand are tabled here; deployment and the staged
+//! `withdraw`/`cancel` rows run end to end in `escrow_deployment.rs`.
+
+use ink::env::{test, DefaultEnvironment};
+use ink::primitives::{AccountId, Hash};
+use polkadot_resolver::polkadot_resolver::{
+    EscrowImmutables, Error, MakerSignature, MakerTraits, Order, PolkadotResolver, TakerTraits,
+    TimeLocks,
+};
+use polkadot_resolver::{capabilities, U256};
+
+type Env = DefaultEnvironment;
+
+const MIN_SAFETY_DEPOSIT: u128 = 1_000;
+const STARTING_BALANCE: u128 = 1_000_000_000;
+
+const DEPLOYED_AT: u64 = 1_700_000_000_000;
+const WITHDRAWAL_OFFSET: u32 = 10_000;
+const CANCELLATION_OFFSET: u32 = 30_000;
+
+const SECRET: [u8; 32] = [0x5e; 32];
+const ORDER_HASH: [u8; 32] = [0x11; 32];
+
+fn owner() -> AccountId {
+    AccountId::from([0x01; 32])
+}
+
+fn operator() -> AccountId {
+    AccountId::from([0x02; 32])
+}
+
+fn stranger() -> AccountId {
+    AccountId::from([0x03; 32])
+}
+
+fn contract() -> AccountId {
+    AccountId::from([0xc0; 32])
+}
+
+fn call_as(caller: AccountId, at: u64) {
+    test::set_caller::<Env>(caller);
+    test::set_callee::<Env>(contract());
+    test::set_value_transferred::<Env>(0);
+    test::set_block_timestamp::<Env>(at);
+}
+
+fn pay_as(caller: AccountId, value: u128) {
+    test::set_caller::<Env>(caller);
+    test::set_callee::<Env>(contract());
+    test::transfer_in::<Env>(value);
+}
+
+fn balance(account: AccountId) -> u128 {
+    test::get_account_balance::<Env>(account).unwrap_or(0)
+}
+
+fn blake2x256(input: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(input, &mut output);
+    output
+}
+
+fn immutables() -> EscrowImmutables {
+    EscrowImmutables {
+        order_hash: ORDER_HASH,
+        hash_lock: blake2x256(&SECRET),
+        maker: AccountId::from([0xaa; 32]),
+        taker: contract(),
+        token: AccountId::from([0u8; 32]),
+        amount: 1_000_000,
+        safety_deposit: MIN_SAFETY_DEPOSIT,
+        timelocks: TimeLocks {
+            src_withdrawal: WITHDRAWAL_OFFSET,
+            src_public_withdrawal: 20_000,
+            src_cancellation: CANCELLATION_OFFSET,
+            src_public_cancellation: 40_000,
+            dst_withdrawal: WITHDRAWAL_OFFSET,
+            dst_public_withdrawal: 20_000,
+            dst_cancellation: CANCELLATION_OFFSET,
+        },
+        deployed_at: Some(DEPLOYED_AT),
+    }
+}
+
+/// Maker traits carrying `nonce` and no other flags
+fn maker_traits(nonce: u64) -> MakerTraits {
+    let mut traits = [0u8; 32];
+    traits[12..17].copy_from_slice(&nonce.to_be_bytes()[3..]);
+    MakerTraits(U256(traits))
+}
+
+/// Fresh resolver owned by `owner()` with `operator()` as its hot key
+fn resolver() -> PolkadotResolver {
+    for account in [owner(), operator(), stranger()] {
+        test::set_account_balance::<Env>(account, STARTING_BALANCE);
+    }
+    test::set_account_balance::<Env>(contract(), 0);
+
+    call_as(owner(), DEPLOYED_AT);
+    let mut resolver = PolkadotResolver::new(
+        AccountId::from([0xfa; 32]),
+        Hash::from([0xcd; 32]),
+        [0xee; 20],
+        MIN_SAFETY_DEPOSIT,
+    );
+    resolver.propose_operator(Some(operator())).expect("propose operator");
+    call_as(operator(), DEPLOYED_AT);
+    resolver.accept_operator().expect("accept operator");
+    resolver
+}
+
+#[test]
+fn only_owner_or_operator_deploys_dst() {
+    test::run_test::<Env, _>(|_| {
+        let mut resolver = resolver();
+        call_as(stranger(), DEPLOYED_AT);
+        pay_as(stranger(), MIN_SAFETY_DEPOSIT);
+        assert_eq!(
+            resolver.deploy_dst(immutables(), DEPLOYED_AT + CANCELLATION_OFFSET as u64),
+            Err(Error::Unauthorized)
+        );
+        assert_eq!(resolver.get_escrow_address(ORDER_HASH), None);
+        assert_eq!(balance(contract()), MIN_SAFETY_DEPOSIT);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn deploy_src_rejects_before_checking_the_signature() {
+    let order = Order {
+        salt: 0,
+        maker: AccountId::from([0xaa; 32]),
+        receiver: AccountId::from([0xaa; 32]),
+        maker_asset: AccountId::from([0u8; 32]),
+        taker_asset: [0xdd; 20],
+        making_amount: 1_000_000,
+        taking_amount: U256::default(),
+        maker_traits: maker_traits(0),
+    };
+    let cases = [
+        ("stranger", stranger(), MIN_SAFETY_DEPOSIT, 0, Error::Unauthorized),
+        (
+            "deposit below minimum",
+            owner(),
+            MIN_SAFETY_DEPOSIT - 1,
+            0,
+            Error::InsufficientFundsV2 { required: MIN_SAFETY_DEPOSIT, provided: MIN_SAFETY_DEPOSIT - 1 },
+        ),
+        ("nonce ahead of the maker's", owner(), MIN_SAFETY_DEPOSIT, 1, Error::InvalidNonce),
+    ];
+    for (name, caller, deposit, nonce, expected) in cases {
+        test::run_test::<Env, _>(|_| {
+            let mut resolver = resolver();
+            call_as(caller, DEPLOYED_AT);
+            pay_as(caller, deposit);
+            let result = resolver.deploy_src(
+                immutables(),
+                Order { maker_traits: maker_traits(nonce), ..order.clone() },
+                MakerSignature::Ecdsa([0u8; 65]),
+                0,
+                TakerTraits { traits: U256::default() },
+                Vec::new(),
+            );
+            assert_eq!(result, Err(expected), "{name}");
+            assert_eq!(resolver.get_total_escrows_created(), 0, "{name}");
+            Ok(())
+        })
+        .expect("off-chain env");
+    }
+}
+
+#[test]
+fn withdraw_and_cancel_need_a_deployed_escrow() {
+    test::run_test::<Env, _>(|_| {
+        let mut resolver = resolver();
+        call_as(stranger(), DEPLOYED_AT + CANCELLATION_OFFSET as u64);
+        assert_eq!(resolver.withdraw(ORDER_HASH, SECRET, immutables()), Err(Error::EscrowNotFound));
+        assert_eq!(resolver.cancel(ORDER_HASH, immutables()), Err(Error::EscrowNotFound));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn maker_nonce_is_the_40_bit_field_at_bit_120() {
+    let mut traits = [0u8; 32];
+    traits[11] = 0xff; // bit 160, outside the field
+    traits[12..17].copy_from_slice(&[0x12, 0x34, 0x56, 0x78, 0x9a]);
+    traits[17] = 0xff; // bit 119, outside the field
+    assert_eq!(MakerTraits(U256(traits)).nonce(), 0x12_3456_789a);
+}
+
+#[test]
+fn invalidating_a_nonce_revokes_the_signatures_over_it() {
+    test::run_test::<Env, _>(|_| {
+        let mut resolver = resolver();
+        let maker = immutables().maker;
+        assert_eq!(resolver.get_nonce(maker), 0);
+
+        call_as(maker, DEPLOYED_AT);
+        resolver.invalidate_nonce(2).expect("invalidate");
+        assert_eq!(resolver.get_nonce(maker), 3);
+        assert_eq!(resolver.invalidate_nonce(1), Err(Error::NonceAlreadyUsed));
+        assert_eq!(resolver.get_nonce(stranger()), 0);
+
+        // An order signed over a revoked nonce no longer deploys
+        call_as(owner(), DEPLOYED_AT);
+        pay_as(owner(), MIN_SAFETY_DEPOSIT);
+        let order = Order {
+            salt: 0,
+            maker,
+            receiver: maker,
+            maker_asset: AccountId::from([0xfe; 32]),
+            taker_asset: [0xdd; 20],
+            making_amount: 1_000_000,
+            taking_amount: U256::default(),
+            maker_traits: maker_traits(0),
+        };
+        let result = resolver.deploy_src(
+            immutables(),
+            order,
+            MakerSignature::Ecdsa([0u8; 65]),
+            0,
+            TakerTraits { traits: U256::default() },
+            Vec::new(),
+        );
+        assert_eq!(result, Err(Error::InvalidNonce));
+        Ok(())
+    })
+    .expect("off-chain env");
+}
+
+#[test]
+fn capabilities_report_psp22_escrows() {
+    test::run_test::<Env, _>(|_| {
+        let reported = resolver().get_capabilities();
+        assert_ne!(reported & capabilities::PSP22_TOKENS, 0);
+        assert_eq!(reported & capabilities::PARTIAL_FILLS, 0);
+        Ok(())
+    })
+    .expect("off-chain env");
+}
\ No newline at end of file
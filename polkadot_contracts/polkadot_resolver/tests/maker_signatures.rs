@@ -0,0 +1,206 @@
+//! Maker signatures on `deploy_src`: ECDSA and sr25519 signatures must cover this contract, the
+//! order hash and the maker's current nonce, which the order must carry, and the order supplied
+//! must hash to the signed order hash.
+//!
+//! A deploy that gets past the signature instantiates the escrow contract, which the off-chain
+//! environment cannot do, so accepted signatures are driven into the immutables check that
+//! follows (a native token, which `deploy_src` never escrows) and each case runs on a fresh
+//! contract.
+
+use ink::env::{test, DefaultEnvironment};
+use ink::primitives::{AccountId, Hash};
+use polkadot_resolver::polkadot_resolver::{
+    EscrowImmutables, Error, MakerSignature, MakerTraits, Order, PolkadotResolver, TakerTraits, TimeLocks,
+};
+use polkadot_resolver::{nonces, U256};
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+
+type Env = DefaultEnvironment;
+
+const MIN_SAFETY_DEPOSIT: u128 = 1_000;
+const STARTING_BALANCE: u128 = 1_000_000_000;
+const DEPLOYED_AT: u64 = 1_700_000_000_000;
+const ORDER_HASH: [u8; 32] = [0x11; 32];
+
+/// sr25519 public key of the test secret scalar `0x5e5e..5e mod l`
+const SR25519_MAKER: [u8; 32] = [
+    0xde, 0x32, 0x8e, 0x5c, 0xe7, 0xd2, 0x38, 0xff, 0xf8, 0xc7, 0x93, 0x08, 0x7c, 0xc6, 0xd4, 0x8d,
+    0x6c, 0x3b, 0x9d, 0x5d, 0xf5, 0xc9, 0xd6, 0xed, 0xdf, 0x8d, 0x9e, 0x56, 0x2a, 0x0d, 0x94, 0x05,
+];
+/// Its signature over `nonces::digest(contract(), ORDER_HASH, 0)`
+const SR25519_SIGNATURE: [u8; 64] = [
+    0x96, 0xd8, 0xce, 0x76, 0x85, 0xc2, 0x90, 0x75, 0x81, 0x0d, 0xab, 0x50, 0x04, 0xb2, 0xdd, 0x41,
+    0x73, 0x3f, 0xf7, 0x2d, 0xf1, 0xab, 0xa9, 0x18, 0x45, 0x45, 0x4d, 0xba, 0x66, 0x9a, 0x60, 0x02,
+    0x3e, 0xb1, 0xc2, 0x02, 0x81, 0x1a, 0x4d, 0x18, 0x66, 0xc8, 0x18, 0x7e, 0x7c, 0x7d, 0x4c, 0xe7,
+    0x6c, 0xd8, 0x83, 0xd0, 0xf0, 0x8d, 0x8e, 0xed, 0x61, 0x37, 0x01, 0xca, 0xee, 0x63, 0x5a, 0x86,
+];
+
+fn owner() -> AccountId {
+    AccountId::from([0x01; 32])
+}
+
+fn contract() -> AccountId {
+    AccountId::from([0xc0; 32])
+}
+
+fn call_as(caller: AccountId, value: u128) {
+    test::set_caller::<Env>(caller);
+    test::set_callee::<Env>(contract());
+    test::set_block_timestamp::<Env>(DEPLOYED_AT);
+    test::transfer_in::<Env>(value);
+}
+
+fn resolver() -> PolkadotResolver {
+    test::set_account_balance::<Env>(owner(), STARTING_BALANCE);
+    test::set_account_balance::<Env>(contract(), 0);
+    call_as(owner(), 0);
+    PolkadotResolver::new(AccountId::from([0xfa; 32]), Hash::from([0xcd; 32]), [0xee; 20], MIN_SAFETY_DEPOSIT)
+}
+
+fn ecdsa_key(seed: u8) -> SecretKey {
+    SecretKey::from_slice(&[seed; 32]).expect("secret key")
+}
+
+/// Substrate ECDSA account of `key`: Blake2x256 of the compressed public key
+fn ecdsa_account(key: &SecretKey) -> AccountId {
+    let mut account = [0u8; 32];
+    let public_key = PublicKey::from_secret_key(&Secp256k1::new(), key).serialize();
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&public_key, &mut account);
+    AccountId::from(account)
+}
+
+fn ecdsa_sign(key: &SecretKey, digest: [u8; 32]) -> MakerSignature {
+    let message = Message::from_slice(&digest).expect("digest");
+    let (recovery_id, rs) = Secp256k1::new().sign_ecdsa_recoverable(&message, key).serialize_compact();
+    let mut signature = [0u8; 65];
+    signature[..64].copy_from_slice(&rs);
+    signature[64] = recovery_id.to_i32() as u8;
+    MakerSignature::Ecdsa(signature)
+}
+
+/// Maker traits carrying `nonce` and no other flags
+fn maker_traits(nonce: u64) -> MakerTraits {
+    let mut traits = [0u8; 32];
+    traits[12..17].copy_from_slice(&nonce.to_be_bytes()[3..]);
+    MakerTraits(U256(traits))
+}
+
+/// `maker`'s order of a native token carrying `nonce`
+fn order(maker: AccountId, nonce: u64) -> Order {
+    Order {
+        salt: 0,
+        maker,
+        receiver: maker,
+        maker_asset: AccountId::from([0u8; 32]),
+        taker_asset: [0xdd; 20],
+        making_amount: 1_000_000,
+        taking_amount: U256::default(),
+        maker_traits: maker_traits(nonce),
+    }
+}
+
+/// Owner deploys `maker`'s order carrying `nonce` with `signature`, after the maker skipped
+/// past `invalidated` nonces
+fn deploy(maker: AccountId, invalidated: u64, nonce: u64, signature: MakerSignature) -> Result<AccountId, Error> {
+    let order = order(maker, nonce);
+    let order_hash = order.hash();
+    deploy_as(order, order_hash, invalidated, signature)
+}
+
+/// Owner deploys `order` under `order_hash` with `signature`, after the maker skipped past
+/// `invalidated` nonces
+fn deploy_as(order: Order, order_hash: [u8; 32], invalidated: u64, signature: MakerSignature) -> Result<AccountId, Error> {
+    let mut result = None;
+    test::run_test::<Env, _>(|_| {
+        let mut resolver = resolver();
+        if invalidated > 0 {
+            call_as(order.maker, 0);
+            resolver.invalidate_nonce(invalidated - 1).expect("invalidate");
+        }
+        let immutables = EscrowImmutables {
+            order_hash,
+            hash_lock: [0x5e; 32],
+            maker: order.maker,
+            taker: contract(),
+            token: order.maker_asset,
+            amount: order.making_amount,
+            safety_deposit: MIN_SAFETY_DEPOSIT,
+            timelocks: TimeLocks {
+                src_withdrawal: 10_000,
+                src_public_withdrawal: 20_000,
+                src_cancellation: 30_000,
+                src_public_cancellation: 40_000,
+                dst_withdrawal: 10_000,
+                dst_public_withdrawal: 20_000,
+                dst_cancellation: 30_000,
+            },
+            deployed_at: None,
+        };
+        call_as(owner(), MIN_SAFETY_DEPOSIT);
+        result = Some(resolver.deploy_src(
+            immutables,
+            order.clone(),
+            signature,
+            0,
+            TakerTraits { traits: U256::default() },
+            Vec::new(),
+        ));
+        Ok(())
+    })
+    .expect("off-chain env");
+    result.expect("deployed")
+}
+
+#[test]
+fn ecdsa_signatures_cover_contract_order_and_nonce() {
+    let key = ecdsa_key(0x4b);
+    let maker = ecdsa_account(&key);
+    let digest = |nonce| nonces::digest(contract(), order(maker, nonce).hash(), nonce);
+    let accepted = Err(Error::InvalidImmutables);
+
+    assert_eq!(deploy(maker, 0, 0, ecdsa_sign(&key, digest(0))), accepted);
+    for (name, signature) in [
+        ("other signer", ecdsa_sign(&ecdsa_key(0x4c), digest(0))),
+        ("other contract", ecdsa_sign(&key, nonces::digest(AccountId::from([0xc1; 32]), order(maker, 0).hash(), 0))),
+        ("other order", ecdsa_sign(&key, nonces::digest(contract(), ORDER_HASH, 0))),
+        ("other nonce", ecdsa_sign(&key, digest(1))),
+    ] {
+        assert_eq!(deploy(maker, 0, 0, signature), Err(Error::InvalidSignature), "{name}");
+    }
+
+    // Once the maker moved past nonce 0 its signature is dead, whatever nonce the order claims
+    assert_eq!(deploy(maker, 1, 0, ecdsa_sign(&key, digest(0))), Err(Error::InvalidNonce));
+    assert_eq!(deploy(maker, 1, 1, ecdsa_sign(&key, digest(0))), Err(Error::InvalidSignature));
+    assert_eq!(deploy(maker, 1, 1, ecdsa_sign(&key, digest(1))), accepted);
+}
+
+#[test]
+fn the_signed_hash_must_be_the_hash_of_the_order() {
+    let key = ecdsa_key(0x4b);
+    let maker = ecdsa_account(&key);
+    let signed = order(maker, 0);
+    let signature = ecdsa_sign(&key, nonces::digest(contract(), signed.hash(), 0));
+
+    for (name, supplied) in [
+        ("larger making amount", Order { making_amount: signed.making_amount * 2, ..signed.clone() }),
+        ("other receiver", Order { receiver: contract(), ..signed.clone() }),
+        ("other maker asset", Order { maker_asset: AccountId::from([0xfe; 32]), ..signed.clone() }),
+    ] {
+        assert_eq!(deploy_as(supplied, signed.hash(), 0, signature.clone()), Err(Error::InvalidOrderHash), "{name}");
+    }
+}
+
+/// The sr25519 vector signs `ORDER_HASH`, which hashes no order: an accepted signature stops at
+/// the order hash check
+#[test]
+fn sr25519_signatures_cover_the_order_and_nonce() {
+    let maker = AccountId::from(SR25519_MAKER);
+    let mut tampered = SR25519_SIGNATURE;
+    tampered[0] ^= 1;
+    let deploy = |maker, invalidated, nonce, signature| deploy_as(order(maker, nonce), ORDER_HASH, invalidated, signature);
+
+    assert_eq!(deploy(maker, 0, 0, MakerSignature::Sr25519(SR25519_SIGNATURE)), Err(Error::InvalidOrderHash));
+    assert_eq!(deploy(maker, 0, 0, MakerSignature::Sr25519(tampered)), Err(Error::InvalidSignature));
+    assert_eq!(deploy(ecdsa_account(&ecdsa_key(0x4b)), 0, 0, MakerSignature::Sr25519(SR25519_SIGNATURE)), Err(Error::InvalidSignature));
+    assert_eq!(deploy(maker, 1, 1, MakerSignature::Sr25519(SR25519_SIGNATURE)), Err(Error::InvalidSignature));
+}
@@ -38,6 +38,28 @@ declare global {
   }
 }
 
+// Zero account: the escrow's marker for the chain's native asset
+const POLKADOT_NATIVE_TOKEN = '0x' + '00'.repeat(32)
+
+// `CreateOrderParams` fields the relayer leaves unset
+const CREATE_ORDER_DEFAULTS = {
+  metadata: [],
+  min_resolver_reputation: null,
+  late_fee_schedule: null,
+  basket: [],
+  encrypted_memo: null,
+  blocked_resolvers: [],
+  executor_tip: null,
+  hash_algo: { Keccak256: null }, // Hashlocks come from the Ethereum-side order
+  preferred_resolver: null,
+  auction: null
+}
+
+// Big-endian 32-byte word, as the escrow's `U256` expects
+function toU256(value: bigint): number[] {
+  return Array.from(Buffer.from(value.toString(16).padStart(64, '0'), 'hex'))
+}
+
 // Enhanced wallet config
 export interface EthereumWalletConfig {
   type: 'metamask' | 'walletconnect' | 'private-key' | 'injected'
@@ -607,13 +629,14 @@ export class NewFusionPolkadotRelayer extends EventEmitter {
     // Create order parameters for Polkadot escrow
     const createOrderParams = {
       direction: { PolkadotToEthereum: null },
-      src_token: this.polkadotWallet.account.address, // Simplified - would be actual token address
+      src_token: POLKADOT_NATIVE_TOKEN, // Native DOT
       dst_token: Array.from(Buffer.from(order.dstToken.slice(2), 'hex')), // Convert hex to byte array
       src_amount: order.srcAmount,
-      min_dst_amount: order.dstAmount,
+      min_dst_amount: toU256(order.dstAmount),
       fill_deadline: BigInt(order.deadline),
-      ethereum_recipient: Array.from(Buffer.from(order.maker.slice(2), 'hex')),
-      max_resolver_fee: parseEther('0.01') // 0.01 DOT fee
+      recipient: { Evm: Array.from(Buffer.from(order.maker.slice(2), 'hex')) },
+      max_resolver_fee: parseEther('0.01'), // 0.01 DOT fee
+      ...CREATE_ORDER_DEFAULTS
     }
 
     // Call create_order on Polkadot escrow
@@ -653,13 +676,14 @@ export class NewFusionPolkadotRelayer extends EventEmitter {
     // Create destination order parameters
     const createOrderParams = {
       direction: { EthereumToPolkadot: null },
-      src_token: Array.from(Buffer.from(order.srcToken.slice(2), 'hex')), // Ethereum token as bytes
-      dst_token: this.polkadotWallet.account.address, // Polkadot token (DOT)
+      src_token: POLKADOT_NATIVE_TOKEN, // Polkadot token (DOT)
+      dst_token: Array.from(Buffer.from(order.srcToken.slice(2), 'hex')), // Ethereum token as bytes
       src_amount: order.srcAmount,
-      min_dst_amount: order.dstAmount,
+      min_dst_amount: toU256(order.dstAmount),
       fill_deadline: BigInt(order.deadline),
-      ethereum_recipient: Array.from(Buffer.from(order.maker.slice(2), 'hex')),
-      max_resolver_fee: parseEther('0.01')
+      recipient: { Evm: Array.from(Buffer.from(order.maker.slice(2), 'hex')) },
+      max_resolver_fee: parseEther('0.01'),
+      ...CREATE_ORDER_DEFAULTS
     }
 
     // Note: For destination orders, we don't send tokens yet - they'll be provided when deploying escrow
@@ -707,7 +731,15 @@ export class NewFusionPolkadotRelayer extends EventEmitter {
       safety_deposit: this.config.relayer.safetyDeposit,
       timelocks: {
         fill_deadline: BigInt(order.deadline),
-        private_cancellation: BigInt(order.deadline - 30 * 60 * 1000) // 30 min before deadline
+        private_cancellation: BigInt(order.deadline - 30 * 60 * 1000), // 30 min before deadline
+        // Stage starts are stamped by the contract when the order is locked
+        src_withdrawal: BigInt(0),
+        src_public_withdrawal: BigInt(0),
+        src_cancellation: BigInt(0),
+        src_public_cancellation: BigInt(0),
+        dst_withdrawal: BigInt(0),
+        dst_public_withdrawal: BigInt(0),
+        dst_cancellation: BigInt(0)
       },
       deployed_at: null // Will be set by contract
     }
@@ -888,6 +920,34 @@ export class NewFusionPolkadotRelayer extends EventEmitter {
     })
 
     this.emit('polkadotSwapExecuted', { order, txHash: result.txHash?.toString() })
+
+    // Settlement payouts are credited on the escrow, collect them
+    await this.claimPolkadotPayouts()
+  }
+
+  private async claimPolkadotPayouts(): Promise<void> {
+    if (!this.polkadotWallet || !this.polkadotEscrowContract) {
+      throw new Error('Polkadot components not available')
+    }
+
+    const gasLimit = this.polkadotWallet.api.registry.createType('WeightV2', {
+      refTime: BigInt(1_000_000_000),
+      proofSize: BigInt(64 * 1024)
+    }) as any // Cast to any to avoid type issues
+
+    // Native payouts (token = None)
+    const tx = this.polkadotEscrowContract.tx.claim({ gasLimit, storageDepositLimit: null }, null)
+
+    await new Promise<any>((resolve, reject) => {
+      tx.signAndSend(this.polkadotWallet!.account, (result: any) => {
+        if (result.status.isInBlock) {
+          console.log(`✅ Polkadot payouts claimed in block: ${result.status.asInBlock}`)
+          resolve(result)
+        } else if (result.isError) {
+          reject(new Error(`Polkadot payout claim failed: ${result}`))
+        }
+      })
+    })
   }
 
   private async executeEthereumWithdrawal(order: EnhancedSwapOrder): Promise<void> {